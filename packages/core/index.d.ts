@@ -15,7 +15,10 @@
 export interface ChannelAVerdict {
   /** Whether the proposal passed all Channel A checks */
   pass: boolean;
-  /** Complexity score (zlib compressed size of canonical payload) */
+  /**
+   * Complexity score (zlib compressed size of canonical payload).
+   * Never negative: saturates at i64::MAX if compression failed.
+   */
   complexityScore: number;
   /** Whether a logical paradox was detected */
   paradoxFound: boolean;
@@ -23,10 +26,40 @@ export interface ChannelAVerdict {
   cycleFound: boolean;
 }
 
+/**
+ * Wall-clock duration of each verification stage, in milliseconds
+ *
+ * Observability only; timings never affect the verdict.
+ */
+export interface StageTimings {
+  /** Parsing and canonicalizing the proposal */
+  canonicalizationMs: number;
+  /** Compressing the payload for the complexity score */
+  complexityMs: number;
+  /** Paradox detection */
+  paradoxMs: number;
+  /** Dependency cycle detection */
+  cyclesMs: number;
+  /** Sum of the four stages */
+  totalMs: number;
+}
+
+/**
+ * Channel A verdict with per-stage timings
+ */
+export interface TimedVerification {
+  /** Channel A verdict, identical to verifyProposal */
+  verdict: ChannelAVerdict;
+  /** Wall-clock duration of each stage */
+  timings: StageTimings;
+}
+
 /**
  * Canonical payload result from canonicalization
  */
 export interface CanonicalResult {
+  /** Canonical form version (the payload's first byte) */
+  version: number;
   /** Canonical payload as hex-encoded string */
   payloadHex: string;
   /** SHA-256 hash as hex-encoded string (proposal ID) */
@@ -35,6 +68,20 @@ export interface CanonicalResult {
   length: number;
 }
 
+/**
+ * Canonical payload result with raw bytes instead of hex
+ */
+export interface CanonicalBufferResult {
+  /** Canonical form version (the payload's first byte) */
+  version: number;
+  /** Canonical payload bytes */
+  payload: Buffer;
+  /** SHA-256 hash (proposal ID), 32 bytes */
+  hash: Buffer;
+  /** Payload length in bytes */
+  length: number;
+}
+
 /**
  * Friction parameters calculated from Channel B alignment score
  */
@@ -51,6 +98,154 @@ export interface FrictionParams {
   timelockMultiplier: number;
 }
 
+/**
+ * Change in friction between two alignment scores (each field is `to - from`)
+ */
+export interface FrictionDelta {
+  /** Change in required quorum (fraction of voting power) */
+  quorumDelta: number;
+  /** Change in required quorum in basis points */
+  quorumDeltaBps: number;
+  /** Change in timelock duration in seconds */
+  timelockDeltaSecs: number;
+}
+
+/**
+ * Structural metrics of a logic AST
+ */
+export interface AstMetrics {
+  /** Number of values below the root */
+  nodeCount: number;
+  /** Deepest nesting level */
+  maxDepth: number;
+  /** Number of dependency references */
+  edgeCount: number;
+  /** Nodes with no children */
+  leafCount: number;
+}
+
+/**
+ * Remaining complexity budget for a score
+ */
+export interface ComplexityHeadroom {
+  /** Bytes left below MAX_COMPLEXITY (0 at or above it) */
+  headroom: number;
+  /** Headroom as a percentage of MAX_COMPLEXITY */
+  headroomPercent: number;
+  /** Score is at or above the near-limit threshold */
+  nearLimit: boolean;
+}
+
+/**
+ * A paradox pattern that matched proposal text
+ */
+export interface ParadoxMatch {
+  /** Position of the pattern in the built-in list (not stable across versions) */
+  index: number;
+  /** Stable ID of the pattern; use this in anything persisted */
+  patternId: string;
+  /** Text the pattern matched */
+  matchedText: string;
+}
+
+/**
+ * Complexity score split between the logic AST and the text
+ */
+export interface ComplexityBreakdown {
+  /** Score of the whole canonical payload */
+  total: number;
+  /** Score of the canonical AST JSON alone */
+  astScore: number;
+  /** Score of "." + normalized text alone */
+  textScore: number;
+}
+
+/**
+ * Why a proposal passed Channel A
+ */
+export interface PassReport {
+  /** Complexity score of the canonical payload */
+  complexityScore: number;
+  /** Complexity limit the score was checked against */
+  maxComplexity: number;
+  /** Margin below the complexity limit */
+  headroom: ComplexityHeadroom;
+  /** Number of built-in paradox patterns checked */
+  paradoxPatternsChecked: number;
+  /** IDs of paradox patterns that matched (always empty for a pass) */
+  paradoxMatches: string[];
+  /** Nodes in the dependency graph */
+  graphNodes: number;
+  /** Edges in the dependency graph, none of which form a cycle */
+  graphEdges: number;
+}
+
+/**
+ * Summary of a verified batch of proposals
+ *
+ * Each failure is counted once, under the first check it failed. The
+ * complexity statistics leave out L0 targets and canonicalization errors,
+ * which fail before scoring.
+ */
+export interface BatchSummary {
+  /** Number of proposals verified */
+  total: number;
+  /** Proposals that passed */
+  passed: number;
+  /** Proposals that failed for any reason */
+  failed: number;
+  /** Failures targeting L0 */
+  failedL0: number;
+  /** Failures to canonicalize the logic AST */
+  failedCanonicalization: number;
+  /** Failures on complexity above the layer's limit */
+  failedComplexity: number;
+  /** Failures on a paradox */
+  failedParadox: number;
+  /** Failures on a dependency cycle */
+  failedCycle: number;
+  /** Failures on unrecognized references */
+  failedReferences: number;
+  /** Failures on banned content */
+  failedBanned: number;
+  /** Lowest complexity score among scored proposals */
+  minComplexity?: number;
+  /** Highest complexity score among scored proposals */
+  maxComplexity?: number;
+  /** Lower median complexity score among scored proposals */
+  medianComplexity?: number;
+}
+
+/**
+ * Result of comparing two proposal texts for containment
+ */
+export interface ContainmentResult {
+  /** How the shorter text appears in the longer */
+  containment: Containment;
+  /** Fraction of the longer text covered (0.0 to 1.0) */
+  strength: number;
+  /** Tokens of the shorter text found in the longer */
+  sharedTokens: number;
+  /** Whether the texts look like a padded resubmission */
+  flagged: boolean;
+}
+
+/**
+ * Estimated jury review effort for a proposal
+ */
+export interface ReviewEffort {
+  /** Words in the normalized text */
+  textTokens: number;
+  /** Values in the logic AST */
+  astNodes: number;
+  /** Channel A complexity score of the canonical payload */
+  complexityScore: number;
+  /** Combined effort: textTokens + 2 * astNodes + complexityScore / 10 */
+  effortScore: number;
+  /** Suggested review duration in seconds, at most the jury voting period */
+  suggestedDurationSecs: number;
+}
+
 /**
  * Governance layer enum
  */
@@ -60,6 +255,18 @@ export type GovernanceLayer =
   | 'L2Operational'
   | 'L3Execution';
 
+/**
+ * Complexity presentation unit
+ *
+ * The consensus gate always uses bytes.
+ */
+export type ComplexityUnit = 'Bytes' | 'Bits' | 'NormalizedPercent';
+
+/**
+ * How the shorter of two normalized texts appears in the longer
+ */
+export type Containment = 'Unrelated' | 'Subsequence' | 'Contiguous' | 'Identical';
+
 /**
  * Verify a proposal through the full Channel A pipeline
  *
@@ -69,6 +276,8 @@ export type GovernanceLayer =
  * @param logicAst - JSON AST of the proposal logic
  * @param text - Natural language description
  * @param layer - Governance layer
+ * @param complexityBucketSize - If set, report the complexity score rounded to the
+ *   nearest multiple of this size (display only; the gate uses the exact score)
  * @returns Channel A verdict with pass/fail and details
  *
  * @example
@@ -95,9 +304,34 @@ export function verifyProposal(
   proposer: string,
   logicAst: string,
   text: string,
-  layer: GovernanceLayer
+  layer: GovernanceLayer,
+  complexityBucketSize?: number
 ): ChannelAVerdict;
 
+/**
+ * Verify a proposal and report how long each stage took
+ *
+ * Stages that did not run (e.g. after a canonicalization failure) report 0.
+ *
+ * @param proposer - XRPL address of the proposer
+ * @param logicAst - JSON AST of the proposal logic
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @returns Channel A verdict and per-stage timings in milliseconds
+ *
+ * @example
+ * ```typescript
+ * const { verdict, timings } = verifyProposalTimed(proposer, logicAst, text, 'L2Operational');
+ * metrics.observe('channel_a_complexity_ms', timings.complexityMs);
+ * ```
+ */
+export function verifyProposalTimed(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer
+): TimedVerification;
+
 /**
  * Verify a proposal from JSON input
  *
@@ -118,6 +352,84 @@ export function verifyProposal(
  */
 export function verifyProposalJson(proposalJson: string): ChannelAVerdict;
 
+/**
+ * Verify a JSON array of proposals in one call
+ *
+ * Saves a boundary crossing per proposal when verifying many at once.
+ * Verdicts are returned in input order and are identical to calling
+ * `verifyProposalJson` on each element in turn.
+ *
+ * @param proposalsJson - JSON array of proposals
+ * @returns One Channel A verdict per proposal
+ * @throws Error if the input is not a JSON array, or naming the index of the first element that is not a valid proposal
+ *
+ * @example
+ * ```typescript
+ * const verdicts = verifyProposalsJson(JSON.stringify(proposals));
+ * verdicts.forEach((verdict, i) => index.record(proposals[i].id, verdict));
+ * ```
+ */
+export function verifyProposalsJson(proposalsJson: string): ChannelAVerdict[];
+
+/**
+ * Verify a proposal on the libuv thread pool
+ *
+ * Same inputs and verdict as `verifyProposal`, but the pipeline runs off
+ * the JavaScript thread and the result arrives as a Promise. The verdict
+ * is deterministic, so it does not depend on which thread computed it.
+ *
+ * @param proposer - XRPL address of the proposer
+ * @param logicAst - JSON AST of the proposal logic
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @param complexityBucketSize - If set, report the complexity score rounded to this bucket size
+ * @returns Promise of the Channel A verdict
+ *
+ * @example
+ * ```typescript
+ * const verdict = await verifyProposalAsync(proposer, logicAst, text, 'L2Operational');
+ * ```
+ */
+export function verifyProposalAsync(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer,
+  complexityBucketSize?: number
+): Promise<ChannelAVerdict>;
+
+/**
+ * Verify a JSON array of proposals on the libuv thread pool
+ *
+ * Parsing and verification both run off the JavaScript thread. Verdicts
+ * are returned in input order and are identical to calling
+ * `verifyProposalJson` on each element in turn.
+ *
+ * @param proposalsJson - JSON array of proposals
+ * @returns Promise of one Channel A verdict per proposal; rejects like `verifyProposalsJson`
+ *
+ * @example
+ * ```typescript
+ * const verdicts = await verifyProposalsBatchAsync(JSON.stringify(proposals));
+ * const rejected = proposals.filter((_, i) => !verdicts[i].pass);
+ * ```
+ */
+export function verifyProposalsBatchAsync(proposalsJson: string): Promise<ChannelAVerdict[]>;
+
+/**
+ * Verify a JSON array of proposals and summarize the outcomes
+ *
+ * @param proposalsJson - JSON array of proposal objects
+ * @returns Pass/fail counts by failure mode and complexity distribution
+ *
+ * @example
+ * ```typescript
+ * const summary = summarizeBatch(JSON.stringify(epochProposals));
+ * console.log(`${summary.passed}/${summary.total} passed`);
+ * ```
+ */
+export function summarizeBatch(proposalsJson: string): BatchSummary;
+
 /**
  * Canonicalize a proposal and return the canonical payload
  *
@@ -147,6 +459,48 @@ export function canonicalizeProposal(
   layer: GovernanceLayer
 ): CanonicalResult;
 
+/**
+ * Canonicalize a proposal, returning the payload and hash as Buffers
+ *
+ * Same payload as `canonicalizeProposal` without the hex round-trip,
+ * for callers that hash or store the bytes directly.
+ *
+ * @param proposer - XRPL address of proposer
+ * @param logicAst - JSON AST
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @returns Canonical payload and hash bytes
+ *
+ * @example
+ * ```typescript
+ * const { payload, hash } = canonicalizeProposalBuffer(proposer, logicAst, text, 'L2Operational');
+ * await store.put(hash, payload);
+ * ```
+ */
+export function canonicalizeProposalBuffer(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer
+): CanonicalBufferResult;
+
+/**
+ * Check that a string is a classic XRPL account address
+ *
+ * Advisory; Channel A verification does not check the proposer.
+ *
+ * @param address - Candidate address
+ * @returns true for a checksummed classic address starting with `r`
+ *
+ * @example
+ * ```typescript
+ * if (!isValidXrplAddress(form.proposer)) {
+ *   showError('Enter a classic XRPL address (r...)');
+ * }
+ * ```
+ */
+export function isValidXrplAddress(address: string): boolean;
+
 /**
  * Compute complexity score for a payload
  *
@@ -154,6 +508,7 @@ export function canonicalizeProposal(
  *
  * @param payloadHex - Hex-encoded payload bytes
  * @returns Complexity score (compressed size in bytes)
+ * @throws Error if compression failed
  *
  * @example
  * ```typescript
@@ -167,6 +522,99 @@ export function canonicalizeProposal(
  */
 export function computeComplexityScore(payloadHex: string): number;
 
+/**
+ * Compute complexity for a payload in a display unit
+ *
+ * @param payloadHex - Hex-encoded payload bytes
+ * @param unit - Bytes, Bits, or NormalizedPercent (percent of MAX_COMPLEXITY)
+ * @returns Complexity in the requested unit
+ * @throws Error if compression failed
+ *
+ * @example
+ * ```typescript
+ * const pct = computeComplexityIn(canonical.payloadHex, 'NormalizedPercent');
+ * console.log(`Using ${pct.toFixed(1)}% of the complexity budget`);
+ * ```
+ */
+export function computeComplexityIn(payloadHex: string, unit: ComplexityUnit): number;
+
+/**
+ * Compute the complexity contributed by proposal text alone
+ *
+ * Diagnostic only: compression is not additive, so text + AST complexity
+ * approximates (and usually slightly exceeds) the consensus score.
+ *
+ * @param text - Natural language proposal text (normalized before compressing)
+ * @returns Compressed size of "." + normalized text
+ * @throws Error if compression failed
+ *
+ * @example
+ * ```typescript
+ * const floor = computeTextComplexity('Transfer 100 tokens to the community fund');
+ * console.log(`AST budget: ${getMaxComplexity() - floor} bytes`);
+ * ```
+ */
+export function computeTextComplexity(text: string): number;
+
+/**
+ * Compute the complexity contributed by the logic AST alone
+ *
+ * Diagnostic only; compresses the canonical (sorted, compact) AST JSON.
+ *
+ * @param logicAst - JSON string of the proposal logic
+ * @returns Compressed size of the canonical AST JSON
+ * @throws Error if the AST is not valid JSON or compression failed
+ *
+ * @example
+ * ```typescript
+ * const astCost = computeAstComplexity('{"action": "transfer", "amount": 100}');
+ * ```
+ */
+export function computeAstComplexity(logicAst: string): number;
+
+/**
+ * Split a proposal's complexity score into AST and text contributions
+ *
+ * The two parts are compressed separately and do not sum to the total;
+ * the larger one shows which half to trim.
+ *
+ * @param proposer - XRPL address of proposer
+ * @param logicAst - JSON AST
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @returns Total, AST and text scores
+ * @throws Error if canonicalization fails
+ *
+ * @example
+ * ```typescript
+ * const { astScore, textScore } = getComplexityBreakdown(proposer, logicAst, text, 'L2Operational');
+ * console.log(astScore > textScore ? 'Simplify the logic' : 'Shorten the description');
+ * ```
+ */
+export function getComplexityBreakdown(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer
+): ComplexityBreakdown;
+
+/**
+ * Report how close a complexity score is to MAX_COMPLEXITY
+ *
+ * @param score - Complexity score (negative values are treated as 0)
+ * @param nearLimitPercent - Share of the limit (0-100) that counts as near it; defaults to 90
+ * @returns Remaining headroom and a near-limit flag
+ *
+ * @example
+ * ```typescript
+ * const { nearLimit, headroom } = getComplexityHeadroom(verdict.complexityScore);
+ * if (nearLimit) {
+ *   console.warn(`Only ${headroom} bytes below the complexity limit`);
+ * }
+ * ```
+ */
+export function getComplexityHeadroom(score: number, nearLimitPercent?: number): ComplexityHeadroom;
+
 /**
  * Detect paradoxes in proposal text
  *
@@ -186,6 +634,24 @@ export function computeComplexityScore(payloadHex: string): number;
  */
 export function detectParadoxInText(text: string): boolean;
 
+/**
+ * List the paradox patterns that match proposal text
+ *
+ * Explains a `detectParadoxInText` result: one entry per matching
+ * pattern, in pattern order, with the substring it matched.
+ *
+ * @param text - Natural language proposal text
+ * @returns Matching patterns (empty if no paradox is detected)
+ *
+ * @example
+ * ```typescript
+ * for (const m of findParadoxMatchesInText(text)) {
+ *   console.log(`Flagged by ${m.patternId}: "${m.matchedText}"`);
+ * }
+ * ```
+ */
+export function findParadoxMatchesInText(text: string): ParadoxMatch[];
+
 /**
  * Detect cycles in proposal logic AST
  *
@@ -204,6 +670,92 @@ export function detectParadoxInText(text: string): boolean;
  */
 export function detectCyclesInAst(logicAst: string): boolean;
 
+/**
+ * Compute structural metrics of a logic AST
+ *
+ * @param logicAst - JSON AST of proposal logic
+ * @returns Node count, max depth, reference edge count and leaf count
+ *
+ * @example
+ * ```typescript
+ * const metrics = getAstMetrics('{"a": {"value": 1}}');
+ * console.log(metrics.nodeCount, metrics.maxDepth);
+ * ```
+ */
+export function getAstMetrics(logicAst: string): AstMetrics;
+
+/**
+ * Compare two proposal texts for padded resubmission
+ *
+ * Advisory only: reports whether one normalized text is a contiguous run
+ * or an in-order token subsequence of the other.
+ *
+ * @param a - First proposal text
+ * @param b - Second proposal text
+ * @returns Containment relation, strength and whether it is flagged
+ *
+ * @example
+ * ```typescript
+ * const result = textContainment(previous.text, submitted.text);
+ * if (result.flagged) {
+ *   console.warn(`Possible resubmission (${result.containment}, ${result.strength})`);
+ * }
+ * ```
+ */
+export function textContainment(a: string, b: string): ContainmentResult;
+
+/**
+ * Estimate jury review effort for a proposal
+ *
+ * Advisory scheduling metadata for Class III proposals; never affects a verdict.
+ *
+ * @param proposer - XRPL address of the proposer
+ * @param logicAst - JSON AST of the proposal logic
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @returns Effort score and suggested review duration in seconds
+ *
+ * @example
+ * ```typescript
+ * const effort = estimateReviewEffort(proposer, logicAst, text, 'L1Constitutional');
+ * scheduleJury(proposalId, effort.suggestedDurationSecs);
+ * ```
+ */
+export function estimateReviewEffort(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer
+): ReviewEffort;
+
+/**
+ * Explain why a proposal passes Channel A
+ *
+ * Advisory feedback for authors; never affects a verdict.
+ *
+ * @param proposer - XRPL address of the proposer
+ * @param logicAst - JSON AST of the proposal logic
+ * @param text - Natural language description
+ * @param layer - Governance layer
+ * @returns Pass report, or null if the proposal fails Channel A
+ *
+ * @example
+ * ```typescript
+ * const report = explainPass(proposer, logicAst, text, 'L2Operational');
+ * if (report) {
+ *   console.log(`${report.headroom.headroom} bytes below the complexity limit`);
+ *   console.log(`${report.paradoxPatternsChecked} paradox patterns checked, none matched`);
+ *   console.log(`Acyclic dependency graph: ${report.graphNodes} nodes, ${report.graphEdges} edges`);
+ * }
+ * ```
+ */
+export function explainPass(
+  proposer: string,
+  logicAst: string,
+  text: string,
+  layer: GovernanceLayer
+): PassReport | null;
+
 /**
  * Calculate friction parameters from alignment score
  *
@@ -211,6 +763,9 @@ export function detectCyclesInAst(logicAst: string): boolean;
  * - Quorum Multiplier: 1.0 + (1.0 - alignment_score) * 0.5
  * - Timelock Multiplier: 1.0 + (1.0 - alignment_score) * 2.0
  *
+ * Non-finite input (`NaN`, `Infinity`) is treated as 0.0 alignment,
+ * giving maximum friction.
+ *
  * @param alignmentScore - Semantic alignment score from Channel B (0.0 to 1.0)
  * @returns Friction parameters
  *
@@ -223,6 +778,52 @@ export function detectCyclesInAst(logicAst: string): boolean;
  */
 export function calculateFriction(alignmentScore: number): FrictionParams;
 
+/**
+ * Calculate the change in friction between two alignment scores
+ *
+ * Each field is `to - from`; positive values mean more friction.
+ * Swapping the arguments negates every field.
+ *
+ * @param from - Starting alignment score (0.0 to 1.0)
+ * @param to - Ending alignment score (0.0 to 1.0)
+ * @returns Change in quorum and timelock
+ *
+ * @example
+ * ```typescript
+ * const delta = frictionDelta(0.6, 0.8);
+ * console.log('Timelock change:', delta.timelockDeltaSecs, 'seconds');
+ * ```
+ */
+export function frictionDelta(from: number, to: number): FrictionDelta;
+
+/**
+ * Run the golden-vector conformance self-test
+ *
+ * Call once at server start to fail fast on a mis-built native module
+ * (e.g. one linked against a different zlib backend).
+ *
+ * @throws Error naming the golden vector and output that diverged
+ *
+ * @example
+ * ```typescript
+ * try {
+ *   selfTest();
+ * } catch (err) {
+ *   console.error('Native module is non-conforming:', err);
+ *   process.exit(1);
+ * }
+ * ```
+ */
+export function selfTest(): void;
+
+/**
+ * Compile all lazily-initialized detectors now
+ *
+ * Call once at server boot so the first verification is not slowed by
+ * regex compilation. Safe to call more than once.
+ */
+export function warmUp(): void;
+
 /**
  * Get the maximum allowed complexity score
  *
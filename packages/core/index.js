@@ -235,12 +235,32 @@ if (!nativeBinding) {
 
 const {
   verifyProposal,
+  verifyProposalTimed,
   verifyProposalJson,
+  verifyProposalsJson,
+  verifyProposalAsync,
+  verifyProposalsBatchAsync,
+  summarizeBatch,
   canonicalizeProposal,
+  canonicalizeProposalBuffer,
+  isValidXrplAddress,
   computeComplexityScore,
+  computeComplexityIn,
+  computeTextComplexity,
+  computeAstComplexity,
+  getComplexityBreakdown,
+  getComplexityHeadroom,
   detectParadoxInText,
+  findParadoxMatchesInText,
   detectCyclesInAst,
+  getAstMetrics,
+  textContainment,
+  explainPass,
+  estimateReviewEffort,
   calculateFriction,
+  frictionDelta,
+  selfTest,
+  warmUp,
   getMaxComplexity,
   getOracleBond,
   getActiveOracleSetSize,
@@ -250,12 +270,32 @@ const {
 
 module.exports = {
   verifyProposal,
+  verifyProposalTimed,
   verifyProposalJson,
+  verifyProposalsJson,
+  verifyProposalAsync,
+  verifyProposalsBatchAsync,
+  summarizeBatch,
   canonicalizeProposal,
+  canonicalizeProposalBuffer,
+  isValidXrplAddress,
   computeComplexityScore,
+  computeComplexityIn,
+  computeTextComplexity,
+  computeAstComplexity,
+  getComplexityBreakdown,
+  getComplexityHeadroom,
   detectParadoxInText,
+  findParadoxMatchesInText,
   detectCyclesInAst,
+  getAstMetrics,
+  textContainment,
+  explainPass,
+  estimateReviewEffort,
   calculateFriction,
+  frictionDelta,
+  selfTest,
+  warmUp,
   getMaxComplexity,
   getOracleBond,
   getActiveOracleSetSize,
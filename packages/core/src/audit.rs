@@ -0,0 +1,179 @@
+//! Verification Audit Log
+//!
+//! An append-only, hash-chained record of every verdict an oracle produced.
+//! Each entry commits to the one before it:
+//!
+//! ```text
+//! h_0 = [0; 32]
+//! h_n = sha256(h_{n-1} || proposal_id || verdict.to_canonical_bytes())
+//! ```
+//!
+//! Editing, reordering, or dropping any entry changes every later hash, so
+//! `verify_chain` detects retroactive tampering. The log is in-memory and
+//! serde-serializable for persistence.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::types::ChannelAVerdict;
+
+/// Hash that precedes the first entry
+pub const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+/// One recorded verdict and its chained hash
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Proposal the verdict was produced for
+    pub proposal_id: [u8; 32],
+    /// The verdict reported
+    pub verdict: ChannelAVerdict,
+    /// Running hash through this entry
+    pub hash: [u8; 32],
+}
+
+/// Append-only, hash-chained verdict history
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a verdict and return the new head hash
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::audit::AuditLog;
+    /// use constitution_dao_core::ChannelAVerdict;
+    ///
+    /// let mut log = AuditLog::new();
+    /// log.record([1u8; 32], ChannelAVerdict::pass(120));
+    /// log.record([2u8; 32], ChannelAVerdict::fail(90, true, false));
+    /// assert!(log.verify_chain().is_ok());
+    /// ```
+    pub fn record(&mut self, proposal_id: [u8; 32], verdict: ChannelAVerdict) -> [u8; 32] {
+        let hash = chain_hash(&self.head(), &proposal_id, &verdict);
+        self.entries.push(AuditEntry {
+            proposal_id,
+            verdict,
+            hash,
+        });
+        hash
+    }
+
+    /// Hash of the latest entry, or `GENESIS_HASH` when empty
+    pub fn head(&self) -> [u8; 32] {
+        self.entries.last().map_or(GENESIS_HASH, |e| e.hash)
+    }
+
+    /// Recorded entries, oldest first
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Number of recorded entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Recompute the chain from genesis
+    ///
+    /// Returns the index of the first entry whose stored hash does not
+    /// match its recomputed hash.
+    pub fn verify_chain(&self) -> Result<(), usize> {
+        let mut prev = GENESIS_HASH;
+        for (i, entry) in self.entries.iter().enumerate() {
+            let expected = chain_hash(&prev, &entry.proposal_id, &entry.verdict);
+            if entry.hash != expected {
+                return Err(i);
+            }
+            prev = expected;
+        }
+        Ok(())
+    }
+}
+
+fn chain_hash(prev: &[u8; 32], proposal_id: &[u8; 32], verdict: &ChannelAVerdict) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(prev);
+    hasher.update(proposal_id);
+    hasher.update(verdict.to_canonical_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_log() -> AuditLog {
+        let mut log = AuditLog::new();
+        log.record([1u8; 32], ChannelAVerdict::pass(120));
+        log.record([2u8; 32], ChannelAVerdict::fail(90, true, false));
+        log.record([3u8; 32], ChannelAVerdict::fail(300, false, true));
+        log
+    }
+
+    #[test]
+    fn test_valid_chain_verifies() {
+        let log = sample_log();
+        assert_eq!(log.len(), 3);
+        assert!(log.verify_chain().is_ok());
+        assert_eq!(log.head(), log.entries()[2].hash);
+    }
+
+    #[test]
+    fn test_genesis_entry() {
+        let mut log = AuditLog::new();
+        assert!(log.is_empty());
+        assert_eq!(log.head(), GENESIS_HASH);
+        assert!(log.verify_chain().is_ok());
+
+        let verdict = ChannelAVerdict::pass(120);
+        let hash = log.record([9u8; 32], verdict.clone());
+        assert_eq!(hash, chain_hash(&GENESIS_HASH, &[9u8; 32], &verdict));
+        assert!(log.verify_chain().is_ok());
+    }
+
+    #[test]
+    fn test_tampered_entry_detected() {
+        let mut log = sample_log();
+        log.entries[1].verdict = ChannelAVerdict::pass(90);
+        assert_eq!(log.verify_chain(), Err(1));
+
+        // Re-hashing the edited entry still breaks the next link
+        let mut log = sample_log();
+        log.entries[1].verdict = ChannelAVerdict::pass(90);
+        log.entries[1].hash = chain_hash(
+            &log.entries[0].hash,
+            &log.entries[1].proposal_id,
+            &log.entries[1].verdict,
+        );
+        assert_eq!(log.verify_chain(), Err(2));
+    }
+
+    #[test]
+    fn test_dropped_entry_detected() {
+        let mut log = sample_log();
+        log.entries.remove(0);
+        assert_eq!(log.verify_chain(), Err(0));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let log = sample_log();
+        let json = serde_json::to_string(&log).unwrap();
+        let restored: AuditLog = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, log);
+        assert!(restored.verify_chain().is_ok());
+    }
+}
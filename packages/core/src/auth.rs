@@ -0,0 +1,313 @@
+//! Proposer Authorization
+//!
+//! A proposal's `proposer` field is a bare claim. `verify_proposal_signed`
+//! binds the Channel A verdict to an authenticated submission by checking,
+//! in order:
+//!
+//! 1. The signature over the signing message (see `signing_message`) is
+//!    valid for the key
+//! 2. The key derives the claimed `proposer` XRPL address
+//! 3. The proposal passes Channel A
+//!
+//! Keys and signatures follow XRPL conventions:
+//!
+//! - Ed25519: 33-byte key `0xED || key`; 64-byte signature over the message
+//! - secp256k1: 33-byte compressed SEC1 key; DER-encoded, low-S ECDSA
+//!   signature over SHA-512Half(message)
+//!
+//! The address is `base58check(0x00 || RIPEMD160(SHA256(public_key)))` in
+//! the XRPL alphabet.
+
+use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519Key};
+use k256::ecdsa::{
+    signature::hazmat::PrehashVerifier, Signature as Secp256k1Signature,
+    VerifyingKey as Secp256k1Key,
+};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+
+use crate::channel_a::{canonicalize, verify_proposal, CanonicalizeError};
+use crate::types::{ChannelAVerdict, GovernanceLayer, Proposal};
+
+/// Prefix byte marking an XRPL Ed25519 public key
+const ED25519_PREFIX: u8 = 0xED;
+
+/// Domain-separation tag opening every signing message
+///
+/// Keeps a proposal signature from being valid as any other XRPL-signed
+/// message, and vice versa.
+pub const SIGNING_DOMAIN: &[u8] = b"CONSTITUTION-DAO-PROPOSAL\0";
+
+/// Errors from proposer authorization
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("Unsupported or malformed public key")]
+    InvalidPublicKey,
+    #[error("Malformed signature")]
+    MalformedSignature,
+    #[error("Signature does not match the signing message")]
+    InvalidSignature,
+    #[error("Public key derives {derived}, not proposer {claimed}")]
+    ProposerMismatch { claimed: String, derived: String },
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
+
+/// Derive the classic XRPL address for a public key
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::auth::derive_xrpl_address;
+///
+/// let key = hex::decode("0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020").unwrap();
+/// assert_eq!(derive_xrpl_address(&key).unwrap(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+/// ```
+pub fn derive_xrpl_address(public_key: &[u8]) -> Result<String, AuthError> {
+    key_type(public_key)?;
+
+    let account_id = Ripemd160::digest(Sha256::digest(public_key));
+    let mut versioned = Vec::with_capacity(21);
+    versioned.push(0x00);
+    versioned.extend_from_slice(&account_id);
+
+    Ok(bs58::encode(versioned)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check()
+        .into_string())
+}
+
+/// The message a proposer signs
+///
+/// `SIGNING_DOMAIN || layer (1) || canonical payload`. The payload does not
+/// record the governance layer, so it is signed alongside it.
+pub fn signing_message(proposal: &Proposal) -> Result<Vec<u8>, CanonicalizeError> {
+    let payload = canonicalize(proposal)?;
+    let mut message = Vec::with_capacity(SIGNING_DOMAIN.len() + 1 + payload.bytes.len());
+    message.extend_from_slice(SIGNING_DOMAIN);
+    message.push(layer_tag(proposal.layer));
+    message.extend_from_slice(&payload.bytes);
+    Ok(message)
+}
+
+/// Verify the proposer's signature, then run Channel A
+///
+/// The signature must cover `signing_message(proposal)`, so a signature
+/// stays valid across formatting-only edits and is invalidated by any
+/// semantic change, including a change of layer.
+pub fn verify_proposal_signed(
+    proposal: &Proposal,
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<ChannelAVerdict, AuthError> {
+    verify_signature(&signing_message(proposal)?, signature, public_key)?;
+
+    let derived = derive_xrpl_address(public_key)?;
+    if derived != proposal.proposer {
+        return Err(AuthError::ProposerMismatch {
+            claimed: proposal.proposer.clone(),
+            derived,
+        });
+    }
+
+    Ok(verify_proposal(proposal))
+}
+
+fn layer_tag(layer: GovernanceLayer) -> u8 {
+    match layer {
+        GovernanceLayer::L0Immutable => 0,
+        GovernanceLayer::L1Constitutional => 1,
+        GovernanceLayer::L2Operational => 2,
+        GovernanceLayer::L3Execution => 3,
+    }
+}
+
+enum KeyType {
+    Ed25519,
+    Secp256k1,
+}
+
+fn key_type(public_key: &[u8]) -> Result<KeyType, AuthError> {
+    match public_key {
+        [ED25519_PREFIX, rest @ ..] if rest.len() == 32 => Ok(KeyType::Ed25519),
+        [0x02 | 0x03, rest @ ..] if rest.len() == 32 => Ok(KeyType::Secp256k1),
+        _ => Err(AuthError::InvalidPublicKey),
+    }
+}
+
+fn verify_signature(message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<(), AuthError> {
+    match key_type(public_key)? {
+        KeyType::Ed25519 => {
+            let key_bytes: [u8; 32] = public_key[1..]
+                .try_into()
+                .map_err(|_| AuthError::InvalidPublicKey)?;
+            let key =
+                Ed25519Key::from_bytes(&key_bytes).map_err(|_| AuthError::InvalidPublicKey)?;
+            let signature = Ed25519Signature::from_slice(signature)
+                .map_err(|_| AuthError::MalformedSignature)?;
+            key.verify_strict(message, &signature)
+                .map_err(|_| AuthError::InvalidSignature)
+        }
+        KeyType::Secp256k1 => {
+            let key = Secp256k1Key::from_sec1_bytes(public_key)
+                .map_err(|_| AuthError::InvalidPublicKey)?;
+            let signature = Secp256k1Signature::from_der(signature)
+                .map_err(|_| AuthError::MalformedSignature)?;
+            key.verify_prehash(&sha512_half(message), &signature)
+                .map_err(|_| AuthError::InvalidSignature)
+        }
+    }
+}
+
+/// First 32 bytes of SHA-512, XRPL's signing hash
+fn sha512_half(message: &[u8]) -> [u8; 32] {
+    let digest = Sha512::digest(message);
+    let mut half = [0u8; 32];
+    half.copy_from_slice(&digest[..32]);
+    half
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey as Ed25519SigningKey};
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey as Secp256k1SigningKey};
+
+    fn ed25519_keypair(seed: u8) -> (Ed25519SigningKey, Vec<u8>) {
+        let signing = Ed25519SigningKey::from_bytes(&[seed; 32]);
+        let mut public = vec![ED25519_PREFIX];
+        public.extend_from_slice(signing.verifying_key().as_bytes());
+        (signing, public)
+    }
+
+    fn proposal_for(public_key: &[u8], text: &str) -> Proposal {
+        Proposal::new(
+            derive_xrpl_address(public_key).unwrap(),
+            r#"{"action": "transfer", "amount": 100}"#.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    fn payload(proposal: &Proposal) -> Vec<u8> {
+        signing_message(proposal).unwrap()
+    }
+
+    #[test]
+    fn test_known_address_derivation() {
+        let key = hex::decode("0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020")
+            .unwrap();
+        assert_eq!(
+            derive_xrpl_address(&key).unwrap(),
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+        );
+        assert!(matches!(
+            derive_xrpl_address(&key[1..]),
+            Err(AuthError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn test_valid_ed25519_signature() {
+        let (signing, public) = ed25519_keypair(9);
+        let proposal = proposal_for(&public, "Transfer 100 tokens");
+        let signature = signing.sign(&payload(&proposal));
+
+        let verdict = verify_proposal_signed(&proposal, &signature.to_bytes(), &public).unwrap();
+        assert_eq!(verdict, verify_proposal(&proposal));
+        assert!(verdict.pass);
+    }
+
+    #[test]
+    fn test_valid_secp256k1_signature() {
+        let signing = Secp256k1SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let public = signing
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        let proposal = proposal_for(&public, "Transfer 100 tokens");
+
+        let signature: Secp256k1Signature = signing
+            .sign_prehash(&sha512_half(&payload(&proposal)))
+            .unwrap();
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        assert!(
+            verify_proposal_signed(&proposal, signature.to_der().as_bytes(), &public)
+                .unwrap()
+                .pass
+        );
+    }
+
+    #[test]
+    fn test_wrong_key_rejected() {
+        let (signing, public) = ed25519_keypair(9);
+        let (_, other_public) = ed25519_keypair(10);
+        let proposal = proposal_for(&public, "Transfer 100 tokens");
+        let signature = signing.sign(&payload(&proposal)).to_bytes();
+
+        // Signature does not verify under another key
+        assert!(matches!(
+            verify_proposal_signed(&proposal, &signature, &other_public),
+            Err(AuthError::InvalidSignature)
+        ));
+
+        // A valid signature from a key that is not the claimed proposer
+        let (other_signing, other_public) = ed25519_keypair(10);
+        let signature = other_signing.sign(&payload(&proposal)).to_bytes();
+        assert!(matches!(
+            verify_proposal_signed(&proposal, &signature, &other_public),
+            Err(AuthError::ProposerMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_signature_over_different_payload_rejected() {
+        let (signing, public) = ed25519_keypair(9);
+        let signed = proposal_for(&public, "Transfer 100 tokens");
+        let submitted = proposal_for(&public, "Transfer 100000 tokens");
+        let signature = signing.sign(&payload(&signed)).to_bytes();
+
+        assert!(matches!(
+            verify_proposal_signed(&submitted, &signature, &public),
+            Err(AuthError::InvalidSignature)
+        ));
+        // Formatting-only edits keep the signature valid
+        let reformatted = proposal_for(&public, "  TRANSFER 100 tokens!");
+        assert!(verify_proposal_signed(&reformatted, &signature, &public).is_ok());
+
+        // The same payload resubmitted under another layer does not
+        let mut relayered = signed.clone();
+        relayered.layer = GovernanceLayer::L1Constitutional;
+        assert!(matches!(
+            verify_proposal_signed(&relayered, &signature, &public),
+            Err(AuthError::InvalidSignature)
+        ));
+
+        // Nor does a signature over the bare canonical payload
+        let bare = signing
+            .sign(&canonicalize(&signed).unwrap().bytes)
+            .to_bytes();
+        assert!(matches!(
+            verify_proposal_signed(&signed, &bare, &public),
+            Err(AuthError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_malformed_inputs() {
+        let (_, public) = ed25519_keypair(9);
+        let proposal = proposal_for(&public, "Transfer 100 tokens");
+
+        assert!(matches!(
+            verify_proposal_signed(&proposal, &[0u8; 10], &public),
+            Err(AuthError::MalformedSignature)
+        ));
+        assert!(matches!(
+            verify_proposal_signed(&proposal, &[0u8; 64], &[0x04; 33]),
+            Err(AuthError::InvalidPublicKey)
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+//! Banned Keywords
+//!
+//! Governance may ban phrases outright (e.g. references to prohibited
+//! actions). The list is explicit, consensus-relevant data: every oracle
+//! must enforce the same `BannedKeywordList` to agree on verdicts.
+//!
+//! Phrases and proposal text are both normalized with the canonical text
+//! normalization, so casing and punctuation do not matter. Matching is on
+//! whole words: `"drain treasury"` matches "DRAIN, treasury!" but `"ban"`
+//! does not match "bandwidth". Normalization deletes punctuation rather
+//! than splitting on it, so `"self-destruct"` matches "Self-Destruct" and
+//! "selfdestruct" but not "self destruct".
+
+use aho_corasick::{AhoCorasick, BuildError, MatchKind};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::canonicalize::normalize_text;
+
+/// Why a banned keyword list could not be built
+#[derive(Debug, Error)]
+pub enum BannedKeywordError {
+    #[error("Banned keyword list is too large to compile: {0}")]
+    TooLarge(#[source] BuildError),
+}
+
+/// A set of banned phrases, matched with Aho-Corasick
+///
+/// Serializes as the plain list of phrases; deserializing a list too large
+/// to compile is an error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct BannedKeywordList {
+    /// Phrases as configured
+    phrases: Vec<String>,
+    /// Indexes into `phrases` for each automaton pattern
+    pattern_phrases: Vec<usize>,
+    /// Automaton over the space-padded normalized phrases
+    automaton: AhoCorasick,
+}
+
+impl BannedKeywordList {
+    /// Build a list from phrases
+    ///
+    /// Phrases that normalize to nothing (e.g. pure punctuation) can never
+    /// match and are kept only for display.
+    ///
+    /// # Panics
+    ///
+    /// If the phrases exceed the automaton's size limits. Use `try_new` for
+    /// lists that come from configuration.
+    pub fn new<I, S>(phrases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::try_new(phrases).expect("banned keyword automaton exceeds size limits")
+    }
+
+    /// Build a list from phrases, failing if they exceed the automaton's size limits
+    pub fn try_new<I, S>(phrases: I) -> Result<Self, BannedKeywordError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let phrases: Vec<String> = phrases.into_iter().map(Into::into).collect();
+
+        let mut pattern_phrases = Vec::new();
+        let mut patterns = Vec::new();
+        for (i, phrase) in phrases.iter().enumerate() {
+            let normalized = normalize_text(phrase);
+            if !normalized.is_empty() {
+                pattern_phrases.push(i);
+                patterns.push(format!(" {} ", normalized));
+            }
+        }
+
+        let automaton = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostFirst)
+            .build(patterns)
+            .map_err(BannedKeywordError::TooLarge)?;
+
+        Ok(Self {
+            phrases,
+            pattern_phrases,
+            automaton,
+        })
+    }
+
+    /// The configured phrases
+    pub fn phrases(&self) -> &[String] {
+        &self.phrases
+    }
+}
+
+impl PartialEq for BannedKeywordList {
+    fn eq(&self, other: &Self) -> bool {
+        self.phrases == other.phrases
+    }
+}
+
+impl TryFrom<Vec<String>> for BannedKeywordList {
+    type Error = BannedKeywordError;
+
+    fn try_from(phrases: Vec<String>) -> Result<Self, Self::Error> {
+        Self::try_new(phrases)
+    }
+}
+
+impl From<BannedKeywordList> for Vec<String> {
+    fn from(list: BannedKeywordList) -> Self {
+        list.phrases
+    }
+}
+
+/// First banned phrase found in the normalized text
+///
+/// Returns the phrase as configured in the list. When several match, the
+/// one occurring earliest in the text wins.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{check_banned_keywords, BannedKeywordList};
+///
+/// let list = BannedKeywordList::new(["drain treasury"]);
+/// assert_eq!(check_banned_keywords("Then DRAIN, treasury!", &list), Some("drain treasury".to_string()));
+/// assert_eq!(check_banned_keywords("Fund the treasury", &list), None);
+/// ```
+pub fn check_banned_keywords(text: &str, list: &BannedKeywordList) -> Option<String> {
+    let haystack = format!(" {} ", normalize_text(text));
+    list.automaton
+        .find(&haystack)
+        .map(|m| list.phrases[list.pattern_phrases[m.pattern().as_usize()]].clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_banned_phrase_ignores_case_and_punctuation() {
+        let list = BannedKeywordList::new(["Drain Treasury", "self-destruct"]);
+
+        assert_eq!(
+            check_banned_keywords("First, DRAIN... Treasury? Now!", &list),
+            Some("Drain Treasury".to_string())
+        );
+        assert_eq!(
+            check_banned_keywords("Trigger the SELF DESTRUCT sequence", &list),
+            None
+        );
+        assert_eq!(
+            check_banned_keywords("Trigger the self-destruct sequence", &list),
+            Some("self-destruct".to_string())
+        );
+    }
+
+    #[test]
+    fn test_clean_text_and_word_boundaries() {
+        let list = BannedKeywordList::new(["ban", "!!!"]);
+        assert_eq!(
+            check_banned_keywords("Increase bandwidth for the urban node", &list),
+            None
+        );
+        assert_eq!(
+            check_banned_keywords("Ban it", &list),
+            Some("ban".to_string())
+        );
+        assert_eq!(check_banned_keywords("", &list), None);
+    }
+
+    #[test]
+    fn test_earliest_match_wins() {
+        let list = BannedKeywordList::new(["mint", "burn"]);
+        assert_eq!(
+            check_banned_keywords("Burn then mint", &list),
+            Some("burn".to_string())
+        );
+    }
+
+    #[test]
+    fn test_serde_as_phrase_list() {
+        let list = BannedKeywordList::new(["drain treasury", "Mint"]);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"["drain treasury","Mint"]"#);
+
+        let back: BannedKeywordList = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, list);
+        assert_eq!(
+            check_banned_keywords("mint more", &back),
+            Some("Mint".to_string())
+        );
+
+        assert_eq!(
+            BannedKeywordList::try_new(["drain treasury", "Mint"]).unwrap(),
+            list
+        );
+        assert!(serde_json::from_str::<BannedKeywordList>(r#"{"drain": true}"#).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! Batch Verification
+//!
+//! Verifies many proposals at once across worker threads. Each verdict is
+//! computed independently, so results are identical to calling
+//! `verify_proposal` in a loop and are always returned in input order.
+
+use serde::{Deserialize, Serialize};
+use std::thread;
+
+use super::{verify_proposal, verify_proposal_with, FailureReason, VerificationConfig};
+use crate::types::{ChannelAVerdict, Proposal};
+
+/// Verify a batch of proposals in parallel
+///
+/// Work is split into contiguous chunks, one per available CPU; the
+/// returned verdicts line up index-for-index with `proposals`.
+pub fn verify_batch(proposals: &[Proposal]) -> Vec<ChannelAVerdict> {
+    map_parallel(proposals, verify_proposal)
+}
+
+/// Apply `verify` to each proposal across worker threads, in input order
+fn map_parallel<T, F>(proposals: &[Proposal], verify: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&Proposal) -> T + Sync,
+{
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let chunk_size = proposals.len().div_ceil(workers).max(1);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = proposals
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let verify = &verify;
+                scope.spawn(move || chunk.iter().map(verify).collect::<Vec<_>>())
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("verification worker panicked"))
+            .collect()
+    })
+}
+
+/// Epoch-level summary of a verified batch
+///
+/// Each failure is counted once, under its `FailureReason`, so the
+/// `failed_*` counts sum to `failed`. The complexity statistics cover the
+/// proposals that were scored: L0 targets and canonicalization errors fail
+/// before scoring and are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// Number of proposals verified
+    pub total: u64,
+    /// Proposals that passed
+    pub passed: u64,
+    /// Proposals that failed for any reason
+    pub failed: u64,
+    /// Failures targeting L0
+    pub failed_l0: u64,
+    /// Failures to canonicalize the logic AST
+    pub failed_canonicalization: u64,
+    /// Failures on complexity above the layer's limit
+    pub failed_complexity: u64,
+    /// Failures on a paradox
+    pub failed_paradox: u64,
+    /// Failures on a dependency cycle
+    pub failed_cycle: u64,
+    /// Failures on unrecognized references
+    pub failed_references: u64,
+    /// Failures on banned content
+    pub failed_banned: u64,
+    /// Lowest complexity score among scored proposals
+    pub min_complexity: Option<u64>,
+    /// Highest complexity score among scored proposals
+    pub max_complexity: Option<u64>,
+    /// Lower median score among scored proposals (element `(n - 1) / 2` when sorted)
+    pub median_complexity: Option<u64>,
+}
+
+/// Verify a batch and summarize the outcomes
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::summarize_batch;
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let proposals = vec![Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "noop"}"#.to_string(),
+///     "This statement is false".to_string(),
+///     GovernanceLayer::L2Operational,
+/// )];
+///
+/// let summary = summarize_batch(&proposals);
+/// assert_eq!(summary.failed_paradox, 1);
+/// ```
+pub fn summarize_batch(proposals: &[Proposal]) -> BatchSummary {
+    summarize_batch_with(proposals, &VerificationConfig::default())
+}
+
+/// Verify a batch with explicit pipeline settings and summarize the outcomes
+pub fn summarize_batch_with(proposals: &[Proposal], config: &VerificationConfig) -> BatchSummary {
+    let detailed = map_parallel(proposals, |proposal| verify_proposal_with(proposal, config));
+    let mut summary = BatchSummary {
+        total: detailed.len() as u64,
+        ..Default::default()
+    };
+
+    let mut scores = Vec::with_capacity(detailed.len());
+    for result in &detailed {
+        let count = match result.failure_reason {
+            None => &mut summary.passed,
+            Some(FailureReason::L0NotTargetable) => &mut summary.failed_l0,
+            Some(FailureReason::CanonicalizationError) => &mut summary.failed_canonicalization,
+            Some(FailureReason::ComplexityExceeded) => &mut summary.failed_complexity,
+            Some(FailureReason::ParadoxFound) => &mut summary.failed_paradox,
+            Some(FailureReason::CycleFound) => &mut summary.failed_cycle,
+            Some(FailureReason::UnknownReferences) => &mut summary.failed_references,
+            Some(FailureReason::BannedContent) => &mut summary.failed_banned,
+        };
+        *count += 1;
+
+        let scored = !matches!(
+            result.failure_reason,
+            Some(FailureReason::L0NotTargetable | FailureReason::CanonicalizationError)
+        );
+        if scored {
+            scores.push(result.verdict.complexity_score);
+        }
+    }
+    summary.failed = summary.total - summary.passed;
+
+    scores.sort_unstable();
+    summary.min_complexity = scores.first().copied();
+    summary.max_complexity = scores.last().copied();
+    if !scores.is_empty() {
+        summary.median_complexity = Some(scores[(scores.len() - 1) / 2]);
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel_a::ComplexityLimits;
+    use crate::types::GovernanceLayer;
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_verify_batch_preserves_order() {
+        let proposals: Vec<Proposal> = (0..17)
+            .map(|i| {
+                if i % 3 == 0 {
+                    proposal("{}", "This statement is false")
+                } else {
+                    proposal(&format!(r#"{{"amount": {}}}"#, i), "Transfer tokens")
+                }
+            })
+            .collect();
+
+        let batch = verify_batch(&proposals);
+        let sequential: Vec<ChannelAVerdict> = proposals.iter().map(verify_proposal).collect();
+        assert_eq!(batch, sequential);
+    }
+
+    #[test]
+    fn test_summarize_batch_counts() {
+        let mut l0 = proposal(r#"{"action": "noop"}"#, "Amend the core");
+        l0.layer = GovernanceLayer::L0Immutable;
+        let mut l1 = proposal(r#"{"action": "transfer"}"#, "Transfer tokens to the fund");
+        l1.layer = GovernanceLayer::L1Constitutional;
+        let config = VerificationConfig {
+            complexity_limits: ComplexityLimits::default()
+                .with_limit(GovernanceLayer::L1Constitutional, 10),
+            ..Default::default()
+        };
+        let proposals = vec![
+            proposal(
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            proposal(r#"{"action": "noop"}"#, "Increase the quorum to 15%"),
+            // Both a paradox and a cycle; counted once, as a paradox
+            proposal(
+                r#"{"a": {"value": "$ref:a"}}"#,
+                "This proposal passes iff it fails",
+            ),
+            proposal(r#"{"a": {"value": "$ref:a"}}"#, "Self-referential logic"),
+            proposal("not json", "Malformed AST"),
+            l0,
+            l1,
+        ];
+        let detailed: Vec<_> = proposals
+            .iter()
+            .map(|p| verify_proposal_with(p, &config))
+            .collect();
+        // Over the L1 limit but within the global one
+        assert_eq!(
+            detailed[6].failure_reason,
+            Some(FailureReason::ComplexityExceeded)
+        );
+        assert!(detailed[6].verdict.complexity_score <= crate::types::config::MAX_COMPLEXITY);
+
+        let summary = summarize_batch_with(&proposals, &config);
+        assert_eq!(summary.total, 7);
+        assert_eq!(summary.passed, 2);
+        assert_eq!(summary.failed, 5);
+        assert_eq!(summary.failed_paradox, 1);
+        assert_eq!(summary.failed_cycle, 1);
+        assert_eq!(summary.failed_complexity, 1);
+        assert_eq!(summary.failed_canonicalization, 1);
+        assert_eq!(summary.failed_l0, 1);
+        assert_eq!(summary.failed_references + summary.failed_banned, 0);
+
+        // Hard fails are not scored, so they do not drag the statistics to 0
+        let mut scores: Vec<u64> = [0, 1, 2, 3, 6]
+            .map(|i| detailed[i].verdict.complexity_score)
+            .to_vec();
+        scores.sort_unstable();
+        assert_eq!(summary.min_complexity, scores.first().copied());
+        assert_ne!(summary.min_complexity, Some(0));
+        assert_eq!(summary.max_complexity, scores.last().copied());
+        assert_eq!(summary.median_complexity, Some(scores[2]));
+    }
+
+    #[test]
+    fn test_summarize_empty_batch() {
+        let summary = summarize_batch(&[]);
+        assert_eq!(summary, BatchSummary::default());
+    }
+}
@@ -0,0 +1,238 @@
+//! Verification Result Cache
+//!
+//! An API server fronting an oracle sees the same proposal verified many
+//! times in quick succession. `TtlVerificationCache` memoizes verdicts by
+//! canonical hash, so textually different but canonically identical
+//! submissions share an entry.
+//!
+//! Entries expire after a fixed TTL. The key also carries the canonical
+//! form version: after a rule upgrade bumps
+//! `config::CANONICAL_FORM_VERSION`, entries computed under the old form
+//! are never served, regardless of TTL.
+//!
+//! L0 proposals and proposals that cannot be canonicalized are verified
+//! directly and never cached; both hard-fail without running the pipeline.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::{canonicalize, verify_proposal};
+use crate::types::{config, ChannelAVerdict, GovernanceLayer, Proposal};
+
+/// Cache key: canonical hash and the canonical form version it was computed under
+type CacheKey = ([u8; 32], u8);
+
+/// Time-bounded cache of Channel A verdicts
+///
+/// Safe to share between threads; the lock is not held while verifying.
+#[derive(Debug)]
+pub struct TtlVerificationCache {
+    ttl: Duration,
+    version: u8,
+    entries: Mutex<HashMap<CacheKey, (ChannelAVerdict, Instant)>>,
+}
+
+impl TtlVerificationCache {
+    /// Cache verdicts for `ttl` under the current canonical form version
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_version(ttl, config::CANONICAL_FORM_VERSION)
+    }
+
+    /// Cache verdicts for `ttl` under an explicit canonical form version
+    pub fn with_version(ttl: Duration, version: u8) -> Self {
+        Self {
+            ttl,
+            version,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Canonical form version new entries are keyed under
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Switch to a new canonical form version, dropping entries from other versions
+    pub fn set_version(&mut self, version: u8) {
+        self.version = version;
+        self.lock().retain(|(_, v), _| *v == version);
+    }
+
+    /// Return the cached verdict for `proposal`, verifying it on a miss
+    ///
+    /// The verdict is always identical to `verify_proposal(proposal)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::TtlVerificationCache;
+    /// use constitution_dao_core::{verify_proposal, GovernanceLayer, Proposal};
+    /// use std::time::Duration;
+    ///
+    /// let cache = TtlVerificationCache::new(Duration::from_secs(60));
+    /// let proposal = Proposal::new(
+    ///     "rAddr".to_string(),
+    ///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+    ///     "Transfer 100 tokens".to_string(),
+    ///     GovernanceLayer::L2Operational,
+    /// );
+    ///
+    /// assert_eq!(cache.get_or_verify(&proposal), verify_proposal(&proposal));
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn get_or_verify(&self, proposal: &Proposal) -> ChannelAVerdict {
+        self.get_or_verify_at(proposal, Instant::now())
+    }
+
+    /// `get_or_verify` as of `now`, for callers that manage their own clock
+    pub fn get_or_verify_at(&self, proposal: &Proposal, now: Instant) -> ChannelAVerdict {
+        if proposal.layer == GovernanceLayer::L0Immutable {
+            return verify_proposal(proposal);
+        }
+        let Ok(payload) = canonicalize(proposal) else {
+            return verify_proposal(proposal);
+        };
+        let key = (payload.hash, self.version);
+
+        if let Some((verdict, inserted)) = self.lock().get(&key) {
+            if now.saturating_duration_since(*inserted) < self.ttl {
+                return verdict.clone();
+            }
+        }
+
+        let verdict = verify_proposal(proposal);
+        self.lock().insert(key, (verdict.clone(), now));
+        verdict
+    }
+
+    /// Drop entries that have expired as of `now`
+    pub fn evict_expired(&self, now: Instant) {
+        let ttl = self.ttl;
+        self.lock()
+            .retain(|_, (_, inserted)| now.saturating_duration_since(*inserted) < ttl);
+    }
+
+    /// Number of entries currently held, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, (ChannelAVerdict, Instant)>> {
+        // A panic while holding the lock cannot leave a map entry half-written
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(30);
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    /// Plant a verdict the pipeline would never produce, to tell hits from re-verification
+    fn plant(
+        cache: &TtlVerificationCache,
+        p: &Proposal,
+        version: u8,
+        at: Instant,
+    ) -> ChannelAVerdict {
+        let planted = ChannelAVerdict::fail(1, true, true);
+        cache.lock().insert(
+            (canonicalize(p).unwrap().hash, version),
+            (planted.clone(), at),
+        );
+        planted
+    }
+
+    #[test]
+    fn test_hit_within_ttl() {
+        let cache = TtlVerificationCache::new(TTL);
+        let p = proposal(
+            r#"{"action": "transfer", "amount": 100}"#,
+            "Transfer 100 tokens",
+        );
+        let start = Instant::now();
+        let planted = plant(&cache, &p, config::CANONICAL_FORM_VERSION, start);
+
+        assert_eq!(cache.get_or_verify_at(&p, start + TTL / 2), planted);
+
+        // A canonically identical submission hits the same entry
+        let reformatted = proposal(
+            r#"{"amount":100,"action":"transfer"}"#,
+            "transfer  100 TOKENS",
+        );
+        assert_eq!(
+            cache.get_or_verify_at(&reformatted, start + TTL / 2),
+            planted
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_miss_after_expiry() {
+        let cache = TtlVerificationCache::new(TTL);
+        let p = proposal(
+            r#"{"action": "transfer", "amount": 100}"#,
+            "Transfer 100 tokens",
+        );
+        let start = Instant::now();
+        let planted = plant(&cache, &p, config::CANONICAL_FORM_VERSION, start);
+
+        let fresh = cache.get_or_verify_at(&p, start + TTL);
+        assert_ne!(fresh, planted);
+        assert_eq!(fresh, verify_proposal(&p));
+
+        // The refreshed entry is served until it expires in turn
+        assert_eq!(cache.get_or_verify_at(&p, start + TTL + TTL / 2), fresh);
+        cache.evict_expired(start + 3 * TTL);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_version_change_invalidates() {
+        let mut cache = TtlVerificationCache::with_version(TTL, 1);
+        let p = proposal(r#"{"a": {"value": "$ref:a"}}"#, "Self-referential logic");
+        let start = Instant::now();
+        let planted = plant(&cache, &p, 1, start);
+        assert_eq!(cache.get_or_verify_at(&p, start), planted);
+
+        // Entries from another canonical form version are never served
+        cache.set_version(2);
+        assert_eq!(cache.get_or_verify_at(&p, start), verify_proposal(&p));
+        assert_eq!(cache.len(), 1);
+
+        let other = TtlVerificationCache::with_version(TTL, 3);
+        plant(&other, &p, 2, start);
+        assert_eq!(other.get_or_verify_at(&p, start), verify_proposal(&p));
+    }
+
+    #[test]
+    fn test_uncacheable_proposals_verified_directly() {
+        let cache = TtlVerificationCache::new(TTL);
+        let broken = proposal("{not json", "Broken");
+        let mut l0 = proposal("{}", "Change the axioms");
+        l0.layer = GovernanceLayer::L0Immutable;
+
+        for p in [broken, l0] {
+            assert_eq!(cache.get_or_verify(&p), verify_proposal(&p));
+        }
+        assert!(cache.is_empty());
+    }
+}
@@ -6,32 +6,165 @@
 //! we define two separate canonical forms derived from the same payload:
 //!
 //! 1. AST Serialization: Parse logic into AST, serialize to canonical JSON with sorted keys
-//! 2. Text Normalization: Lowercase, remove punctuation, normalize whitespace
-//! 3. Canonical Payload: serialized_ast_json + "." + normalized_text
+//! 2. Text Normalization: NFKC, lowercase, remove punctuation, normalize whitespace
+//! 3. Canonical Payload: version_byte + serialized_ast_json + "." + normalized_text
 //! 4. Canonical Hash: sha256(CanonicalPayloadBytes)
+//!
+//! # Version Prefix
+//!
+//! The payload starts with a single byte holding the canonical form version
+//! it was produced under (`config::CANONICAL_FORM_VERSION`). The byte is
+//! hashed with the rest, so a proposal canonicalized under one form can
+//! never share an ID with any proposal under another, and a fraud proof's
+//! payload records which form the challenged oracle used. Payloads carrying
+//! any other version are rejected with `UnsupportedVersion` when split.
+//! Introduced in canonical form version 4.
+//!
+//! # Number Formatting
+//!
+//! The AST is re-serialized by `write_canonical_json` rather than by
+//! `serde_json::to_vec`, so the canonical bytes never depend on how a given
+//! serde_json version (or feature set) chooses to print numbers:
+//!
+//! - Integers are printed in plain decimal
+//! - Integral floats that fit in `i64`/`u64` are printed as integers, so
+//!   `1`, `1.0`, `1.00` and `1e0` all become `1`; `-0.0` becomes `0`
+//! - Other floats are printed with `ryu` (shortest round-trippable
+//!   representation), so `0.1` is always `0.1` and `1e20` is `1e20`
+//! - Non-finite numbers cannot occur in parsed JSON (`NaN`, `Infinity` and
+//!   out-of-range literals like `1e400` are parse errors); one reaching the
+//!   writer is a `NonFiniteNumber` error rather than a guessed encoding
+//!
+//! Folding integral floats was introduced in canonical form version 3.
+//!
+//! # Compatibility Characters
+//!
+//! Text is NFKC-normalized before anything else, so characters that render
+//! like their base form but are distinct code points collapse to it:
+//! fullwidth `Ｔｈｉｓ` becomes `This`, superscript `²` becomes `2`, and the
+//! ligature `ﬁ` becomes `fi`. Without this, such text would dodge paradox
+//! detection and hash differently from its plain form. NFKC subsumes NFC,
+//! so precomposed `é` (U+00E9) and `e` followed by a combining acute
+//! (U+0301) also normalize identically, whatever order stacked marks were
+//! typed in. Only the text is normalized; strings in the AST are hashed
+//! exactly as submitted. Introduced in
+//! canonical form version 2 (`config::CANONICAL_FORM_VERSION`); the
+//! `unicode-normalization` crate is pinned exactly because its Unicode
+//! tables are consensus-relevant.
+//!
+//! # Null Values
+//!
+//! `null` is a value like any other: `{"a": null}` and `{}` are different
+//! ASTs with different canonical hashes, at every nesting level and inside
+//! arrays. Nulls are never stripped; changing this would change proposal IDs
+//! and requires a canonical form version bump.
+//!
+//! # String Escapes
+//!
+//! Escapes in AST strings are validated before parsing rather than left to
+//! serde_json's defaults, since how an ill-formed escape decodes would feed
+//! straight into the canonical bytes. Only the JSON escapes (`\"`, `\\`, `\/`,
+//! `\b`, `\f`, `\n`, `\r`, `\t`, `\uXXXX`) are accepted, and a `\u` escape
+//! must encode a Unicode scalar value: a high surrogate must be immediately
+//! followed by an escaped low surrogate, and a low surrogate may not appear
+//! alone. Violations are `InvalidUnicodeEscape` errors.
+//!
+//! # Duplicate Keys
+//!
+//! An object that repeats a key is ambiguous: serde_json keeps the last
+//! value, other parsers keep the first or reject it, so `{"a":1,"a":2}`
+//! could mean different things to the proposer's tooling and to the
+//! oracles. Such ASTs are rejected with `DuplicateKey` at any nesting
+//! level. Keys are compared after unescaping, so `"a"` and `"\u0061"` are
+//! the same key.
+//!
+//! # Quick Rejection
+//!
+//! `quick_ast_check` runs before the parser and rejects obvious garbage
+//! (empty input, a first byte that cannot start a JSON value, unbalanced or
+//! mismatched brackets, an unterminated string) without building a `Value`
+//! tree. It is conservative: it never rejects input the parser would
+//! accept, and it reports rejections as `JsonParseError` like the parser
+//! does, so callers see the same error kinds either way.
+//!
+//! # Metadata Stripping
+//!
+//! `CanonicalizeOptions::strip_key_prefixes` removes object members whose
+//! key starts with a configured prefix (e.g. `_meta`), at every depth,
+//! before the AST is serialized. Proposals that differ only in tooling
+//! metadata then share an ID and complexity score. The strip set is
+//! consensus-relevant: oracles using different strip sets compute different
+//! hashes for the same proposal, so it must be fixed network-wide. The
+//! default strips nothing.
+//!
+//! # Array Semantics
+//!
+//! Arrays are ordered by default. `CanonicalizeOptions::array_semantics`
+//! declares, per object key (at every depth), that the array under that key
+//! is unordered:
+//!
+//! - `Multiset`: elements are sorted, duplicates kept (`["b","a","a"]`
+//!   becomes `["a","a","b"]`)
+//! - `Set`: elements are sorted and duplicates removed (`["b","a","a"]`
+//!   becomes `["a","b"]`)
+//!
+//! Elements are compared by their own canonical bytes, so nested objects
+//! sort deterministically. Like the strip set, the mapping is
+//! consensus-affecting: it changes hashes and complexity scores, so it must
+//! be fixed network-wide. Only the canonical bytes change; checks that read
+//! the parsed AST, such as `$ref` index references, see the submitted order.
 
-use sha2::{Sha256, Digest};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::ops::Range;
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
-use crate::types::Proposal;
+use crate::types::{config, Proposal};
 
 /// Errors that can occur during canonicalization
 #[derive(Debug, Error)]
 pub enum CanonicalizeError {
     #[error("Failed to parse logic AST as JSON: {0}")]
     JsonParseError(#[from] serde_json::Error),
-    #[error("Invalid UTF-8 in payload")]
-    Utf8Error,
+    /// `offset` counts from the start of the payload, version byte included
+    #[error("Invalid UTF-8 in canonical payload at byte {offset}")]
+    Utf8Error { offset: usize },
+    #[error("Extra data after logic AST at byte {offset}")]
+    TrailingData { offset: usize },
+    #[error("Canonical payload has no '.' separator after the logic AST")]
+    MissingSeparator,
+    #[error("Invalid escape sequence in AST string at byte {offset}")]
+    InvalidUnicodeEscape { offset: usize },
+    #[error("Duplicate key {0:?} in logic AST object")]
+    DuplicateKey(String),
+    #[error("Non-finite number in logic AST")]
+    NonFiniteNumber,
+    #[error(
+        "Unsupported canonical form version {0} (expected {})",
+        config::CANONICAL_FORM_VERSION
+    )]
+    UnsupportedVersion(u8),
 }
 
 /// The canonical representation of a proposal
 #[derive(Debug, Clone)]
 pub struct CanonicalPayload {
-    /// The canonical payload bytes (AST + "." + normalized_text)
+    /// Canonical form version, also the first byte of `bytes`
+    pub version: u8,
+    /// The canonical payload bytes (version + AST + "." + normalized_text)
     pub bytes: Vec<u8>,
     /// SHA-256 hash of the payload (serves as proposal ID)
     pub hash: [u8; 32],
+    /// Byte range of the canonical AST JSON within `bytes`
+    pub ast_range: Range<usize>,
+    /// Byte range of the normalized text within `bytes`
+    pub text_range: Range<usize>,
 }
 
 impl CanonicalPayload {
@@ -39,6 +172,108 @@ impl CanonicalPayload {
     pub fn hash_hex(&self) -> String {
         hex::encode(self.hash)
     }
+
+    /// The canonical AST JSON portion of the payload
+    pub fn ast_bytes(&self) -> &[u8] {
+        &self.bytes[self.ast_range.clone()]
+    }
+
+    /// The normalized text portion of the payload
+    pub fn text_bytes(&self) -> &[u8] {
+        &self.bytes[self.text_range.clone()]
+    }
+
+    /// The normalized text, as hashed
+    ///
+    /// Paradox detection runs on this, so it sees exactly the string the
+    /// proposal ID commits to.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` or `text_range` were modified so the range is no longer
+    /// UTF-8; canonicalization always produces UTF-8 text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::canonicalize;
+    /// use constitution_dao_core::{GovernanceLayer, Proposal};
+    ///
+    /// let proposal = Proposal::new(
+    ///     "rAddr".to_string(),
+    ///     "{}".to_string(),
+    ///     "This proposal pass-es iff it fai.ls!".to_string(),
+    ///     GovernanceLayer::L2Operational,
+    /// );
+    /// let payload = canonicalize(&proposal).unwrap();
+    /// assert_eq!(payload.normalized_text(), "this proposal passes iff it fails");
+    /// ```
+    pub fn normalized_text(&self) -> &str {
+        std::str::from_utf8(self.text_bytes()).expect("normalized text is UTF-8")
+    }
+
+    /// Recover the parsed AST and normalized text from `bytes` alone
+    ///
+    /// Ignores `ast_range` and `text_range`, so it checks exactly what was
+    /// hashed: the separator is the `.` that ends the JSON value, not the
+    /// first `.` in the payload. Fails on a payload that is not in the
+    /// current canonical form version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::canonicalize;
+    /// use constitution_dao_core::{GovernanceLayer, Proposal};
+    ///
+    /// let proposal = Proposal::new(
+    ///     "rAddr".to_string(),
+    ///     r#"{"note": "v1.2"}"#.to_string(),
+    ///     "Apply patch 1.2".to_string(),
+    ///     GovernanceLayer::L2Operational,
+    /// );
+    /// let (ast, text) = canonicalize(&proposal).unwrap().split().unwrap();
+    /// assert_eq!(ast["note"], "v1.2");
+    /// assert_eq!(text, "apply patch 12");
+    /// ```
+    pub fn split(&self) -> Result<(Value, String), CanonicalizeError> {
+        split_payload(&self.bytes).map(|(ast, text)| (ast, text.to_string()))
+    }
+}
+
+/// How the canonical form treats the elements of an array
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArraySemantics {
+    /// Order and duplicates are significant (the default)
+    #[default]
+    Ordered,
+    /// Order is irrelevant, duplicates are significant
+    Multiset,
+    /// Order and duplicates are both irrelevant
+    Set,
+}
+
+/// Consensus-relevant canonicalization settings
+///
+/// The default reproduces the plain canonical form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanonicalizeOptions {
+    /// Object keys starting with any of these prefixes are removed, at
+    /// every depth, before hashing (see "Metadata Stripping")
+    pub strip_key_prefixes: Vec<String>,
+    /// Semantics of arrays stored under the given object keys, at every
+    /// depth (see "Array Semantics"); unlisted arrays are ordered
+    #[serde(default)]
+    pub array_semantics: BTreeMap<String, ArraySemantics>,
+}
+
+impl CanonicalizeOptions {
+    /// Strip `_meta`-prefixed authoring metadata
+    pub fn strip_metadata() -> Self {
+        Self {
+            strip_key_prefixes: vec!["_meta".to_string()],
+            ..Default::default()
+        }
+    }
 }
 
 /// Canonicalize a proposal into deterministic representation
@@ -46,7 +281,7 @@ impl CanonicalPayload {
 /// # Process
 ///
 /// 1. Parse logic_ast as JSON and sort all keys alphabetically (recursive)
-/// 2. Normalize text: lowercase, remove punctuation, single spaces
+/// 2. Normalize text: NFKC, lowercase, remove punctuation, single spaces
 /// 3. Combine: sorted_ast_json + "." + normalized_text
 /// 4. Hash with SHA-256
 ///
@@ -68,29 +303,515 @@ impl CanonicalPayload {
 /// // Text will be normalized: "hello world"
 /// ```
 pub fn canonicalize(proposal: &Proposal) -> Result<CanonicalPayload, CanonicalizeError> {
-    // Step 1: Parse and sort AST JSON
-    let ast: Value = serde_json::from_str(&proposal.logic_ast)?;
-    let sorted_ast = sort_json_keys(&ast);
-    let ast_bytes = serde_json::to_vec(&sorted_ast)?;
+    canonicalize_with(proposal, &CanonicalizeOptions::default())
+}
+
+/// Canonicalize a proposal and return a copy with `id` set to the canonical hash
+///
+/// `Proposal::new` leaves `id` zeroed and nothing in the pipeline fills it
+/// in (`verify_proposal` takes `&Proposal` and never mutates it), so call
+/// this before handing a proposal to code that trusts `proposal.id`. Any
+/// `id` already on the proposal is replaced.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{canonicalize, canonicalize_and_id};
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer"}"#.to_string(),
+///     "Transfer".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let (identified, payload) = canonicalize_and_id(&proposal).unwrap();
+/// assert_eq!(identified.id, payload.hash);
+/// assert_eq!(identified.id, canonicalize(&proposal).unwrap().hash);
+/// ```
+pub fn canonicalize_and_id(
+    proposal: &Proposal,
+) -> Result<(Proposal, CanonicalPayload), CanonicalizeError> {
+    let payload = canonicalize(proposal)?;
+    Ok((proposal.clone().with_id(payload.hash), payload))
+}
+
+/// Canonicalize a proposal with explicit options
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{canonicalize_with, CanonicalizeOptions};
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"_meta": {"tool_version": "1.2"}, "action": "transfer"}"#.to_string(),
+///     "Transfer".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let canonical = canonicalize_with(&proposal, &CanonicalizeOptions::strip_metadata()).unwrap();
+/// assert_eq!(canonical.ast_bytes(), br#"{"action":"transfer"}"#);
+/// ```
+pub fn canonicalize_with(
+    proposal: &Proposal,
+    options: &CanonicalizeOptions,
+) -> Result<CanonicalPayload, CanonicalizeError> {
+    let ast = parse_ast(&proposal.logic_ast)?;
+    canonicalize_value_with(&ast, &proposal.text, options)
+}
+
+/// Parse a logic AST string into a JSON value
+///
+/// Exactly one JSON value is accepted, optionally surrounded by JSON
+/// whitespace. Anything else after it is reported as `TrailingData` with
+/// the byte offset where it starts, so data cannot be smuggled past the AST.
+pub(crate) fn parse_ast(ast_json: &str) -> Result<Value, CanonicalizeError> {
+    quick_ast_check(ast_json)?;
+    validate_escapes(ast_json)?;
+
+    let mut stream = serde_json::Deserializer::from_str(ast_json).into_iter::<Value>();
+    let ast = match stream.next() {
+        Some(result) => result?,
+        // Empty or whitespace-only input: let serde_json report it
+        None => return Ok(serde_json::from_str(ast_json)?),
+    };
+
+    let end = stream.byte_offset();
+    let rest = &ast_json[end..];
+    let trailing = rest.trim_start_matches([' ', '\t', '\n', '\r']);
+    if !trailing.is_empty() {
+        return Err(CanonicalizeError::TrailingData {
+            offset: end + (rest.len() - trailing.len()),
+        });
+    }
+
+    // `Value` keeps only the last of repeated keys; rescan to reject them
+    let FirstDuplicateKey(duplicate) =
+        FirstDuplicateKey::deserialize(&mut serde_json::Deserializer::from_str(&ast_json[..end]))?;
+    if let Some(key) = duplicate {
+        return Err(CanonicalizeError::DuplicateKey(key));
+    }
+
+    Ok(ast)
+}
+
+/// First object key repeated within a single object, in document order
+///
+/// Keys are compared after unescaping, so `"a"` and `"\u0061"` collide.
+/// Repeating a key across different objects is fine.
+struct FirstDuplicateKey(Option<String>);
+
+impl<'de> Deserialize<'de> for FirstDuplicateKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FirstDuplicateKeyVisitor)
+    }
+}
+
+struct FirstDuplicateKeyVisitor;
+
+impl<'de> serde::de::Visitor<'de> for FirstDuplicateKeyVisitor {
+    type Value = FirstDuplicateKey;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(FirstDuplicateKey(None))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut found = None;
+        while let Some(FirstDuplicateKey(nested)) = seq.next_element()? {
+            found = found.or(nested);
+        }
+        Ok(FirstDuplicateKey(found))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut keys = HashSet::new();
+        let mut found = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if found.is_none() && keys.contains(&key) {
+                found = Some(key);
+            } else {
+                keys.insert(key);
+            }
+            let FirstDuplicateKey(nested) = map.next_value()?;
+            found = found.or(nested);
+        }
+        Ok(FirstDuplicateKey(found))
+    }
+}
+
+/// Cheap byte-level pre-scan rejecting clearly malformed ASTs
+///
+/// Only the first JSON value is scanned; data after it is left to the
+/// parser's `TrailingData` check. See "Quick Rejection".
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::quick_ast_check;
+///
+/// assert!(quick_ast_check(r#"{"action": "transfer"}"#).is_ok());
+/// assert!(quick_ast_check(r#"{"action": ["transfer"}"#).is_err());
+/// assert!(quick_ast_check("<html>").is_err());
+/// ```
+pub fn quick_ast_check(ast_json: &str) -> Result<(), CanonicalizeError> {
+    let bytes = ast_json.as_bytes();
+    let reject = |what: String| -> Result<(), CanonicalizeError> {
+        Err(CanonicalizeError::JsonParseError(serde::de::Error::custom(
+            what,
+        )))
+    };
+
+    let Some(start) = bytes
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+    else {
+        return reject("empty logic AST".to_string());
+    };
+    match bytes[start] {
+        b'{' | b'[' => {}
+        b'"' | b'-' | b'0'..=b'9' | b't' | b'f' | b'n' => return Ok(()),
+        other => {
+            return reject(format!(
+                "byte {:?} at {} cannot start a JSON value",
+                other as char, start
+            ))
+        }
+    }
+
+    // Expected closing bracket and the offset of its opener
+    let mut open = Vec::new();
+    let mut string_start = None;
+    let mut i = start;
+    while i < bytes.len() {
+        match (string_start.is_some(), bytes[i]) {
+            (true, b'\\') => i += 1,
+            (true, b'"') => string_start = None,
+            (true, _) => {}
+            (false, b'"') => string_start = Some(i),
+            (false, b'{') => open.push((b'}', i)),
+            (false, b'[') => open.push((b']', i)),
+            (false, close @ (b'}' | b']')) => {
+                if open.pop().map(|(expected, _)| expected) != Some(close) {
+                    return reject(format!("mismatched {:?} at byte {}", close as char, i));
+                }
+                if open.is_empty() {
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if let Some(at) = string_start {
+        reject(format!("unterminated string starting at byte {}", at))
+    } else {
+        let innermost = open.last().map_or(start, |&(_, at)| at);
+        reject(format!(
+            "{} unclosed bracket(s), innermost opened at byte {}",
+            open.len(),
+            innermost
+        ))
+    }
+}
+
+/// Reject escapes that are not JSON escapes or do not encode a scalar value
+///
+/// Only string contents are inspected; structural errors are left to the
+/// JSON parser. See "String Escapes".
+fn validate_escapes(json: &str) -> Result<(), CanonicalizeError> {
+    let bytes = json.as_bytes();
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match (in_string, bytes[i]) {
+            (false, b'"') => in_string = true,
+            (true, b'"') => in_string = false,
+            (true, b'\\') => {
+                let len = escape_len(bytes, i)
+                    .ok_or(CanonicalizeError::InvalidUnicodeEscape { offset: i })?;
+                i += len - 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Ok(())
+}
+
+/// Length of the valid escape sequence starting at the backslash `i`
+fn escape_len(bytes: &[u8], i: usize) -> Option<usize> {
+    match bytes.get(i + 1)? {
+        b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => Some(2),
+        b'u' => match hex_unit(bytes, i + 2)? {
+            // A high surrogate must be followed by an escaped low surrogate
+            0xD800..=0xDBFF => {
+                if bytes.get(i + 6..i + 8) != Some(&b"\\u"[..]) {
+                    return None;
+                }
+                matches!(hex_unit(bytes, i + 8)?, 0xDC00..=0xDFFF).then_some(12)
+            }
+            0xDC00..=0xDFFF => None,
+            _ => Some(6),
+        },
+        _ => None,
+    }
+}
+
+/// Four hex digits starting at `start`, as a UTF-16 code unit
+fn hex_unit(bytes: &[u8], start: usize) -> Option<u16> {
+    let digits = std::str::from_utf8(bytes.get(start..start + 4)?).ok()?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u16::from_str_radix(digits, 16).ok()
+}
+
+/// Split canonical payload bytes into the parsed AST and the normalized text
+///
+/// The version prefix must be the current canonical form version. The
+/// separator is the byte right after the AST's JSON value, so dots inside
+/// it (string values, floats) never split the payload, and the AST half
+/// must parse as exactly one JSON value.
+pub(crate) fn split_payload(bytes: &[u8]) -> Result<(Value, &str), CanonicalizeError> {
+    let (&version, bytes) = bytes
+        .split_first()
+        .ok_or(CanonicalizeError::MissingSeparator)?;
+    if version != config::CANONICAL_FORM_VERSION {
+        return Err(CanonicalizeError::UnsupportedVersion(version));
+    }
+    let separator = json_value_end(bytes)?;
+    match bytes.get(separator) {
+        Some(b'.') => {}
+        Some(_) => return Err(CanonicalizeError::TrailingData { offset: separator }),
+        None => return Err(CanonicalizeError::MissingSeparator),
+    }
+
+    let utf8 = |start: usize| {
+        move |e: std::str::Utf8Error| CanonicalizeError::Utf8Error {
+            offset: 1 + start + e.valid_up_to(),
+        }
+    };
+    let ast_json = std::str::from_utf8(&bytes[..separator]).map_err(utf8(0))?;
+    let text = std::str::from_utf8(&bytes[separator + 1..]).map_err(utf8(separator + 1))?;
+
+    Ok((parse_ast(ast_json)?, text))
+}
+
+/// Length of the JSON value at the start of `bytes`
+fn json_value_end(bytes: &[u8]) -> Result<usize, CanonicalizeError> {
+    match bytes.first() {
+        None => Err(CanonicalizeError::MissingSeparator),
+        // A bare number does not delimit itself (`1.5` may be 1 then "5"),
+        // but normalized text never contains `.`, so the last one separates
+        Some(b'-' | b'0'..=b'9') => bytes
+            .iter()
+            .rposition(|&b| b == b'.')
+            .ok_or(CanonicalizeError::MissingSeparator),
+        Some(_) => {
+            let mut stream = serde_json::Deserializer::from_slice(bytes).into_iter::<IgnoredAny>();
+            match stream.next() {
+                Some(result) => result.map(|_| stream.byte_offset()).map_err(Into::into),
+                None => Err(CanonicalizeError::MissingSeparator),
+            }
+        }
+    }
+}
+
+/// Canonicalize a proposal whose AST is already parsed
+///
+/// Produces byte-identical output to `canonicalize` for the equivalent
+/// string AST, without a serialize/parse round-trip. Only the AST and text
+/// enter the canonical payload.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::canonicalize_value;
+/// use serde_json::json;
+///
+/// let ast = json!({"action": "transfer", "amount": 100});
+/// let canonical = canonicalize_value(&ast, "Transfer").unwrap();
+/// assert_eq!(canonical.ast_bytes(), br#"{"action":"transfer","amount":100}"#);
+/// ```
+pub fn canonicalize_value(ast: &Value, text: &str) -> Result<CanonicalPayload, CanonicalizeError> {
+    canonicalize_value_with(ast, text, &CanonicalizeOptions::default())
+}
+
+/// Canonicalize an already-parsed AST with explicit options
+pub fn canonicalize_value_with(
+    ast: &Value,
+    text: &str,
+    options: &CanonicalizeOptions,
+) -> Result<CanonicalPayload, CanonicalizeError> {
+    canonicalize_versioned(ast, text, options, config::CANONICAL_FORM_VERSION)
+}
+
+/// Canonicalize under an explicit version prefix
+pub(crate) fn canonicalize_versioned(
+    ast: &Value,
+    text: &str,
+    options: &CanonicalizeOptions,
+    version: u8,
+) -> Result<CanonicalPayload, CanonicalizeError> {
+    // Step 1: Sort AST JSON
+    let ast_bytes = canonical_ast_bytes(ast, options)?;
 
     // Step 2: Normalize text
-    let normalized_text = normalize_text(&proposal.text);
+    let normalized_text = normalize_text(text);
+    debug_assert!(
+        is_normalized(&normalized_text),
+        "normalize_text is not idempotent for {:?}",
+        text
+    );
 
-    // Step 3: Combine payload
-    let mut payload = ast_bytes;
+    // Steps 3-4: Combine payload and hash
+    Ok(assemble_payload(version, &ast_bytes, &normalized_text))
+}
+
+/// Build and hash the payload from its already-canonical parts
+pub(crate) fn assemble_payload(
+    version: u8,
+    ast_bytes: &[u8],
+    normalized_text: &str,
+) -> CanonicalPayload {
+    let mut payload = Vec::with_capacity(ast_bytes.len() + normalized_text.len() + 2);
+    payload.push(version);
+    payload.extend(ast_bytes);
+    let ast_range = 1..payload.len();
     payload.push(b'.');
+    let text_start = payload.len();
     payload.extend(normalized_text.as_bytes());
+    let text_range = text_start..payload.len();
 
-    // Step 4: Compute hash
     let hash: [u8; 32] = Sha256::digest(&payload).into();
 
-    Ok(CanonicalPayload {
+    CanonicalPayload {
+        version,
         bytes: payload,
         hash,
+        ast_range,
+        text_range,
+    }
+}
+
+/// The canonical AST JSON bytes (the part of the payload before the `.`)
+pub(crate) fn canonical_ast_bytes(
+    ast: &Value,
+    options: &CanonicalizeOptions,
+) -> Result<Vec<u8>, CanonicalizeError> {
+    let ast = if options.strip_key_prefixes.is_empty() {
+        Cow::Borrowed(ast)
+    } else {
+        Cow::Owned(strip_keys(ast, &options.strip_key_prefixes))
+    };
+
+    let ast = if options.array_semantics.is_empty() {
+        ast
+    } else {
+        Cow::Owned(order_arrays(&ast, &options.array_semantics)?)
+    };
+
+    let mut bytes = Vec::new();
+    write_canonical_json(&sort_json_keys(&ast), &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Recursively sort (and for sets, deduplicate) arrays under keys declared unordered
+fn order_arrays(
+    value: &Value,
+    semantics: &BTreeMap<String, ArraySemantics>,
+) -> Result<Value, CanonicalizeError> {
+    Ok(match value {
+        Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            for (key, v) in map {
+                let v = order_arrays(v, semantics)?;
+                let v = match (semantics.get(key), v) {
+                    (
+                        Some(&kind @ (ArraySemantics::Multiset | ArraySemantics::Set)),
+                        Value::Array(items),
+                    ) => Value::Array(sort_elements(items, kind == ArraySemantics::Set)?),
+                    (_, v) => v,
+                };
+                out.insert(key.clone(), v);
+            }
+            Value::Object(out)
+        }
+        Value::Array(arr) => Value::Array(
+            arr.iter()
+                .map(|v| order_arrays(v, semantics))
+                .collect::<Result<_, _>>()?,
+        ),
+        other => other.clone(),
     })
 }
 
+/// Sort array elements by their canonical bytes, optionally dropping duplicates
+fn sort_elements(items: Vec<Value>, dedup: bool) -> Result<Vec<Value>, CanonicalizeError> {
+    let mut keyed = items
+        .into_iter()
+        .map(|item| {
+            let mut bytes = Vec::new();
+            write_canonical_json(&sort_json_keys(&item), &mut bytes)?;
+            Ok((bytes, item))
+        })
+        .collect::<Result<Vec<_>, CanonicalizeError>>()?;
+
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+    if dedup {
+        keyed.dedup_by(|a, b| a.0 == b.0);
+    }
+    Ok(keyed.into_iter().map(|(_, item)| item).collect())
+}
+
+/// Recursively remove object members whose key starts with any prefix
+fn strip_keys(value: &Value, prefixes: &[String]) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, _)| !prefixes.iter().any(|p| key.starts_with(p.as_str())))
+                .map(|(key, v)| (key.clone(), strip_keys(v, prefixes)))
+                .collect(),
+        ),
+        Value::Array(arr) => Value::Array(arr.iter().map(|v| strip_keys(v, prefixes)).collect()),
+        other => other.clone(),
+    }
+}
+
 /// Recursively sort all keys in a JSON value
+///
+/// `null` members are kept (see "Null Values" in the module docs).
 fn sort_json_keys(value: &Value) -> Value {
     match value {
         Value::Object(map) => {
@@ -104,22 +825,96 @@ fn sort_json_keys(value: &Value) -> Value {
             }
             Value::Object(sorted)
         }
+        Value::Array(arr) => Value::Array(arr.iter().map(sort_json_keys).collect()),
+        // Primitives pass through unchanged
+        other => other.clone(),
+    }
+}
+
+/// Serialize a JSON value into its canonical byte form
+///
+/// Object keys are written in the order the map yields them (already sorted
+/// by `sort_json_keys`); strings use serde_json's escaping; numbers use the
+/// fixed formatting described in the module docs.
+fn write_canonical_json(value: &Value, out: &mut Vec<u8>) -> Result<(), CanonicalizeError> {
+    match value {
+        Value::Object(map) => {
+            out.push(b'{');
+            for (i, (key, v)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key)?;
+                out.push(b':');
+                write_canonical_json(v, out)?;
+            }
+            out.push(b'}');
+        }
         Value::Array(arr) => {
-            Value::Array(arr.iter().map(sort_json_keys).collect())
+            out.push(b'[');
+            for (i, v) in arr.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical_json(v, out)?;
+            }
+            out.push(b']');
         }
-        // Primitives pass through unchanged
-        other => other.clone()
+        Value::String(s) => serde_json::to_writer(&mut *out, s)?,
+        Value::Number(n) => out.extend(canonical_number(n)?.as_bytes()),
+        Value::Bool(b) => out.extend(if *b {
+            b"true".as_slice()
+        } else {
+            b"false".as_slice()
+        }),
+        Value::Null => out.extend(b"null"),
     }
+    Ok(())
+}
+
+/// Format a JSON number deterministically (see "Number Formatting")
+fn canonical_number(n: &serde_json::Number) -> Result<String, CanonicalizeError> {
+    if let Some(u) = n.as_u64() {
+        return Ok(u.to_string());
+    }
+    if let Some(i) = n.as_i64() {
+        return Ok(i.to_string());
+    }
+
+    let f = n
+        .as_f64()
+        .filter(|f| f.is_finite())
+        .ok_or(CanonicalizeError::NonFiniteNumber)?;
+    // 2^64 and -2^63 are exact in f64, so integral values in range convert exactly
+    Ok(
+        if f.fract() == 0.0 && (0.0..18_446_744_073_709_551_616.0).contains(&f) {
+            (f as u64).to_string()
+        } else if f.fract() == 0.0 && (-9_223_372_036_854_775_808.0..0.0).contains(&f) {
+            (f as i64).to_string()
+        } else {
+            ryu::Buffer::new().format_finite(f).to_string()
+        },
+    )
 }
 
 /// Normalize text for canonical representation
 ///
+/// - Apply NFKC (compatibility) normalization
 /// - Convert to lowercase
 /// - Remove all punctuation
 /// - Normalize whitespace to single spaces
 /// - Trim leading/trailing whitespace
-fn normalize_text(text: &str) -> String {
-    text.to_lowercase()
+/// - Re-apply NFKC, since removing punctuation can join composable characters
+///
+/// Lowercasing is always locale-independent: it uses the Unicode default
+/// lowercase mapping (`str::to_lowercase`), never the process locale, so
+/// Turkish `I` becomes `i` and German `ß` stays `ß` on every platform.
+/// This is lowercase mapping, not full case folding (`ß` is not expanded
+/// to `ss`).
+pub(crate) fn normalize_text(text: &str) -> String {
+    text.nfkc()
+        .collect::<String>()
+        .to_lowercase()
         .chars()
         .filter_map(|c| {
             if c.is_alphanumeric() {
@@ -135,27 +930,752 @@ fn normalize_text(text: &str) -> String {
         .split_whitespace()
         .collect::<Vec<&str>>()
         .join(" ")
+        // Removing punctuation can leave composable neighbors (e.g. Hangul jamo)
+        .nfkc()
+        .collect()
+}
+
+/// Fold compatibility characters (NFKC) while keeping punctuation
+///
+/// For checks that read the raw text, such as paradox and clause
+/// detection, so fullwidth or ligature forms cannot dodge them.
+pub(crate) fn fold_compatibility(text: &str) -> Cow<'_, str> {
+    if unicode_normalization::is_nfkc_quick(text.chars())
+        == unicode_normalization::IsNormalized::Yes
+    {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfkc().collect())
+    }
+}
+
+/// Whether `text` is a fixed point of `normalize_text`
+///
+/// Normalization must be idempotent for canonical hashes to be stable:
+/// re-normalizing already-normalized text must not change it. Checked with
+/// a debug assertion during canonicalization; a `false` here for any
+/// `normalize_text` output is a normalization bug.
+pub fn is_normalized(text: &str) -> bool {
+    normalize_text(text) == text
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_trailing_garbage_rejected() {
+        match parse_ast(r#"{"a":1} garbage"#) {
+            Err(CanonicalizeError::TrailingData { offset }) => assert_eq!(offset, 8),
+            other => panic!("expected TrailingData, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trailing_whitespace_allowed() {
+        let ast = parse_ast("  {\"a\": 1} \n\t\r\n").unwrap();
+        assert_eq!(ast, serde_json::json!({"a": 1}));
+    }
+
+    #[test]
+    fn test_unicode_escapes() {
+        // Lone high surrogate
+        assert!(matches!(
+            parse_ast(r#"{"a": "x\uD800"}"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { offset: 8 })
+        ));
+        // High surrogate followed by a non-surrogate escape, and a lone low surrogate
+        assert!(matches!(
+            parse_ast(r#"{"a": "\uD800\u0041"}"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { .. })
+        ));
+        assert!(matches!(
+            parse_ast(r#"["\uDE00"]"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { .. })
+        ));
+
+        // Valid surrogate pair decodes to the emoji
+        let ast = parse_ast(r#"{"a": "\uD83D\uDE00 ok \u00e9\n"}"#).unwrap();
+        assert_eq!(ast["a"], "\u{1F600} ok \u{e9}\n");
+
+        // Invalid escape character, also in a key
+        assert!(matches!(
+            parse_ast(r#"{"a": "\x41"}"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { offset: 7 })
+        ));
+        assert!(matches!(
+            parse_ast(r#"{"\q": 1}"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { .. })
+        ));
+        assert!(matches!(
+            parse_ast(r#"{"a": "\u12G4"}"#),
+            Err(CanonicalizeError::InvalidUnicodeEscape { .. })
+        ));
+
+        // Escaped quotes and backslashes do not confuse string tracking
+        let ast = parse_ast(r#"{"a": "say \"\\\" then \u0041"}"#).unwrap();
+        assert_eq!(ast["a"], r#"say "\" then A"#);
+    }
+
+    #[test]
+    fn test_quick_check_rejects_garbage() {
+        let garbage = [
+            "",
+            " \n\t",
+            "<html>",
+            "hello",
+            "}",
+            r#"{"a": [1, 2}"#,
+            r#"{"a": {"b": 1}"#,
+            r#"[[[["#,
+            r#"{"a": "unterminated}"#,
+            r#"{"a": "escaped quote \"}"#,
+        ];
+        for input in garbage {
+            assert!(
+                matches!(
+                    quick_ast_check(input),
+                    Err(CanonicalizeError::JsonParseError(_))
+                ),
+                "{:?}",
+                input
+            );
+            assert!(serde_json::from_str::<Value>(input).is_err(), "{:?}", input);
+        }
+
+        // Short-circuits before the parser: a huge unclosed AST is rejected by the pre-scan
+        let unclosed = format!("[{}", "1,".repeat(100_000));
+        assert!(quick_ast_check(&unclosed).is_err());
+    }
+
+    #[test]
+    fn test_quick_check_accepts_everything_serde_accepts() {
+        let valid = [
+            r#"{"action": "transfer", "amount": 100}"#,
+            r#"{"s": "braces } ] in { [ strings", "e": "\\\"}\\"}"#,
+            r#"[{"a": [1, {"b": []}]}, "x"]"#,
+            r#"  {"a": {"b": {"c": [null, true, false, -1.5e3]}}}  "#,
+            "{}",
+            "[]",
+            "42",
+            "-0.5",
+            r#""just a string""#,
+            "true",
+            "null",
+            r#"{"unicode": "é 😀 é"}"#,
+        ];
+        for input in valid {
+            assert!(serde_json::from_str::<Value>(input).is_ok(), "{:?}", input);
+            assert!(quick_ast_check(input).is_ok(), "{:?}", input);
+            assert!(parse_ast(input).is_ok(), "{:?}", input);
+        }
+
+        // Trailing data is still reported with its offset by the parser
+        assert!(quick_ast_check(r#"{"a":1} }"#).is_ok());
+        assert!(matches!(
+            parse_ast(r#"{"a":1} }"#),
+            Err(CanonicalizeError::TrailingData { offset: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_second_json_value_rejected() {
+        match parse_ast(r#"{"a":1}  {"b":2}"#) {
+            Err(CanonicalizeError::TrailingData { offset }) => assert_eq!(offset, 9),
+            other => panic!("expected TrailingData, got {:?}", other),
+        }
+        assert!(matches!(
+            parse_ast("[1]\n[2]"),
+            Err(CanonicalizeError::TrailingData { offset: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_keys_rejected() {
+        let duplicate = |json: &str| match parse_ast(json) {
+            Err(CanonicalizeError::DuplicateKey(key)) => Some(key),
+            Ok(_) => None,
+            Err(other) => panic!("expected DuplicateKey, got {:?}", other),
+        };
+
+        assert_eq!(duplicate(r#"{"a": 1, "a": 2}"#), Some("a".to_string()));
+        assert_eq!(
+            duplicate(r#"{"a": 1, "b": 2, "a": 1}"#),
+            Some("a".to_string())
+        );
+        assert_eq!(duplicate(r#"{"a": 1, "\u0061": 2}"#), Some("a".to_string()));
+
+        // Nested objects, including inside arrays
+        assert_eq!(
+            duplicate(r#"{"a": {"b": {"c": 1, "c": 1}}}"#),
+            Some("c".to_string())
+        );
+        assert_eq!(
+            duplicate(r#"[1, {"x": [{"y": null, "y": null}]}]"#),
+            Some("y".to_string())
+        );
+
+        // The first duplicate in document order is reported
+        assert_eq!(
+            duplicate(r#"{"a": {"b": 1, "b": 2}, "c": 1, "c": 2}"#),
+            Some("b".to_string())
+        );
+
+        // The same key in different objects is not a duplicate
+        assert_eq!(
+            duplicate(r#"{"a": {"a": 1}, "b": {"a": 2}, "c": [{"a": 3}, {"a": 4}]}"#),
+            None
+        );
+
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#"{"amount": 1, "amount": 1000000}"#.to_string(),
+            "Transfer".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        assert!(matches!(
+            canonicalize(&proposal),
+            Err(CanonicalizeError::DuplicateKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_empty_and_invalid_ast_still_parse_errors() {
+        assert!(matches!(
+            parse_ast(""),
+            Err(CanonicalizeError::JsonParseError(_))
+        ));
+        assert!(matches!(
+            parse_ast("   "),
+            Err(CanonicalizeError::JsonParseError(_))
+        ));
+        assert!(matches!(
+            parse_ast(r#"{"a":"#),
+            Err(CanonicalizeError::JsonParseError(_))
+        ));
+    }
     use crate::types::GovernanceLayer;
 
     #[test]
     fn test_sort_json_keys() {
-        let input: Value = serde_json::from_str(r#"{"z": 1, "a": 2, "m": {"y": 3, "b": 4}}"#).unwrap();
+        let input: Value =
+            serde_json::from_str(r#"{"z": 1, "a": 2, "m": {"y": 3, "b": 4}}"#).unwrap();
         let sorted = sort_json_keys(&input);
         let output = serde_json::to_string(&sorted).unwrap();
         assert_eq!(output, r#"{"a":2,"m":{"b":4,"y":3},"z":1}"#);
     }
 
+    #[test]
+    fn test_non_object_ast_canonical_forms() {
+        // Objects inside a top-level array are sorted like any other
+        assert_eq!(
+            canonical_ast(r#"[{"b": 1, "a": {"d": 2, "c": 3}}, [{"z": 0, "y": 1.0}]]"#),
+            r#"[{"a":{"c":3,"d":2},"b":1},[{"y":1,"z":0}]]"#
+        );
+        assert_eq!(canonical_ast(r#" "Fund it" "#), r#""Fund it""#);
+        assert_eq!(canonical_ast("4.0"), "4");
+        assert_eq!(canonical_ast("null"), "null");
+    }
+
+    fn canonical_ast(json: &str) -> String {
+        let value: Value = serde_json::from_str(json).unwrap();
+        let mut out = Vec::new();
+        write_canonical_json(&sort_json_keys(&value), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_float_formatting_vectors() {
+        assert_eq!(canonical_ast(r#"{"x": 0.1}"#), r#"{"x":0.1}"#);
+        assert_eq!(canonical_ast(r#"{"x": 1e20}"#), r#"{"x":1e20}"#);
+        assert_eq!(canonical_ast(r#"{"x": 2.5e-3}"#), r#"{"x":0.0025}"#);
+        assert_eq!(canonical_ast(r#"{"x": -1.5}"#), r#"{"x":-1.5}"#);
+        assert_eq!(
+            canonical_ast(r#"{"x": 0.30000000000000004}"#),
+            r#"{"x":0.30000000000000004}"#
+        );
+        assert_eq!(
+            canonical_ast(r#"{"x": 18446744073709551615}"#),
+            r#"{"x":18446744073709551615}"#
+        );
+        assert_eq!(canonical_ast(r#"{"x": -42}"#), r#"{"x":-42}"#);
+    }
+
+    #[test]
+    fn test_integral_floats_fold_to_integers() {
+        for json in [
+            r#"{"x": 1}"#,
+            r#"{"x": 1.0}"#,
+            r#"{"x": 1.00}"#,
+            r#"{"x": 1e0}"#,
+            r#"{"x": 10e-1}"#,
+        ] {
+            assert_eq!(canonical_ast(json), r#"{"x":1}"#, "{json}");
+        }
+        assert_eq!(hash_of(r#"{"x": 1.0}"#), hash_of(r#"{"x": 1}"#));
+
+        assert_eq!(canonical_ast(r#"{"x": -0.0}"#), r#"{"x":0}"#);
+        assert_eq!(canonical_ast(r#"{"x": -3.0}"#), r#"{"x":-3}"#);
+        assert_eq!(
+            canonical_ast(r#"{"x": 1e19}"#),
+            r#"{"x":10000000000000000000}"#
+        );
+        assert_eq!(
+            canonical_ast(r#"{"x": -9.223372036854775808e18}"#),
+            r#"{"x":-9223372036854775808}"#
+        );
+
+        // Beyond the integer range, integral floats keep the ryu form
+        assert_eq!(
+            canonical_ast(r#"{"x": 1.8446744073709552e19}"#),
+            r#"{"x":1.8446744073709552e19}"#
+        );
+        assert_eq!(
+            canonical_ast(r#"{"x": 1e20}"#),
+            canonical_ast(r#"{"x": 100000000000000000000}"#)
+        );
+    }
+
+    #[test]
+    fn test_non_finite_numbers_rejected() {
+        for ast in [
+            r#"{"x": NaN}"#,
+            r#"{"x": Infinity}"#,
+            r#"{"x": -Infinity}"#,
+            r#"{"x": 1e400}"#,
+        ] {
+            assert!(parse_ast(ast).is_err(), "{ast}");
+        }
+    }
+
+    #[test]
+    fn test_canonical_writer_matches_serde() {
+        // The writer must stay byte-compatible with the previous serde_json output
+        for json in [
+            r#"{"b": [1, 2.5, "s\"q", null, true], "a": {"y": false, "x": "\u00e9"}}"#,
+            r#"[{"k": 1e-7}, "tab\tnewline\n", 0]"#,
+            r#""scalar""#,
+        ] {
+            let value = sort_json_keys(&serde_json::from_str(json).unwrap());
+            let expected = serde_json::to_string(&value).unwrap();
+            assert_eq!(canonical_ast(json), expected);
+        }
+    }
+
+    fn hash_of(ast: &str) -> [u8; 32] {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            ast.to_string(),
+            "Null handling".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        canonicalize(&proposal).unwrap().hash
+    }
+
+    #[test]
+    fn test_null_is_preserved() {
+        assert_eq!(canonical_ast(r#"{"a": null}"#), r#"{"a":null}"#);
+        assert_ne!(hash_of(r#"{"a": null}"#), hash_of(r#"{}"#));
+    }
+
+    #[test]
+    fn test_nested_null_is_preserved() {
+        assert_eq!(
+            canonical_ast(r#"{"b": {"y": null, "x": 1}, "a": [null, 1]}"#),
+            r#"{"a":[null,1],"b":{"x":1,"y":null}}"#
+        );
+        assert_ne!(
+            hash_of(r#"{"b": {"x": 1, "y": null}}"#),
+            hash_of(r#"{"b": {"x": 1}}"#)
+        );
+        assert_ne!(hash_of(r#"{"a": [null]}"#), hash_of(r#"{"a": []}"#));
+    }
+
     #[test]
     fn test_normalize_text() {
         assert_eq!(normalize_text("Hello, World!"), "hello world");
         assert_eq!(normalize_text("  Multiple   spaces  "), "multiple spaces");
         assert_eq!(normalize_text("This is a test."), "this is a test");
-        assert_eq!(normalize_text("UPPERCASE lowercase MiXeD"), "uppercase lowercase mixed");
+        assert_eq!(
+            normalize_text("UPPERCASE lowercase MiXeD"),
+            "uppercase lowercase mixed"
+        );
+    }
+
+    #[test]
+    fn test_compatibility_characters_fold() {
+        assert_eq!(
+            normalize_text("Ｔｈｉｓ ＰＲＯＰＯＳＡＬ １２３"),
+            "this proposal 123"
+        );
+        assert_eq!(normalize_text("x² + ﬁle ½"), "x2 file 12");
+
+        // Fullwidth paradox text is caught and shares the ASCII form's hash
+        let fullwidth = "Ｔｈｉｓ ｐｒｏｐｏｓａｌ ｐａｓｓｅｓ ｉｆｆ ｉｔ ｆａｉｌｓ";
+        let ascii = "This proposal passes iff it fails";
+        let proposal = |text: &str| {
+            Proposal::new(
+                "rAddr".to_string(),
+                "{}".to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        assert!(crate::channel_a::detect_paradox(&normalize_text(fullwidth)));
+        assert!(!crate::channel_a::verify_proposal(&proposal(fullwidth)).pass);
+        assert_eq!(
+            canonicalize(&proposal(fullwidth)).unwrap().hash,
+            canonicalize(&proposal(ascii)).unwrap().hash
+        );
+    }
+
+    #[test]
+    fn test_equivalent_unicode_forms_share_hash() {
+        let hash = |ast: &str, text: &str| {
+            let p = Proposal::new(
+                "rAddr".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            canonicalize(&p).unwrap().hash
+        };
+        let vectors = [
+            // Combining diacritics: precomposed vs decomposed, and stacked marks in either order
+            (
+                "Caf\u{e9} r\u{e9}sum\u{e9}",
+                "Cafe\u{301} re\u{301}sume\u{301}",
+            ),
+            ("Vi\u{1ec7}t Nam", "Vie\u{323}\u{302}t Nam"),
+            ("Vi\u{1ec7}t Nam", "Vie\u{302}\u{323}t Nam"),
+            ("\u{c5}ngstr\u{f6}m", "A\u{30a}ngstro\u{308}m"),
+            // Fullwidth vs halfwidth digits
+            (
+                "Allocate 2500 tokens",
+                "Allocate \u{ff12}\u{ff15}\u{ff10}\u{ff10} tokens",
+            ),
+            // Ligatures
+            ("Office efficiency", "O\u{fb03}ce e\u{fb03}ciency"),
+            ("flat fee", "\u{fb02}at fee"),
+        ];
+
+        for (plain, variant) in vectors {
+            assert_eq!(normalize_text(plain), normalize_text(variant), "{variant}");
+            assert_eq!(hash("{}", plain), hash("{}", variant), "{variant}");
+        }
+
+        // The AST is left untouched: its strings hash exactly as submitted
+        assert_ne!(
+            hash("{\"name\": \"caf\u{e9}\"}", "x"),
+            hash("{\"name\": \"cafe\u{301}\"}", "x")
+        );
+    }
+
+    #[test]
+    fn test_locale_independent_lowercase() {
+        // Turkish: I -> i (never dotless ı), İ -> i + U+0307 whose mark is dropped
+        assert_eq!(normalize_text("ISTANBUL"), "istanbul");
+        assert_eq!(normalize_text("İstanbul"), "istanbul");
+        assert_eq!(normalize_text("ılık"), "ılık");
+
+        // German: ß is already lowercase, capital ẞ maps to ß, no expansion to ss
+        assert_eq!(normalize_text("STRAẞE"), "straße");
+        assert_eq!(normalize_text("Straße"), "straße");
+        assert_ne!(normalize_text("Straße"), normalize_text("STRASSE"));
+
+        // Greek final sigma is context-dependent but locale-independent
+        assert_eq!(normalize_text("ΟΔΟΣ"), "οδος");
+    }
+
+    #[test]
+    fn test_normalize_text_idempotent_random_unicode() {
+        // Code point ranges weighted toward case-mapping and whitespace edge cases
+        const RANGES: &[(u32, u32)] = &[
+            (0x20, 0x7E),       // ASCII
+            (0xA0, 0x24F),      // Latin-1, Latin Extended (incl. titlecase digraphs)
+            (0x300, 0x36F),     // Combining marks
+            (0x370, 0x3FF),     // Greek (final sigma)
+            (0x400, 0x52F),     // Cyrillic
+            (0x1E00, 0x1FFF),   // Latin Extended Additional, Greek Extended
+            (0x2000, 0x206F),   // Unicode spaces and general punctuation
+            (0x2150, 0x218F),   // Number forms (Roman numerals have case)
+            (0x24B6, 0x24E9),   // Circled letters (have case, not alphabetic digits)
+            (0x3000, 0x303F),   // CJK symbols, ideographic space
+            (0xFF00, 0xFFEF),   // Fullwidth forms
+            (0x10400, 0x1044F), // Deseret (astral case pairs)
+            (0x1F600, 0x1F64F), // Emoji
+            (0x0, 0x10FFFF),    // Anything
+        ];
+
+        let mut state: u32 = 0x9E37_79B9;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for _ in 0..5000 {
+            let len = next() % 24;
+            let text: String = (0..len)
+                .filter_map(|_| {
+                    let (lo, hi) = RANGES[next() as usize % RANGES.len()];
+                    char::from_u32(lo + next() % (hi - lo + 1))
+                })
+                .collect();
+
+            let once = normalize_text(&text);
+            assert!(
+                is_normalized(&once),
+                "not idempotent for {:?}: {:?}",
+                text,
+                once
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_text_idempotent_known_cases() {
+        for text in [
+            "ΑΣ-Β",
+            "ΑΣ.Β",
+            "İI",
+            "ǅǄǆ",
+            "ⅫⅯ",
+            "Ⓐⓑ",
+            "𐐀𐐨",
+            "a\u{3000}b\u{2028}c",
+            "ﬁ ﬃ",
+        ] {
+            let once = normalize_text(text);
+            assert!(
+                is_normalized(&once),
+                "not idempotent for {:?}: {:?}",
+                text,
+                once
+            );
+        }
+        assert!(!is_normalized("Not Normalized!"));
+    }
+
+    #[test]
+    fn test_metadata_stripping() {
+        let proposal = |ast: &str| {
+            Proposal::new(
+                "rAddr".to_string(),
+                ast.to_string(),
+                "Transfer".to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        let a = proposal(r#"{"action": "transfer", "_meta": {"tool_version": "1.0.0"}}"#);
+        let b = proposal(r#"{"action": "transfer", "_meta": {"tool_version": "2.3.1"}}"#);
+
+        let strip = CanonicalizeOptions::strip_metadata();
+        assert_eq!(
+            canonicalize_with(&a, &strip).unwrap().hash,
+            canonicalize_with(&b, &strip).unwrap().hash
+        );
+        assert_ne!(
+            canonicalize(&a).unwrap().hash,
+            canonicalize(&b).unwrap().hash
+        );
+    }
+
+    #[test]
+    fn test_metadata_stripping_nested_and_prefix_only() {
+        let strip = CanonicalizeOptions::strip_metadata();
+        let ast = serde_json::json!({
+            "steps": [{"op": "pay", "_meta_hint": "x"}],
+            "plan": {"_metadata": 1, "meta": 2},
+        });
+        let bytes = canonical_ast_bytes(&ast, &strip).unwrap();
+        assert_eq!(bytes, br#"{"plan":{"meta":2},"steps":[{"op":"pay"}]}"#);
+
+        // Default options strip nothing
+        let bytes = canonical_ast_bytes(&ast, &CanonicalizeOptions::default()).unwrap();
+        assert!(bytes.windows(5).any(|w| w == b"_meta"));
+    }
+
+    #[test]
+    fn test_array_semantics() {
+        let options = CanonicalizeOptions {
+            array_semantics: BTreeMap::from([
+                ("approvers".to_string(), ArraySemantics::Set),
+                ("votes".to_string(), ArraySemantics::Multiset),
+                ("steps".to_string(), ArraySemantics::Ordered),
+            ]),
+            ..Default::default()
+        };
+        let canonical = |ast: &str| {
+            String::from_utf8(canonical_ast_bytes(&parse_ast(ast).unwrap(), &options).unwrap())
+                .unwrap()
+        };
+
+        // Set: order and duplicates irrelevant
+        assert_eq!(
+            canonical(r#"{"approvers": ["b", "a", "a"]}"#),
+            r#"{"approvers":["a","b"]}"#
+        );
+        assert_eq!(
+            canonical(r#"{"approvers": ["a", "b"]}"#),
+            canonical(r#"{"approvers": ["b", "a", "b"]}"#)
+        );
+
+        // Multiset: order irrelevant, duplicates kept
+        assert_eq!(
+            canonical(r#"{"votes": ["b", "a", "a"]}"#),
+            r#"{"votes":["a","a","b"]}"#
+        );
+        assert_ne!(
+            canonical(r#"{"votes": ["a", "b"]}"#),
+            canonical(r#"{"votes": ["b", "a", "a"]}"#)
+        );
+
+        // Ordered (declared or not): untouched
+        assert_eq!(
+            canonical(r#"{"steps": ["b", "a", "a"]}"#),
+            r#"{"steps":["b","a","a"]}"#
+        );
+        assert_eq!(
+            canonical(r#"{"other": ["b", "a"]}"#),
+            r#"{"other":["b","a"]}"#
+        );
+
+        // Nested keys and element objects, compared by their canonical bytes
+        assert_eq!(
+            canonical(r#"{"plan": {"approvers": [{"z": 1, "a": 2}, {"a": 1}, {"a": 2, "z": 1}]}}"#),
+            r#"{"plan":{"approvers":[{"a":1},{"a":2,"z":1}]}}"#
+        );
+
+        // The default options leave every array in order
+        let plain = canonical_ast_bytes(
+            &parse_ast(r#"{"approvers": ["b", "a"]}"#).unwrap(),
+            &CanonicalizeOptions::default(),
+        );
+        assert_eq!(plain.unwrap(), br#"{"approvers":["b","a"]}"#);
+    }
+
+    fn proposal_hash(ast: &str, text: &str) -> [u8; 32] {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        canonicalize(&proposal).unwrap().hash
+    }
+
+    #[test]
+    fn test_generated_proposals_never_collide() {
+        let actions = ["transfer", "mint", "burn", "set_param"];
+        let words = ["alpha", "beta", "gamma", "delta", "epsilon"];
+        let mut seen = std::collections::HashMap::new();
+
+        for action in actions {
+            for amount in 0..20u32 {
+                for (w, word) in words.iter().enumerate() {
+                    // Alternate between flat, nested, and reference-carrying shapes
+                    let ast = match (amount + w as u32) % 3 {
+                        0 => format!(r#"{{"action": "{}", "amount": {}}}"#, action, amount),
+                        1 => format!(
+                            r#"{{"action": "{}", "params": {{"amount": {}, "unit": "drops"}}}}"#,
+                            action, amount
+                        ),
+                        _ => format!(
+                            r#"{{"a": {{"action": "{}"}}, "b": {{"amount": {}, "value": "$ref:a"}}}}"#,
+                            action, amount
+                        ),
+                    };
+                    let text = format!("{} {} tokens for {}", action, amount, word);
+
+                    let hash = proposal_hash(&ast, &text);
+                    if let Some(previous) = seen.insert(hash, (ast.clone(), text.clone())) {
+                        panic!("collision: {:?} and {:?}", previous, (ast, text));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(seen.len(), 400);
+    }
+
+    #[test]
+    fn test_equivalent_proposals_collide() {
+        let pairs = [
+            // Key order
+            (
+                (r#"{"a": 1, "b": 2}"#, "Fund it"),
+                (r#"{"b": 2, "a": 1}"#, "Fund it"),
+            ),
+            // Nested key order and JSON whitespace
+            (
+                (r#"{"x": {"q": [1, 2], "p": null}}"#, "Fund it"),
+                (
+                    "{ \"x\" : { \"p\" : null , \"q\" : [ 1 , 2 ] } }",
+                    "Fund it",
+                ),
+            ),
+            // Text casing, punctuation, and whitespace
+            (
+                (r#"{}"#, "Transfer 100 tokens."),
+                (r#"{}"#, "  TRANSFER   100, tokens!"),
+            ),
+            // Integral floats and integers
+            ((r#"{"amount": 1}"#, "Pay"), (r#"{"amount": 1.0}"#, "Pay")),
+        ];
+
+        for ((ast_a, text_a), (ast_b, text_b)) in pairs {
+            assert_eq!(
+                proposal_hash(ast_a, text_a),
+                proposal_hash(ast_b, text_b),
+                "{} / {}",
+                ast_a,
+                ast_b
+            );
+        }
+    }
+
+    #[test]
+    fn test_distinct_proposals_do_not_collide() {
+        let pairs = [
+            // Different amounts
+            (
+                (r#"{"amount": 100}"#, "Pay"),
+                (r#"{"amount": 1000}"#, "Pay"),
+            ),
+            // Number vs string, and non-integral vs integral floats
+            ((r#"{"amount": 1}"#, "Pay"), (r#"{"amount": "1"}"#, "Pay")),
+            ((r#"{"amount": 1}"#, "Pay"), (r#"{"amount": 1.5}"#, "Pay")),
+            // Different refs
+            (
+                (r#"{"a": "$ref:b", "b": 1, "c": 2}"#, "Pay"),
+                (r#"{"a": "$ref:c", "b": 1, "c": 2}"#, "Pay"),
+            ),
+            // Key casing is significant in the AST
+            ((r#"{"Amount": 1}"#, "Pay"), (r#"{"amount": 1}"#, "Pay")),
+            // Array order is significant
+            (
+                (r#"{"to": ["x", "y"]}"#, "Pay"),
+                (r#"{"to": ["y", "x"]}"#, "Pay"),
+            ),
+            // Null member vs absent
+            ((r#"{"a": null}"#, "Pay"), (r#"{}"#, "Pay")),
+            // Text moved across the separator
+            ((r#"{"a": "b"}"#, "c"), (r#"{"a": "bc"}"#, "")),
+            // Word boundaries in text
+            ((r#"{}"#, "pay ten"), (r#"{}"#, "payten")),
+        ];
+
+        for ((ast_a, text_a), (ast_b, text_b)) in pairs {
+            assert_ne!(
+                proposal_hash(ast_a, text_a),
+                proposal_hash(ast_b, text_b),
+                "{} / {}",
+                ast_a,
+                ast_b
+            );
+        }
     }
 
     #[test]
@@ -181,6 +1701,259 @@ mod tests {
         assert_eq!(c1.hash, c2.hash);
     }
 
+    #[test]
+    fn test_serde_roundtrip_preserves_canonical_form() {
+        let cases = [
+            (
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            (
+                r#"{"plan": {"steps": [{"a": {"b": {"c": [1, [2, [3, null]]]}}}], "depends_on": ["x"]}}"#,
+                "Nested",
+            ),
+            (
+                r#"{"max": 18446744073709551615, "min": -9223372036854775808, "big": 1e300, "tiny": 1.5e-10, "neg_zero": -0.0}"#,
+                "Large numbers",
+            ),
+            (
+                r#"{"beyond_u64": 123456789012345678901234567890}"#,
+                "Number past u64",
+            ),
+            (
+                r#"{"name": "Zoë é😀", "emoji": "🗳️"}"#,
+                "Ünïcödé tëxt — İstanbul ß 🗳️ 日本語",
+            ),
+            (r#"{}"#, ""),
+        ];
+
+        for (ast, text) in cases {
+            let proposal = Proposal::new(
+                "rAddr".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L3Execution,
+            );
+            let hash = canonicalize(&proposal).unwrap().hash;
+            let proposal = proposal.with_id(hash);
+
+            let json = serde_json::to_string(&proposal).unwrap();
+            let restored: Proposal = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, proposal, "{}", ast);
+
+            // Through an untyped value too, as a relay that re-serializes would
+            let value = serde_json::to_value(&proposal).unwrap();
+            let relayed: Proposal = serde_json::from_value(value).unwrap();
+
+            let original = canonicalize(&proposal).unwrap();
+            for copy in [&restored, &relayed] {
+                let c = canonicalize(copy).unwrap();
+                assert_eq!(c.bytes, original.bytes, "{}", ast);
+                assert_eq!(c.hash, original.hash, "{}", ast);
+                assert_eq!(copy.id, original.hash, "{}", ast);
+            }
+        }
+    }
+
+    #[test]
+    fn test_payload_ranges() {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#"{"note": "a.b", "amount": 1.5}"#.to_string(),
+            "Pay 1.5 tokens. Then stop.".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+
+        let canonical = canonicalize(&proposal).unwrap();
+        assert_eq!(canonical.ast_bytes(), br#"{"amount":1.5,"note":"a.b"}"#);
+        assert_eq!(canonical.text_bytes(), b"pay 15 tokens then stop");
+        assert_eq!(canonical.ast_range.start, 1);
+        assert_eq!(canonical.ast_range.end + 1, canonical.text_range.start);
+        assert_eq!(canonical.text_range.end, canonical.bytes.len());
+    }
+
+    #[test]
+    fn test_split_payload() {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#"{"note": "a.b", "amount": 1.5}"#.to_string(),
+            "Pay 1.5 tokens. Then stop.".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let canonical = canonicalize(&proposal).unwrap();
+
+        let (ast, text) = split_payload(&canonical.bytes).unwrap();
+        assert_eq!(ast, serde_json::json!({"amount": 1.5, "note": "a.b"}));
+        assert_eq!(text.as_bytes(), canonical.text_bytes());
+
+        let v = config::CANONICAL_FORM_VERSION;
+        assert!(matches!(
+            split_payload(&[&[v], b"{}".as_slice()].concat()),
+            Err(CanonicalizeError::MissingSeparator)
+        ));
+        assert!(matches!(
+            split_payload(&[&[v], b"{}x.text".as_slice()].concat()),
+            Err(CanonicalizeError::TrailingData { .. })
+        ));
+        assert!(matches!(
+            split_payload(b""),
+            Err(CanonicalizeError::MissingSeparator)
+        ));
+
+        // A payload from another canonical form version is not reinterpreted
+        let mut other = canonical.bytes.clone();
+        other[0] = v - 1;
+        assert!(
+            matches!(split_payload(&other), Err(CanonicalizeError::UnsupportedVersion(found)) if found == v - 1)
+        );
+        assert!(matches!(
+            split_payload(b"{}.text"),
+            Err(CanonicalizeError::UnsupportedVersion(b'{'))
+        ));
+    }
+
+    #[test]
+    fn test_canonicalize_and_id() {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#"{"b": 2, "a": 1}"#.to_string(),
+            "Hello, World!".to_string(),
+            GovernanceLayer::L2Operational,
+        )
+        .with_id([0xAB; 32]);
+
+        let (identified, payload) = canonicalize_and_id(&proposal).unwrap();
+        assert_eq!(identified.id, canonicalize(&proposal).unwrap().hash);
+        assert_eq!(identified.id, payload.hash);
+        assert_eq!(identified.with_id(proposal.id), proposal);
+
+        // The input is untouched, and verification does not assign an ID either
+        assert_eq!(proposal.id, [0xAB; 32]);
+        let fresh = Proposal::new(
+            "rAddr".to_string(),
+            "{}".to_string(),
+            "Noop".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        crate::verify_proposal(&fresh);
+        assert_eq!(fresh.id, [0u8; 32]);
+
+        let broken = Proposal::new(
+            "rAddr".to_string(),
+            "{not json".to_string(),
+            "Broken".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        assert!(canonicalize_and_id(&broken).is_err());
+    }
+
+    #[test]
+    fn test_error_messages_carry_context() {
+        let v = config::CANONICAL_FORM_VERSION;
+
+        // UTF-8 offsets count from the version byte, in either half
+        let err = split_payload(&[&[v], b"{}.ab\xff".as_slice()].concat()).unwrap_err();
+        assert!(matches!(err, CanonicalizeError::Utf8Error { offset: 6 }));
+        assert_eq!(
+            err.to_string(),
+            "Invalid UTF-8 in canonical payload at byte 6"
+        );
+        let err = split_payload(&[&[v], b"1\xff.text".as_slice()].concat()).unwrap_err();
+        assert!(matches!(err, CanonicalizeError::Utf8Error { offset: 2 }));
+
+        let err = split_payload(&[&[v - 1], b"{}.text".as_slice()].concat()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Unsupported canonical form version {} (expected {})",
+                v - 1,
+                v
+            )
+        );
+
+        let err = parse_ast(r#"{"fee": 1, "fee": 2}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Duplicate key "fee" in logic AST object"#
+        );
+
+        for (input, detail) in [
+            (r#"{"a": "open"#, "unterminated string starting at byte 6"),
+            (
+                r#"{"a": [1, {"b": 2}"#,
+                "2 unclosed bracket(s), innermost opened at byte 6",
+            ),
+            (r#"{"a": [1, 2}"#, "mismatched '}' at byte 11"),
+        ] {
+            let err = quick_ast_check(input).unwrap_err();
+            assert!(err.to_string().contains(detail), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_json_errors_chain_to_serde() {
+        use std::error::Error as _;
+
+        let err = parse_ast("{\"a\": tru}").unwrap_err();
+        let source = err
+            .source()
+            .and_then(|e| e.downcast_ref::<serde_json::Error>())
+            .unwrap();
+        assert_eq!((source.line(), source.column()), (1, 10));
+        assert!(err.to_string().contains("line 1 column 10"), "{}", err);
+    }
+
+    #[test]
+    fn test_split_round_trips_hashed_halves() {
+        let cases = [
+            (
+                r#"{"note": "see section 4.2. then 4.3", "rate": 0.05, "nested": ["a.b", {"c.d": "."}]}"#,
+                "Raise the rate to 0.05 on 2024.01.01",
+            ),
+            (r#""v1.2.3""#, "Release 1.2.3"),
+            ("1", "5"),
+            ("-1.5e3", "42 tokens"),
+            ("[1.5, 2]", "3.5"),
+        ];
+        for (ast, text) in cases {
+            let proposal = Proposal::new(
+                "rAddr".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let canonical = canonicalize(&proposal).unwrap();
+
+            let (split_ast, split_text) = canonical.split().unwrap();
+            assert_eq!(
+                split_ast,
+                serde_json::from_slice::<Value>(canonical.ast_bytes()).unwrap(),
+                "{}",
+                ast
+            );
+            assert_eq!(split_text, normalize_text(text));
+            assert_eq!(split_text.as_bytes(), canonical.text_bytes());
+
+            // Re-canonicalizing the recovered halves reproduces the hashed bytes
+            let rebuilt = canonicalize_value(&split_ast, &split_text).unwrap();
+            assert_eq!(rebuilt.bytes, canonical.bytes, "{}", ast);
+        }
+
+        // The first dot is inside the AST string, not the separator
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#""a.b""#.to_string(),
+            "c".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let canonical = canonicalize(&proposal).unwrap();
+        assert_eq!(canonical.ast_bytes(), br#""a.b""#);
+        assert_eq!(
+            canonical.split().unwrap(),
+            (serde_json::json!("a.b"), "c".to_string())
+        );
+    }
+
     #[test]
     fn test_canonical_payload_format() {
         let proposal = Proposal::new(
@@ -191,10 +1964,31 @@ mod tests {
         );
 
         let canonical = canonicalize(&proposal).unwrap();
-        let payload_str = String::from_utf8(canonical.bytes.clone()).unwrap();
+        assert_eq!(canonical.version, config::CANONICAL_FORM_VERSION);
+        assert_eq!(canonical.bytes[0], canonical.version);
+        let payload_str = String::from_utf8(canonical.bytes[1..].to_vec()).unwrap();
+
+        // Should be: version + sorted_json + "." + normalized_text
+        assert_eq!(payload_str, r#"{"action":"test"}.test proposal"#);
+    }
+
+    #[test]
+    fn test_version_is_hashed() {
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            r#"{"action": "test"}"#.to_string(),
+            "Test proposal".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let current = canonicalize(&proposal).unwrap();
+        let bumped = assemble_payload(current.version + 1, current.ast_bytes(), "test proposal");
 
-        // Should be: sorted_json + "." + normalized_text
-        assert!(payload_str.contains("."));
-        assert!(payload_str.ends_with("test proposal"));
+        assert_eq!(bumped.ast_bytes(), current.ast_bytes());
+        assert_eq!(bumped.text_bytes(), current.text_bytes());
+        assert_ne!(bumped.hash, current.hash);
+        assert_eq!(
+            assemble_payload(current.version, current.ast_bytes(), "test proposal").hash,
+            current.hash
+        );
     }
 }
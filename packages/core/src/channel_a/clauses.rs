@@ -0,0 +1,218 @@
+//! Cross-Clause Paradox Detection
+//!
+//! The sentence-local patterns in `paradox` miss self-reference that is
+//! split across numbered clauses and expressed by clause number:
+//!
+//! ```text
+//! 1. This proposal passes.
+//! 2. Clause 1 holds iff clause 2 is false.
+//! ```
+//!
+//! This pass works on the normalized text (so it can be reproduced from the
+//! canonical payload alone), where numbering punctuation has been stripped.
+//! Clauses are recovered from the sequence of bare numbers `1 2 3 ...`; a
+//! number directly preceded by a reference word (`clause 2`) is a reference,
+//! not a clause marker. Bullets carry no number and are dropped by
+//! normalization, so bulleted lists are only recognized when numbered.
+//!
+//! A paradox is reported when:
+//! - a clause states `clause X holds iff clause Y is false` where `X == Y`
+//!   or either side refers back to the clause itself, or
+//! - the truth assertions between clauses (`clause K is true` / `clause K
+//!   is false`) form a cycle with an odd number of negations (a liar loop,
+//!   including a clause asserting its own falsity).
+
+use lazy_static::lazy_static;
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::DiGraph;
+use regex::Regex;
+
+use super::canonicalize::normalize_text;
+
+/// Words that turn a following number into a clause reference
+const REFERENCE_WORDS: &[&str] = &["clause", "clauses", "item", "point", "section", "paragraph"];
+
+lazy_static! {
+    /// `clause K <truth assertion>`
+    static ref ASSERTION: Regex = Regex::new(
+        r"clause (\d+) (is true|holds|is valid|is false|does not hold|is invalid|fails)"
+    ).unwrap();
+
+    /// `clause X holds iff clause Y is false`
+    static ref BICONDITIONAL: Regex = Regex::new(
+        r"clause (\d+) (?:is true|holds|applies) (?:iff|if and only if) clause (\d+) (?:is false|does not hold|fails)"
+    ).unwrap();
+}
+
+/// Force compilation of the clause patterns (see `channel_a::warm_up`)
+pub(crate) fn warm_up() {
+    lazy_static::initialize(&ASSERTION);
+    lazy_static::initialize(&BICONDITIONAL);
+}
+
+/// A numbered clause recovered from normalized text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    /// The clause number as written
+    pub number: usize,
+    /// Normalized clause body (without its number)
+    pub text: String,
+}
+
+/// Split text into numbered clauses
+///
+/// Text before clause 1 is ignored. Returns an empty list unless at least
+/// two clauses are found.
+pub fn split_clauses(text: &str) -> Vec<Clause> {
+    let normalized = normalize_text(text);
+    let tokens: Vec<&str> = normalized.split(' ').collect();
+
+    let mut clauses: Vec<(usize, Vec<&str>)> = Vec::new();
+    let mut expected = 1usize;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let is_reference = i > 0 && REFERENCE_WORDS.contains(&tokens[i - 1]);
+        if !is_reference && token.parse::<usize>().ok() == Some(expected) {
+            clauses.push((expected, Vec::new()));
+            expected += 1;
+        } else if let Some((_, body)) = clauses.last_mut() {
+            body.push(token);
+        }
+    }
+
+    if clauses.len() < 2 {
+        return Vec::new();
+    }
+
+    clauses
+        .into_iter()
+        .map(|(number, body)| Clause {
+            number,
+            text: body.join(" "),
+        })
+        .collect()
+}
+
+/// Detect paradoxes expressed across numbered clauses
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::detect_clause_paradox;
+///
+/// assert!(detect_clause_paradox("1. This proposal passes. 2. Clause 1 holds iff clause 2 is false."));
+/// assert!(!detect_clause_paradox("1. Transfer 100 tokens. 2. Raise the quorum to 15%."));
+/// ```
+pub fn detect_clause_paradox(text: &str) -> bool {
+    let clauses = split_clauses(text);
+    if clauses.is_empty() {
+        return false;
+    }
+    let count = clauses.len();
+
+    // Doubled graph: node 2*i is "clause i+1 is true", 2*i+1 is "clause i+1 is false".
+    // An odd-negation cycle puts both polarities of a clause in one SCC.
+    let mut graph: DiGraph<(), ()> = DiGraph::new();
+    let nodes: Vec<_> = (0..2 * count).map(|_| graph.add_node(())).collect();
+
+    for clause in &clauses {
+        for caps in BICONDITIONAL.captures_iter(&clause.text) {
+            let x: usize = caps[1].parse().unwrap_or(0);
+            let y: usize = caps[2].parse().unwrap_or(0);
+            if x == y || x == clause.number || y == clause.number {
+                return true;
+            }
+        }
+
+        for caps in ASSERTION.captures_iter(&clause.text) {
+            let target: usize = match caps[1].parse() {
+                Ok(n) if (1..=count).contains(&n) => n,
+                _ => continue,
+            };
+            let negated = matches!(
+                &caps[2],
+                "is false" | "does not hold" | "is invalid" | "fails"
+            );
+
+            let from = clause.number - 1;
+            let to = target - 1;
+            for polarity in 0..2 {
+                let flipped = if negated { 1 - polarity } else { polarity };
+                graph.add_edge(nodes[2 * from + polarity], nodes[2 * to + flipped], ());
+            }
+        }
+    }
+
+    tarjan_scc(&graph).iter().any(|scc| {
+        let mut seen = vec![false; count];
+        scc.iter().any(|node| {
+            let clause = node.index() / 2;
+            std::mem::replace(&mut seen[clause], true)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_clauses() {
+        let clauses = split_clauses("Preamble. 1. Pay out. 2. See clause 1 for details. 3) Stop.");
+        assert_eq!(
+            clauses,
+            vec![
+                Clause {
+                    number: 1,
+                    text: "pay out".to_string()
+                },
+                Clause {
+                    number: 2,
+                    text: "see clause 1 for details".to_string()
+                },
+                Clause {
+                    number: 3,
+                    text: "stop".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_clause_paradox_by_number() {
+        assert!(detect_clause_paradox(
+            "1. This proposal passes. 2. Clause 1 holds iff clause 2 is false."
+        ));
+    }
+
+    #[test]
+    fn test_two_sentence_liar_loop() {
+        assert!(detect_clause_paradox(
+            "1. Clause 2 is true.\n2. Clause 1 is false."
+        ));
+        assert!(detect_clause_paradox(
+            "1. Clause 1 is false. 2. Pay the auditor."
+        ));
+    }
+
+    #[test]
+    fn test_consistent_cross_references_pass() {
+        // Even number of negations: consistent
+        assert!(!detect_clause_paradox(
+            "1. Clause 2 is false. 2. Clause 1 is false."
+        ));
+        assert!(!detect_clause_paradox(
+            "1. Clause 2 holds. 2. Clause 1 holds."
+        ));
+    }
+
+    #[test]
+    fn test_unrelated_numbered_list_passes() {
+        assert!(!detect_clause_paradox(
+            "1. Transfer 100 tokens to the fund. 2. Increase the quorum to 15%. 3. Clause 1 applies after 2 days."
+        ));
+        assert!(!detect_clause_paradox(
+            "Transfer 100 tokens to the community fund"
+        ));
+    }
+}
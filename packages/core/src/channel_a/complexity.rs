@@ -8,21 +8,104 @@
 //!
 //! The score is derived from compressing the full payload, not its hash.
 //!
+//! # Compression Backend
+//!
+//! The score is consensus-critical, so the compressor is locked: the crate
+//! calls the pure-Rust `miniz_oxide` deflater directly, pinned to an exact
+//! version, rather than going through `flate2`. flate2's output depends on
+//! which backend (`miniz_oxide`, `zlib`, `zlib-ng`, `zlib-rs`) feature
+//! unification happens to select in the final binary, and the backends emit
+//! different byte counts for the same input, which would make honest
+//! oracles disagree. Upgrading `miniz_oxide` requires re-checking the
+//! exact-length test vectors below and the self-test golden vectors.
+//!
+//! The deflater settings are spelled out rather than taken from a
+//! convenience wrapper's defaults:
+//!
+//! - Level: 9 (`ZLIB_LEVEL`)
+//! - Window: 15 bits, 32 KiB (`ZLIB_WINDOW_BITS`), with the zlib header
+//!   and Adler-32 trailer
+//! - Strategy: default, i.e. LZ77 with dynamic Huffman blocks
+//!   (`ZLIB_STRATEGY`)
+//! - Dictionary: none, except under `ComplexityAlgorithm::ZlibDictionary`
+//!
+//! miniz_oxide has no memory-level setting; its hash table and buffer
+//! sizes are compile-time constants of the pinned version.
+//!
+//! # Shared-Dictionary Scoring (opt-in)
+//!
+//! Every canonical payload repeats the same scaffolding: braces, common
+//! keys, the `.` separator, everyday governance words. With
+//! `ComplexityAlgorithm::ZlibDictionary` the payload is compressed against
+//! a preset dictionary of those tokens (`DEFAULT_COMPLEXITY_DICTIONARY`),
+//! so boilerplate costs almost nothing and the score tracks novel content.
+//!
+//! miniz_oxide has no preset-dictionary API, so the dictionary is fed
+//! through the same raw deflater first and ended with a sync flush, which
+//! keeps it in the match window; only the bytes produced for the payload
+//! are kept. The result is a valid zlib stream with the `FDICT` flag set
+//! (header, dictionary Adler-32, deflate data, payload Adler-32) that any
+//! zlib inflater decodes when given the same dictionary.
+//!
+//! This is a different consensus algorithm, not a tweak: scores are not
+//! comparable with `Zlib` scores, so every oracle must select the same
+//! algorithm, identified by `ComplexityAlgorithm::version`.
+//!
+//! # Payload Size Guard
+//!
+//! Compression costs time and memory in proportion to the payload, and
+//! canonicalization does not bound it. A payload longer than
+//! `config::MAX_PAYLOAD_BYTES` is therefore never compressed: it scores
+//! `COMPLEXITY_FAILURE` immediately, whatever it would have compressed to,
+//! so the hard gate stays cheap and still deterministic. The bound is
+//! consensus-relevant; `ComplexityAlgorithm::score_within` takes an
+//! explicit one.
+//!
+//! # Per-Layer Limits
+//!
+//! The score is gated against a limit that depends on the proposal's
+//! governance layer (`ComplexityLimits`). The default applies the spec's
+//! `MAX_COMPLEXITY` to every layer; tightening L1 or loosening L3 is
+//! consensus-relevant, so a network must agree on its limits like any other
+//! pipeline setting.
+//!
 //! # Test Vector
 //!
 //! - Input: A simple proposal to transfer 100 tokens
 //! - Expected Score: ~75-150 (depending on exact text)
 
-use flate2::Compression;
-use flate2::write::ZlibEncoder;
-use std::io::Write;
+use miniz_oxide::deflate::core::{
+    compress, create_comp_flags_from_zip_params, CompressorOxide, TDEFLFlush, TDEFLStatus,
+};
+use miniz_oxide::{mz_adler32_oxide, MZ_ADLER32_INIT};
+use serde::{Deserialize, Serialize};
 
-use crate::types::config::MAX_COMPLEXITY;
+use super::canonicalize::{
+    canonical_ast_bytes, normalize_text, parse_ast, CanonicalPayload, CanonicalizeError,
+    CanonicalizeOptions,
+};
+use crate::types::config::{MAX_COMPLEXITY, MAX_PAYLOAD_BYTES};
+use crate::types::GovernanceLayer;
+
+/// zlib compression level used for scoring (maximum)
+const ZLIB_LEVEL: u8 = 9;
+
+/// Base-2 log of the deflate window size (32 KiB, the zlib maximum)
+const ZLIB_WINDOW_BITS: i32 = 15;
+
+/// Deflate strategy (`Z_DEFAULT_STRATEGY`)
+const ZLIB_STRATEGY: i32 = 0;
+
+/// Fail-safe score for a payload that could not be scored
+///
+/// Always above any limit, so an unscorable payload can never PASS.
+pub const COMPLEXITY_FAILURE: u64 = u64::MAX;
 
 /// Compute the complexity score of a canonical payload
 ///
 /// Uses zlib compression at maximum level (9) to measure
-/// the information content of the proposal.
+/// the information content of the proposal. Payloads longer than
+/// `MAX_PAYLOAD_BYTES` score `COMPLEXITY_FAILURE` without being compressed.
 ///
 /// # Rationale
 ///
@@ -40,27 +123,437 @@ use crate::types::config::MAX_COMPLEXITY;
 /// assert!(score < 100);
 /// ```
 pub fn compute_complexity(payload: &[u8]) -> u64 {
-    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    ComplexityAlgorithm::Zlib.score(payload)
+}
+
+/// Default preset dictionary for `ComplexityAlgorithm::ZlibDictionary`
+///
+/// Canonical AST scaffolding and common normalized-text words. zlib
+/// encodes nearer matches more cheaply, so the most frequent tokens come
+/// last. Consensus-critical: changing it changes every dictionary score.
+pub const DEFAULT_COMPLEXITY_DICTIONARY: &[u8] = b"\
+proposal amendment parameter threshold delegate council member vote voting \
+quorum timelock period epoch oracle jury review bond stake reward slash \
+budget grant allocate fund treasury community account address payment \
+update change set increase decrease remove add enable disable \
+in on by for from with of and to the \
+{\"target\":\"\"value\":\"$ref:\"references\":\"depends_on\":[\"\
+\"decidability_class\":\"\"params\":{\"recipient\":\"\"amount\":\"action\":\"\
+treasury community fund transfer tokens\"}.";
+
+/// Zlib header for level 9 with a preset dictionary (`FDICT` set)
+const ZLIB_DICT_HEADER: [u8; 2] = [0x78, 0xF9];
+
+/// Complexity algorithm used by the hard gate
+///
+/// The default, `Zlib`, is the spec v5.1 score. Switching algorithms is a
+/// consensus change.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComplexityAlgorithm {
+    /// `len(zlib(payload))` (spec v5.1)
+    #[default]
+    Zlib,
+    /// `len(zlib(payload))` against `DEFAULT_COMPLEXITY_DICTIONARY`
+    ZlibDictionary,
+}
+
+impl ComplexityAlgorithm {
+    /// Algorithm version number, for recording alongside scores
+    pub fn version(self) -> u8 {
+        match self {
+            ComplexityAlgorithm::Zlib => 1,
+            ComplexityAlgorithm::ZlibDictionary => 2,
+        }
+    }
+
+    /// Score a canonical payload with this algorithm
+    pub fn score(self, payload: &[u8]) -> u64 {
+        self.score_within(payload, MAX_PAYLOAD_BYTES)
+    }
+
+    /// Score a payload, or `COMPLEXITY_FAILURE` if it is longer than `max_payload_bytes`
+    ///
+    /// An oversized payload is rejected before any compression work.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::{ComplexityAlgorithm, COMPLEXITY_FAILURE};
+    ///
+    /// let payload = b"transfer 100 tokens";
+    /// assert!(ComplexityAlgorithm::Zlib.score_within(payload, payload.len()) < 100);
+    /// assert_eq!(ComplexityAlgorithm::Zlib.score_within(payload, payload.len() - 1), COMPLEXITY_FAILURE);
+    /// ```
+    pub fn score_within(self, payload: &[u8], max_payload_bytes: usize) -> u64 {
+        if payload.len() > max_payload_bytes {
+            return COMPLEXITY_FAILURE;
+        }
+        let compressed = match self {
+            ComplexityAlgorithm::Zlib => compress_zlib(payload),
+            ComplexityAlgorithm::ZlibDictionary => {
+                compress_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY)
+            }
+        };
+        compressed.len() as u64
+    }
+}
+
+/// Compute the complexity score of a payload against a preset dictionary
+///
+/// The score is the length of the zlib stream, including the 4-byte
+/// dictionary ID. An empty dictionary gives the plain `compute_complexity`
+/// score, and payloads over `MAX_PAYLOAD_BYTES` score `COMPLEXITY_FAILURE`
+/// as they do there.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{
+///     compute_complexity, compute_complexity_with_dictionary, DEFAULT_COMPLEXITY_DICTIONARY,
+/// };
+///
+/// let payload = br#"{"action":"transfer","amount":100}.transfer 100 tokens to the community fund"#;
+/// let with_dict = compute_complexity_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY);
+/// assert!(with_dict < compute_complexity(payload));
+/// ```
+pub fn compute_complexity_with_dictionary(payload: &[u8], dict: &[u8]) -> u64 {
+    if payload.len() > MAX_PAYLOAD_BYTES {
+        return COMPLEXITY_FAILURE;
+    }
+    compress_with_dictionary(payload, dict).len() as u64
+}
+
+/// Build a zlib stream for `payload` with the pinned deflater settings
+fn compress_zlib(payload: &[u8]) -> Vec<u8> {
+    let flags =
+        create_comp_flags_from_zip_params(ZLIB_LEVEL.into(), ZLIB_WINDOW_BITS, ZLIB_STRATEGY);
+    deflate(
+        &mut CompressorOxide::new(flags),
+        payload,
+        TDEFLFlush::Finish,
+    )
+}
+
+/// Build a zlib stream for `payload` with `dict` as the preset dictionary
+fn compress_with_dictionary(payload: &[u8], dict: &[u8]) -> Vec<u8> {
+    if dict.is_empty() {
+        return compress_zlib(payload);
+    }
+
+    // Raw deflate (negative window bits); the zlib framing is added below
+    let flags =
+        create_comp_flags_from_zip_params(ZLIB_LEVEL.into(), -ZLIB_WINDOW_BITS, ZLIB_STRATEGY);
+    let mut compressor = CompressorOxide::new(flags);
+    deflate(&mut compressor, dict, TDEFLFlush::Sync);
+    let body = deflate(&mut compressor, payload, TDEFLFlush::Finish);
+
+    let mut stream = Vec::with_capacity(body.len() + 10);
+    stream.extend_from_slice(&ZLIB_DICT_HEADER);
+    stream.extend_from_slice(&mz_adler32_oxide(MZ_ADLER32_INIT, dict).to_be_bytes());
+    stream.extend_from_slice(&body);
+    stream.extend_from_slice(&mz_adler32_oxide(MZ_ADLER32_INIT, payload).to_be_bytes());
+    stream
+}
+
+/// Feed `input` to the compressor and return the bytes it emits
+fn deflate(compressor: &mut CompressorOxide, mut input: &[u8], flush: TDEFLFlush) -> Vec<u8> {
+    let mut output = vec![0; input.len() + input.len() / 8 + 64];
+    let mut out_pos = 0;
+    loop {
+        let (status, bytes_in, bytes_out) =
+            compress(compressor, input, &mut output[out_pos..], flush);
+        out_pos += bytes_out;
+        input = &input[bytes_in..];
 
-    // Write the payload to the encoder
-    if encoder.write_all(payload).is_err() {
-        // On error, return max complexity (fail-safe)
-        return u64::MAX;
+        match status {
+            TDEFLStatus::Done => break,
+            // A sync flush is complete once all input is consumed and the
+            // output buffer was not filled
+            TDEFLStatus::Okay
+                if input.is_empty() && out_pos < output.len() && flush == TDEFLFlush::Sync =>
+            {
+                break
+            }
+            TDEFLStatus::Okay => {
+                if output.len() - out_pos < 30 {
+                    output.resize(output.len() * 2, 0);
+                }
+            }
+            // Compressing into memory cannot fail
+            _ => unreachable!("deflate failed with {:?}", status),
+        }
     }
+    output.truncate(out_pos);
+    output
+}
+
+/// Presentation unit for complexity scores
+///
+/// The consensus gate always uses `Bytes`; the other units are a display
+/// convenience for dashboards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComplexityUnit {
+    /// Compressed size in bytes (the consensus score)
+    #[default]
+    Bytes,
+    /// Compressed size in bits (bytes * 8)
+    Bits,
+    /// Score as a percentage of `MAX_COMPLEXITY`
+    NormalizedPercent,
+}
+
+/// Convert a complexity score (bytes) into the requested unit
+pub fn convert_complexity(score: u64, unit: ComplexityUnit) -> f64 {
+    match unit {
+        ComplexityUnit::Bytes => score as f64,
+        ComplexityUnit::Bits => score as f64 * 8.0,
+        ComplexityUnit::NormalizedPercent => score as f64 / MAX_COMPLEXITY as f64 * 100.0,
+    }
+}
+
+/// Compute the complexity of a payload in the requested unit
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{complexity_in, compute_complexity, ComplexityUnit};
+///
+/// let payload = b"transfer 100 tokens";
+/// let bytes = compute_complexity(payload);
+/// assert_eq!(complexity_in(payload, ComplexityUnit::Bits), (bytes * 8) as f64);
+/// ```
+pub fn complexity_in(payload: &[u8], unit: ComplexityUnit) -> f64 {
+    convert_complexity(compute_complexity(payload), unit)
+}
+
+/// Complexity contributed by the proposal text alone
+///
+/// Compresses the text portion of the payload, `"." + normalized_text`,
+/// so an author can see the floor their text sets before budgeting the
+/// AST. Diagnostic only: compression is not additive, so this plus
+/// `ast_only_complexity` only approximates the consensus score.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::text_only_complexity;
+///
+/// let floor = text_only_complexity("Transfer 100 tokens to the community fund");
+/// assert!(floor > 0 && floor < 100);
+/// ```
+pub fn text_only_complexity(text: &str) -> u64 {
+    let mut portion = Vec::with_capacity(text.len() + 1);
+    portion.push(b'.');
+    portion.extend(normalize_text(text).as_bytes());
+    compute_complexity(&portion)
+}
+
+/// Complexity contributed by the logic AST alone
+///
+/// Compresses the canonical (sorted, compact) AST JSON, without the `.`
+/// separator or text. Diagnostic only, like `text_only_complexity`.
+pub fn ast_only_complexity(ast_json: &str) -> Result<u64, CanonicalizeError> {
+    let ast = parse_ast(ast_json)?;
+    Ok(compute_complexity(&canonical_ast_bytes(
+        &ast,
+        &CanonicalizeOptions::default(),
+    )?))
+}
+
+/// A complexity score split into its AST and text contributions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplexityBreakdown {
+    /// Score of the whole payload (the consensus score)
+    pub total: u64,
+    /// Score of the canonical AST JSON alone
+    pub ast_score: u64,
+    /// Score of `"." + normalized_text` alone
+    pub text_score: u64,
+}
 
-    // Finish compression and get the result
-    match encoder.finish() {
-        Ok(compressed) => compressed.len() as u64,
-        Err(_) => u64::MAX,
+/// Split a payload's complexity into AST and text contributions
+///
+/// Each half is compressed on its own, so the two scores match
+/// `ast_only_complexity` and `text_only_complexity`. They do not sum to
+/// `total`: in the single stream the text can reuse matches from the AST,
+/// the zlib framing is paid once and the Huffman tables are shared, so the
+/// sum can land above or below the total. The larger share is still the
+/// half to trim. Diagnostic only; the gate compares `total`.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{canonicalize, compute_complexity_breakdown};
+/// use constitution_dao_core::{GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+/// let breakdown = compute_complexity_breakdown(&canonicalize(&proposal).unwrap());
+/// assert!(breakdown.ast_score > breakdown.text_score);
+/// ```
+pub fn compute_complexity_breakdown(canonical: &CanonicalPayload) -> ComplexityBreakdown {
+    // The text half keeps its "." separator, as in `text_only_complexity`
+    let text_start = canonical.text_range.start.saturating_sub(1);
+    ComplexityBreakdown {
+        total: compute_complexity(&canonical.bytes),
+        ast_score: compute_complexity(canonical.ast_bytes()),
+        text_score: compute_complexity(&canonical.bytes[text_start..canonical.text_range.end]),
     }
 }
 
-/// Check if a complexity score passes the threshold
+/// Round a complexity score to the nearest multiple of `bucket_size`, for display
+///
+/// Hides the exact score where it could leak information about the
+/// proposal content. Halves round up. Display only: the hard gate always
+/// compares the exact score, so a bucketed score at or below the limit
+/// does not imply a pass. A `bucket_size` of 0 or 1 returns the score
+/// unchanged, as does `COMPLEXITY_FAILURE`; a score near `u64::MAX`
+/// rounds down rather than overflowing.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::complexity_bucket;
+///
+/// assert_eq!(complexity_bucket(1234, 100), 1200);
+/// assert_eq!(complexity_bucket(1250, 100), 1300);
+/// assert_eq!(complexity_bucket(1234, 0), 1234);
+/// ```
+pub fn complexity_bucket(score: u64, bucket_size: u64) -> u64 {
+    if bucket_size <= 1 || score == COMPLEXITY_FAILURE {
+        return score;
+    }
+    let lower = score - score % bucket_size;
+    if score - lower >= bucket_size.div_ceil(2) {
+        lower.checked_add(bucket_size).unwrap_or(lower)
+    } else {
+        lower
+    }
+}
+
+/// Default share of `MAX_COMPLEXITY` (in percent) at which a score is near the limit
+pub const NEAR_LIMIT_PERCENT: f64 = 90.0;
+
+/// How much room a complexity score leaves below `MAX_COMPLEXITY`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ComplexityHeadroom {
+    /// Bytes left below the limit (0 at or above it)
+    pub headroom: u64,
+    /// Headroom as a percentage of the limit (0.0 at or above it)
+    pub headroom_percent: f64,
+    /// Score is at or above the near-limit threshold
+    pub near_limit: bool,
+}
+
+/// Headroom below `MAX_COMPLEXITY` using the default 90% near-limit threshold
 ///
-/// Returns true if the score is within acceptable limits.
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{complexity_headroom, max_complexity};
+///
+/// let headroom = complexity_headroom(max_complexity() / 2);
+/// assert_eq!(headroom.headroom_percent, 50.0);
+/// assert!(!headroom.near_limit);
+/// ```
+pub fn complexity_headroom(score: u64) -> ComplexityHeadroom {
+    complexity_headroom_with(score, NEAR_LIMIT_PERCENT)
+}
+
+/// Headroom below `MAX_COMPLEXITY` with a custom near-limit threshold
+///
+/// `near_limit_percent` is the share of the limit (0-100) at or above which
+/// `near_limit` is set. Scores over the limit are always near it.
+pub fn complexity_headroom_with(score: u64, near_limit_percent: f64) -> ComplexityHeadroom {
+    let headroom = MAX_COMPLEXITY.saturating_sub(score);
+    let used_percent = convert_complexity(score, ComplexityUnit::NormalizedPercent);
+
+    ComplexityHeadroom {
+        headroom,
+        headroom_percent: convert_complexity(headroom, ComplexityUnit::NormalizedPercent),
+        near_limit: score > MAX_COMPLEXITY || used_percent >= near_limit_percent,
+    }
+}
+
+/// Maximum complexity score for each governance layer
+///
+/// L0 cannot be targeted by proposals, so it has no limit of its own:
+/// `limit_for(L0Immutable)` is 0 and nothing passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplexityLimits {
+    /// Limit for L1 (Constitutional) proposals
+    pub constitutional: u64,
+    /// Limit for L2 (Operational) proposals
+    pub operational: u64,
+    /// Limit for L3 (Execution) proposals
+    pub execution: u64,
+}
+
+impl ComplexityLimits {
+    /// The same limit for every targetable layer
+    pub fn uniform(limit: u64) -> Self {
+        Self {
+            constitutional: limit,
+            operational: limit,
+            execution: limit,
+        }
+    }
+
+    /// Override the limit for one layer
+    ///
+    /// Setting a limit for L0 has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::ComplexityLimits;
+    /// use constitution_dao_core::GovernanceLayer;
+    ///
+    /// let limits = ComplexityLimits::default()
+    ///     .with_limit(GovernanceLayer::L1Constitutional, 2_000)
+    ///     .with_limit(GovernanceLayer::L3Execution, 50_000);
+    /// assert_eq!(limits.limit_for(GovernanceLayer::L1Constitutional), 2_000);
+    /// assert_eq!(limits.limit_for(GovernanceLayer::L2Operational), 10_000);
+    /// ```
+    pub fn with_limit(mut self, layer: GovernanceLayer, limit: u64) -> Self {
+        match layer {
+            GovernanceLayer::L0Immutable => {}
+            GovernanceLayer::L1Constitutional => self.constitutional = limit,
+            GovernanceLayer::L2Operational => self.operational = limit,
+            GovernanceLayer::L3Execution => self.execution = limit,
+        }
+        self
+    }
+
+    /// The limit applied to proposals targeting `layer`
+    pub fn limit_for(&self, layer: GovernanceLayer) -> u64 {
+        match layer {
+            GovernanceLayer::L0Immutable => 0,
+            GovernanceLayer::L1Constitutional => self.constitutional,
+            GovernanceLayer::L2Operational => self.operational,
+            GovernanceLayer::L3Execution => self.execution,
+        }
+    }
+}
+
+impl Default for ComplexityLimits {
+    /// `MAX_COMPLEXITY` for every layer, as in the spec
+    fn default() -> Self {
+        Self::uniform(MAX_COMPLEXITY)
+    }
+}
+
+/// Check if a complexity score passes the effective limit
+///
+/// Returns true if the score is at most `limit`; see `ComplexityLimits`
+/// for the limit that applies to a layer.
 #[inline]
-pub fn check_complexity(score: u64) -> bool {
-    score <= MAX_COMPLEXITY
+pub fn check_complexity(score: u64, limit: u64) -> bool {
+    score <= limit
 }
 
 /// Get the maximum allowed complexity score
@@ -72,6 +565,9 @@ pub fn max_complexity() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::channel_a::canonicalize;
+    use crate::types::Proposal;
+    use sha2::{Digest, Sha256};
 
     #[test]
     fn test_simple_payload_low_complexity() {
@@ -80,7 +576,7 @@ mod tests {
 
         // Simple text should have low complexity
         assert!(score < 100);
-        assert!(check_complexity(score));
+        assert!(check_complexity(score, MAX_COMPLEXITY));
     }
 
     #[test]
@@ -95,8 +591,16 @@ mod tests {
 
     #[test]
     fn test_random_payload_high_complexity() {
-        // Random data doesn't compress well
-        let random: Vec<u8> = (0..10000).map(|i| (i * 17 + 31) as u8).collect();
+        // Random data doesn't compress well (xorshift, so the bytes don't repeat)
+        let mut state: u32 = 0x9E37_79B9;
+        let random: Vec<u8> = (0..10000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state >> 24) as u8
+            })
+            .collect();
         let score = compute_complexity(&random);
 
         // Random data should have high complexity
@@ -111,7 +615,11 @@ mod tests {
         let score = compute_complexity(payload.as_bytes());
 
         // Should be in the expected range
-        assert!(score >= 50 && score <= 200, "Score {} not in expected range", score);
+        assert!(
+            (50..=200).contains(&score),
+            "Score {} not in expected range",
+            score
+        );
     }
 
     #[test]
@@ -122,10 +630,400 @@ mod tests {
         assert!(score < 50);
     }
 
+    #[test]
+    fn test_exact_compressed_lengths() {
+        // Must hold on every platform and build; a change here is a consensus break
+        let hashed: Vec<u8> = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+        let vectors: [(&[u8], u64); 5] = [
+            (b"", 8),
+            (b"transfer 100 tokens", 27),
+            (
+                br#"{"action":"transfer","amount":100}.transfer 100 tokens to the community fund"#,
+                76,
+            ),
+            (&[b'a'; 100_000], 121),
+            (&hashed, 919),
+        ];
+
+        for (payload, expected) in vectors {
+            assert_eq!(
+                compute_complexity(payload),
+                expected,
+                "payload of {} bytes",
+                payload.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_pinned_zlib_stream() {
+        let payload =
+            br#"{"action":"transfer","amount":100}.transfer 100 tokens to the community fund"#;
+        let stream = compress_zlib(payload);
+
+        // CMF 0x78 is deflate with a 32 KiB window; FLG 0xDA is level 9, no dictionary
+        assert_eq!(stream[..2], [0x78, 0xDA]);
+        assert_eq!(stream.len(), 76);
+        assert_eq!(
+            hex::encode(Sha256::digest(&stream)),
+            "fd7174e8f4ed93bff7e5a47434580e9c94000c9c098731773f91db7675673156"
+        );
+        assert_eq!(
+            stream[stream.len() - 4..],
+            mz_adler32_oxide(MZ_ADLER32_INIT, payload).to_be_bytes()
+        );
+        assert_eq!(
+            miniz_oxide::inflate::decompress_to_vec_zlib(&stream).unwrap(),
+            payload
+        );
+
+        // The same stream the crate's one-shot zlib helper produces at level 9
+        assert_eq!(
+            stream,
+            miniz_oxide::deflate::compress_to_vec_zlib(payload, ZLIB_LEVEL)
+        );
+    }
+
+    #[test]
+    fn test_dictionary_score_vectors() {
+        let payload =
+            br#"{"action":"transfer","amount":100}.transfer 100 tokens to the community fund"#;
+
+        // Against the 76-byte plain score: the scaffolding is now in the dictionary
+        assert_eq!(compute_complexity(payload), 76);
+        assert_eq!(
+            compute_complexity_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY),
+            47
+        );
+        assert_eq!(ComplexityAlgorithm::ZlibDictionary.score(payload), 47);
+        assert_eq!(ComplexityAlgorithm::Zlib.score(payload), 76);
+
+        assert_eq!(compute_complexity_with_dictionary(payload, b""), 76);
+    }
+
+    #[test]
+    fn test_dictionary_score_deterministic() {
+        let payload = br#"{"params":{"quorum":0.2},"target":"voting"}.raise the voting quorum to twenty percent"#;
+        let first = compute_complexity_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY);
+        for _ in 0..10 {
+            assert_eq!(
+                compute_complexity_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY),
+                first
+            );
+        }
+
+        // Novel content still costs: unrelated bytes gain little from the dictionary
+        let novel = b"zq7 xv9 kj2 wp4 fm8 lb3 rt6 yc1 hn5 gd0";
+        let plain = compute_complexity(novel);
+        let dict = compute_complexity_with_dictionary(novel, DEFAULT_COMPLEXITY_DICTIONARY);
+        assert!(dict + 6 >= plain, "{} vs {}", dict, plain);
+    }
+
+    #[test]
+    fn test_dictionary_stream_is_valid_zlib() {
+        let payload = br#"{"amount":5,"recipient":"rAddr"}.pay the council member"#;
+        let stream = compress_with_dictionary(payload, DEFAULT_COMPLEXITY_DICTIONARY);
+
+        assert_eq!(stream[..2], ZLIB_DICT_HEADER);
+        assert_eq!(u16::from_be_bytes([stream[0], stream[1]]) % 31, 0);
+        let dict_id = mz_adler32_oxide(MZ_ADLER32_INIT, DEFAULT_COMPLEXITY_DICTIONARY);
+        assert_eq!(stream[2..6], dict_id.to_be_bytes());
+
+        // Inflating the dictionary's stream followed by the payload's deflate data gives both back
+        let flags =
+            create_comp_flags_from_zip_params(ZLIB_LEVEL.into(), -ZLIB_WINDOW_BITS, ZLIB_STRATEGY);
+        let mut compressor = CompressorOxide::new(flags);
+        let mut raw = deflate(
+            &mut compressor,
+            DEFAULT_COMPLEXITY_DICTIONARY,
+            TDEFLFlush::Sync,
+        );
+        raw.extend_from_slice(&stream[6..stream.len() - 4]);
+        let inflated = miniz_oxide::inflate::decompress_to_vec(&raw).unwrap();
+        assert_eq!(
+            inflated,
+            [DEFAULT_COMPLEXITY_DICTIONARY, &payload[..]].concat()
+        );
+    }
+
+    #[test]
+    fn test_payload_size_guard_boundary() {
+        // Highly compressible, so only the guard can push it over the limit
+        let at_limit = vec![b'a'; MAX_PAYLOAD_BYTES];
+        let score = compute_complexity(&at_limit);
+        assert!(score < MAX_COMPLEXITY);
+        assert_eq!(ComplexityAlgorithm::Zlib.score(&at_limit), score);
+        assert!(
+            compute_complexity_with_dictionary(&at_limit, DEFAULT_COMPLEXITY_DICTIONARY)
+                < MAX_COMPLEXITY
+        );
+
+        let over = vec![b'a'; MAX_PAYLOAD_BYTES + 1];
+        assert_eq!(compute_complexity(&over), COMPLEXITY_FAILURE);
+        assert_eq!(
+            compute_complexity_with_dictionary(&over, DEFAULT_COMPLEXITY_DICTIONARY),
+            COMPLEXITY_FAILURE
+        );
+        for algorithm in [
+            ComplexityAlgorithm::Zlib,
+            ComplexityAlgorithm::ZlibDictionary,
+        ] {
+            assert_eq!(algorithm.score(&over), COMPLEXITY_FAILURE);
+            assert_eq!(
+                algorithm.score_within(&at_limit, MAX_PAYLOAD_BYTES - 1),
+                COMPLEXITY_FAILURE
+            );
+        }
+    }
+
+    #[test]
+    fn test_oversized_proposal_fails_complexity() {
+        use crate::channel_a::{verify_proposal_with, FailureReason, VerificationConfig};
+
+        // The version byte, "{}" and "." precede the text in the payload
+        let text_len = MAX_PAYLOAD_BYTES - 4;
+        let proposal = |len: usize| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                "{}".to_string(),
+                "a".repeat(len),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        assert_eq!(
+            canonicalize(&proposal(text_len)).unwrap().bytes.len(),
+            MAX_PAYLOAD_BYTES
+        );
+
+        let config = VerificationConfig::default();
+        assert!(
+            verify_proposal_with(&proposal(text_len), &config)
+                .verdict
+                .pass
+        );
+
+        let over = verify_proposal_with(&proposal(text_len + 1), &config);
+        assert_eq!(over.verdict.complexity_score, COMPLEXITY_FAILURE);
+        assert_eq!(over.failure_reason, Some(FailureReason::ComplexityExceeded));
+    }
+
+    #[test]
+    fn test_complexity_units() {
+        let payload = br#"{"action":"transfer","amount":100}.transfer 100 tokens"#;
+        let score = compute_complexity(payload);
+
+        assert_eq!(complexity_in(payload, ComplexityUnit::Bytes), score as f64);
+        assert_eq!(
+            complexity_in(payload, ComplexityUnit::Bits),
+            (score * 8) as f64
+        );
+        assert_eq!(
+            complexity_in(payload, ComplexityUnit::NormalizedPercent),
+            score as f64 / MAX_COMPLEXITY as f64 * 100.0
+        );
+        assert_eq!(
+            convert_complexity(MAX_COMPLEXITY, ComplexityUnit::NormalizedPercent),
+            100.0
+        );
+    }
+
     #[test]
     fn test_check_complexity_boundary() {
-        assert!(check_complexity(MAX_COMPLEXITY));
-        assert!(check_complexity(MAX_COMPLEXITY - 1));
-        assert!(!check_complexity(MAX_COMPLEXITY + 1));
+        assert!(check_complexity(MAX_COMPLEXITY, MAX_COMPLEXITY));
+        assert!(check_complexity(MAX_COMPLEXITY - 1, MAX_COMPLEXITY));
+        assert!(!check_complexity(MAX_COMPLEXITY + 1, MAX_COMPLEXITY));
+        assert!(!check_complexity(1, 0));
+    }
+
+    #[test]
+    fn test_complexity_limits() {
+        let defaults = ComplexityLimits::default();
+        for layer in [
+            GovernanceLayer::L1Constitutional,
+            GovernanceLayer::L2Operational,
+            GovernanceLayer::L3Execution,
+        ] {
+            assert_eq!(defaults.limit_for(layer), MAX_COMPLEXITY);
+        }
+        assert_eq!(defaults.limit_for(GovernanceLayer::L0Immutable), 0);
+
+        let custom = defaults
+            .with_limit(GovernanceLayer::L3Execution, 40_000)
+            .with_limit(GovernanceLayer::L0Immutable, 1_000_000);
+        assert_eq!(custom.limit_for(GovernanceLayer::L3Execution), 40_000);
+        assert_eq!(
+            custom.limit_for(GovernanceLayer::L2Operational),
+            MAX_COMPLEXITY
+        );
+        assert_eq!(custom.limit_for(GovernanceLayer::L0Immutable), 0);
+    }
+
+    #[test]
+    fn test_complexity_headroom() {
+        let half = complexity_headroom(MAX_COMPLEXITY / 2);
+        assert_eq!(half.headroom, MAX_COMPLEXITY / 2);
+        assert_eq!(half.headroom_percent, 50.0);
+        assert!(!half.near_limit);
+
+        let ninety = complexity_headroom(MAX_COMPLEXITY * 9 / 10);
+        assert_eq!(ninety.headroom, MAX_COMPLEXITY / 10);
+        assert!((ninety.headroom_percent - 10.0).abs() < 1e-9);
+        assert!(ninety.near_limit);
+        assert!(!complexity_headroom(MAX_COMPLEXITY * 9 / 10 - 1).near_limit);
+
+        let ninety_nine = complexity_headroom(MAX_COMPLEXITY * 99 / 100);
+        assert_eq!(ninety_nine.headroom, MAX_COMPLEXITY / 100);
+        assert!((ninety_nine.headroom_percent - 1.0).abs() < 1e-9);
+        assert!(ninety_nine.near_limit);
+    }
+
+    #[test]
+    fn test_complexity_headroom_over_limit_and_custom_threshold() {
+        let over = complexity_headroom(MAX_COMPLEXITY + 500);
+        assert_eq!(over.headroom, 0);
+        assert_eq!(over.headroom_percent, 0.0);
+        assert!(over.near_limit);
+        assert!(complexity_headroom(COMPLEXITY_FAILURE).near_limit);
+
+        assert!(complexity_headroom_with(MAX_COMPLEXITY * 8 / 10, 75.0).near_limit);
+        assert!(!complexity_headroom_with(MAX_COMPLEXITY * 9 / 10, 95.0).near_limit);
+    }
+
+    #[test]
+    fn test_complexity_breakdown_blames_the_larger_half() {
+        let breakdown = |ast: &str, text: &str| {
+            let p = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let canonical = canonicalize(&p).unwrap();
+            let breakdown = compute_complexity_breakdown(&canonical);
+            assert_eq!(breakdown.total, compute_complexity(&canonical.bytes));
+            assert_eq!(breakdown.ast_score, ast_only_complexity(ast).unwrap());
+            assert_eq!(breakdown.text_score, text_only_complexity(text));
+            breakdown
+        };
+
+        // Many distinct parameters, one-word description
+        let params: Vec<String> = (0..200)
+            .map(|i| format!(r#""param_{}": {}"#, i * 7919 % 1000, i * 31))
+            .collect();
+        let large_ast = breakdown(&format!("{{{}}}", params.join(", ")), "Tune");
+        assert!(
+            large_ast.ast_score > 10 * large_ast.text_score,
+            "{:?}",
+            large_ast
+        );
+
+        // Long prose, trivial logic
+        let words: Vec<String> = (0..300u32)
+            .map(|i| format!("w{}", i.wrapping_mul(2_654_435_761) % 100_000))
+            .collect();
+        let large_text = breakdown(r#"{"action": "signal"}"#, &words.join(" "));
+        assert!(
+            large_text.text_score > 10 * large_text.ast_score,
+            "{:?}",
+            large_text
+        );
+    }
+
+    #[test]
+    fn test_complexity_bucket_sizes() {
+        assert_eq!(complexity_bucket(0, 100), 0);
+        assert_eq!(complexity_bucket(49, 100), 0);
+        assert_eq!(complexity_bucket(50, 100), 100);
+        assert_eq!(complexity_bucket(9_949, 100), 9_900);
+        assert_eq!(complexity_bucket(9_950, 100), 10_000);
+        assert_eq!(complexity_bucket(7, 5), 5);
+        assert_eq!(complexity_bucket(8, 5), 10);
+        assert_eq!(complexity_bucket(1_499, 1_000), 1_000);
+        assert_eq!(complexity_bucket(1_500, 1_000), 2_000);
+
+        for score in [0, 1, 77, 10_001] {
+            assert_eq!(complexity_bucket(score, 0), score);
+            assert_eq!(complexity_bucket(score, 1), score);
+        }
+        assert_eq!(
+            complexity_bucket(COMPLEXITY_FAILURE, 100),
+            COMPLEXITY_FAILURE
+        );
+        assert_eq!(complexity_bucket(u64::MAX - 1, 10), u64::MAX - 5);
+    }
+
+    #[test]
+    fn test_bucketing_does_not_affect_gate() {
+        // Just over the limit, but the bucketed score displays as the limit
+        let over = MAX_COMPLEXITY + 40;
+        assert_eq!(complexity_bucket(over, 100), MAX_COMPLEXITY);
+        assert!(!check_complexity(over, MAX_COMPLEXITY));
+
+        // Just under the limit, but the bucketed score displays above it
+        let under = MAX_COMPLEXITY - 40;
+        assert!(complexity_bucket(under, 1_000) >= MAX_COMPLEXITY);
+        assert!(check_complexity(under, MAX_COMPLEXITY));
+    }
+
+    #[test]
+    fn test_partial_complexities_near_combined() {
+        let cases = [
+            (
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens to the community fund",
+            ),
+            (
+                r#"{"a": {"depends_on": ["b"]}, "b": {"value": 1}}"#,
+                "Two steps, the first waits on the second",
+            ),
+            (r#"{}"#, "Minimal"),
+        ];
+
+        for (ast, text) in cases {
+            let proposal = Proposal::new(
+                "rAddr".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let combined = compute_complexity(&canonicalize(&proposal).unwrap().bytes);
+            let sum = text_only_complexity(text) + ast_only_complexity(ast).unwrap();
+
+            // Each part carries its own zlib header, block framing and
+            // checksum and loses cross-part matches, so the sum sits above
+            // the combined score, but stays in the same range
+            assert!(sum >= combined, "{}: {} < {}", ast, sum, combined);
+            assert!(
+                sum <= combined + combined / 2,
+                "{}: {} far above {}",
+                ast,
+                sum,
+                combined
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_complexities_deterministic() {
+        let text = "Transfer 100 tokens to the community fund";
+        let ast = r#"{"b": 2, "a": [1, 2, 3]}"#;
+
+        assert_eq!(text_only_complexity(text), text_only_complexity(text));
+        assert_eq!(
+            ast_only_complexity(ast).unwrap(),
+            ast_only_complexity(ast).unwrap()
+        );
+        // Canonicalization applies before compression
+        assert_eq!(
+            text_only_complexity(text),
+            text_only_complexity("  TRANSFER 100 tokens, to the community fund!")
+        );
+        assert_eq!(
+            ast_only_complexity(ast).unwrap(),
+            ast_only_complexity(r#"{"a":[1,2,3],"b":2}"#).unwrap()
+        );
+        assert!(ast_only_complexity("{not json").is_err());
     }
 }
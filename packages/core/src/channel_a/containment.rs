@@ -0,0 +1,208 @@
+//! Text Containment
+//!
+//! Advisory duplicate detection. A resubmission that prepends or appends a
+//! few words to an earlier proposal's text gets a new hash while keeping its
+//! substance. `text_containment` compares the normalized texts of two
+//! proposals token by token and reports whether the shorter one appears in
+//! the longer one:
+//!
+//! - `Identical`: the normalized texts are equal
+//! - `Contiguous`: the shorter text is a contiguous run of the longer one
+//!   (prefix, suffix or infix)
+//! - `Subsequence`: the shorter text's tokens appear in order in the longer
+//!   one, with other words interleaved
+//!
+//! `strength` is the fraction of the longer text covered by the shorter
+//! one, halved for a subsequence match. A match is only flagged when it is
+//! identical, or shares at least `MIN_SHARED_TOKENS` tokens at a strength
+//! of at least `MIN_FLAGGED_STRENGTH`, so a short text sharing a word or
+//! two with a long one is not a resubmission. This never affects a verdict.
+
+use serde::{Deserialize, Serialize};
+
+use super::canonicalize::normalize_text;
+
+/// Weight applied to the coverage of a non-contiguous match
+const SUBSEQUENCE_WEIGHT: f64 = 0.5;
+
+/// Fewest shared tokens for a non-identical match to be flagged
+pub const MIN_SHARED_TOKENS: usize = 3;
+
+/// Lowest strength for a non-identical match to be flagged
+pub const MIN_FLAGGED_STRENGTH: f64 = 0.25;
+
+/// How the shorter of two texts appears in the longer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Containment {
+    /// Neither text contains the other
+    Unrelated,
+    /// Tokens appear in order, with gaps
+    Subsequence,
+    /// Tokens appear as one contiguous run
+    Contiguous,
+    /// Normalized texts are equal
+    Identical,
+}
+
+/// Result of comparing two proposal texts
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContainmentResult {
+    /// How the shorter text appears in the longer
+    pub containment: Containment,
+    /// Fraction of the longer text covered, in `[0.0, 1.0]`
+    pub strength: f64,
+    /// Tokens of the shorter text found in the longer (0 when unrelated)
+    pub shared_tokens: usize,
+}
+
+impl ContainmentResult {
+    /// Whether the texts look like a padded resubmission
+    pub fn is_flagged(&self) -> bool {
+        match self.containment {
+            Containment::Unrelated => false,
+            Containment::Identical => true,
+            Containment::Contiguous | Containment::Subsequence => {
+                self.shared_tokens >= MIN_SHARED_TOKENS && self.strength >= MIN_FLAGGED_STRENGTH
+            }
+        }
+    }
+}
+
+/// Report whether one normalized text is contained in the other
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{text_containment, Containment};
+///
+/// let result = text_containment("Transfer 100 tokens", "Transfer 100 tokens, effective now.");
+/// assert_eq!(result.containment, Containment::Contiguous);
+/// assert!(result.is_flagged());
+/// ```
+pub fn text_containment(a: &str, b: &str) -> ContainmentResult {
+    let a = normalize_text(a);
+    let b = normalize_text(b);
+    let a: Vec<&str> = a.split(' ').filter(|t| !t.is_empty()).collect();
+    let b: Vec<&str> = b.split(' ').filter(|t| !t.is_empty()).collect();
+
+    let (shorter, longer) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+    // An empty text is contained in everything; that says nothing
+    if shorter.is_empty() {
+        return ContainmentResult {
+            containment: Containment::Unrelated,
+            strength: 0.0,
+            shared_tokens: 0,
+        };
+    }
+
+    let coverage = shorter.len() as f64 / longer.len() as f64;
+    let (containment, strength) = if shorter == longer {
+        (Containment::Identical, 1.0)
+    } else if longer
+        .windows(shorter.len())
+        .any(|w| w == shorter.as_slice())
+    {
+        (Containment::Contiguous, coverage)
+    } else if is_subsequence(shorter, longer) {
+        (Containment::Subsequence, coverage * SUBSEQUENCE_WEIGHT)
+    } else {
+        (Containment::Unrelated, 0.0)
+    };
+
+    ContainmentResult {
+        containment,
+        strength,
+        shared_tokens: if containment == Containment::Unrelated {
+            0
+        } else {
+            shorter.len()
+        },
+    }
+}
+
+fn is_subsequence(needle: &[&str], haystack: &[&str]) -> bool {
+    let mut rest = haystack.iter();
+    needle.iter().all(|token| rest.any(|t| t == token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_prefix_flagged() {
+        let result = text_containment(
+            "Transfer 100 tokens to the community fund",
+            "Transfer 100 tokens to the community fund. Also thank the team!",
+        );
+        assert_eq!(result.containment, Containment::Contiguous);
+        assert!(result.is_flagged());
+        assert!((result.strength - 7.0 / 11.0).abs() < 1e-12);
+
+        // Order of arguments does not matter
+        assert_eq!(
+            result,
+            text_containment(
+                "Transfer 100 tokens to the community fund. Also thank the team!",
+                "Transfer 100 tokens to the community fund",
+            )
+        );
+    }
+
+    #[test]
+    fn test_shared_subsequence_flagged_weaker() {
+        let padded = text_containment(
+            "Transfer 100 tokens to the fund",
+            "Transfer exactly 100 tokens to the fund",
+        );
+        assert_eq!(padded.containment, Containment::Subsequence);
+        assert!(padded.is_flagged());
+
+        let suffixed = text_containment(
+            "Transfer 100 tokens to the fund",
+            "Transfer 100 tokens to the fund today",
+        );
+        assert_eq!(suffixed.containment, Containment::Contiguous);
+        assert!(padded.strength < suffixed.strength);
+    }
+
+    #[test]
+    fn test_unrelated_and_identical() {
+        let unrelated = text_containment("Transfer 100 tokens", "Raise the oracle bond");
+        assert_eq!(unrelated.containment, Containment::Unrelated);
+        assert!(!unrelated.is_flagged());
+        assert_eq!(unrelated.strength, 0.0);
+
+        // Reordered words are not a subsequence
+        assert!(!text_containment("tokens 100 transfer", "Transfer 100 tokens now").is_flagged());
+
+        let identical = text_containment("Transfer 100 tokens!", "  transfer   100 TOKENS");
+        assert_eq!(identical.containment, Containment::Identical);
+        assert_eq!(identical.strength, 1.0);
+
+        assert!(!text_containment("", "Anything at all").is_flagged());
+    }
+
+    #[test]
+    fn test_small_overlap_not_flagged() {
+        // One shared word is contained, but is not a resubmission
+        let one_word = text_containment("Fund", "Transfer 100 tokens to the fund");
+        assert_eq!(one_word.containment, Containment::Contiguous);
+        assert_eq!(one_word.shared_tokens, 1);
+        assert!(!one_word.is_flagged());
+
+        // Enough tokens, but a small part of a much longer text
+        let long = "Transfer 100 tokens to the fund and report back. ".repeat(4);
+        let weak = text_containment("Transfer 100 tokens", &long);
+        assert_eq!(weak.shared_tokens, 3);
+        assert!(weak.strength < MIN_FLAGGED_STRENGTH);
+        assert!(!weak.is_flagged());
+
+        // A short exact duplicate is still flagged
+        assert!(text_containment("Approved", "approved!").is_flagged());
+    }
+}
@@ -5,13 +5,191 @@
 //! - Graph Extraction: Parse AST to build directed dependency graph
 //! - Algorithm: Tarjan's strongly connected components algorithm
 //! - Output: cycle_found = true if any component contains >1 node or self-edge
+//!
+//! # Edge Types
+//!
+//! The dependency graph carries two kinds of edges:
+//!
+//! - `EdgeKind::Reference`: data references (`$ref:`, `depends_on`,
+//!   `references`, `ref`). Always modeled.
+//! - `EdgeKind::Expansion`: macro/template expansion (`{"$expand": "name"}`).
+//!   Only modeled when `CycleOptions::expand_key` is set. A template that
+//!   (transitively) expands to itself would never finish expanding, so such
+//!   a loop is reported as a cycle just like a data-reference loop.
+//!
+//! # Name Resolution
+//!
+//! Named references (`$ref:a`, `depends_on`, `references`, `ref`) always
+//! resolve to top-level keys of the AST, which are the only named nodes.
+//! Nested keys never create nodes, even when they collide with a top-level
+//! name, and a reference found anywhere inside a top-level value is an
+//! edge from that top-level node:
+//!
+//! ```text
+//! {"a": {"value": "$ref:b"}, "b": {"a": {"value": 1}, "next": "$ref:a"}}
+//!     nodes: a, b    edges: a -> b, b -> a (the top-level a)
+//! {"b": {"a": {"value": 1}}, "c": {"value": "$ref:a"}}
+//!     nodes: b, c    no edges (no top-level a)
+//! ```
+//!
+//! A reference to a name with no top-level key is ignored. This rule is
+//! consensus-relevant. Targets starting with `/` are not names but JSON
+//! Pointers; see "Pointer References".
+//!
+//! # Top-Level Arrays and Scalars
+//!
+//! A top-level array is a list of rule sets: the keys of every object
+//! element (recursing into nested arrays) are named nodes in one shared
+//! namespace, exactly as if the objects had been merged. Rules split across
+//! elements can therefore still form a cycle:
+//!
+//! ```text
+//! [{"a": {"value": "$ref:b"}}, {"b": {"value": "$ref:a"}}]
+//!     nodes: a, b    edges: a -> b, b -> a
+//! ```
+//!
+//! A name defined by more than one element is a single node carrying the
+//! edges of every definition, so splitting a rule in two can only add
+//! edges, never hide one. Elements are also indexed nodes, so `$ref:[i]`
+//! references between them work as described under "Indexed Nodes".
+//!
+//! A scalar AST (string, number, boolean or `null`) is valid but has no
+//! nodes, so it never contains a cycle; a top-level `"$ref:a"` string
+//! references nothing.
+//!
+//! # Reference Strings
+//!
+//! A string value is a reference if, after trimming whitespace from both
+//! ends, it starts with `$ref`, then optional whitespace, then `:`. The
+//! target is the rest of the string with surrounding whitespace trimmed:
+//!
+//! ```text
+//! "$ref:a"   "$ref: a"   " $ref:a "   "$ref : a"     all target "a"
+//! "$ref: a b"                                       targets "a b"
+//! "$Ref:a"   "$ refs:a"  "$refs:a"                  not references
+//! ```
+//!
+//! Whitespace is Unicode `White_Space` (Rust's `char::is_whitespace`).
+//! `$ref` is case-sensitive and contiguous. This rule is
+//! consensus-relevant: every oracle must resolve the same edges.
+//!
+//! # Indexed Nodes
+//!
+//! Array elements may reference their siblings by index, either as a
+//! `"$ref:[i]"` string or a `{"$ref": "[i]"}` object. Each array element is
+//! named by its path from the root: top-level keys by name, object fields
+//! joined with `.`, and array elements suffixed with `[i]`:
+//!
+//! ```text
+//! [{"$ref": "[1]"}, {"$ref": "[0]"}]          nodes: [0], [1]
+//! {"plan": {"steps": ["$ref:[1]", "done"]}}    nodes: plan.steps[0], plan.steps[1]
+//! [[1, "$ref:[0]"]]                            nodes: [0], [0][0], [0][1]
+//! ```
+//!
+//! An index reference resolves against the innermost array containing it
+//! and adds an edge from the referencing element to the referenced one.
+//! References past the end of the array are ignored. Indexed nodes are kept
+//! apart from top-level named nodes, so a key literally named `"[0]"` never
+//! aliases an array element.
+//!
+//! # Pointer References
+//!
+//! A reference target starting with `/` (in any reference form, e.g.
+//! `"$ref:/rules/0/condition"`) is an RFC 6901 JSON Pointer from the AST
+//! root, so dependencies can target nested nodes. Every pointer that
+//! resolves is a node of its own, named by the pointer; pointers that do
+//! not resolve are ignored, like unknown names. Pointer nodes are kept
+//! apart from named nodes: `/a` and `a` are different nodes even when they
+//! address the same value.
+//!
+//! Evaluating a pointed-to node means evaluating everything inside it, so
+//! each reference inside its subtree is an edge from the pointer node, in
+//! addition to the usual edge from the enclosing top-level node:
+//!
+//! ```text
+//! {"rules": [{"if": "$ref:/rules/1"}, {"then": "$ref:/rules/0/if"}]}
+//!     nodes: rules, /rules/0/if, /rules/1
+//!     edges: rules -> /rules/0/if, rules -> /rules/1,
+//!            /rules/0/if -> /rules/1, /rules/1 -> /rules/0/if
+//! ```
+//!
+//! A pointer that resolves to a value containing the reference itself (or
+//! to the reference) is therefore a self-edge, and so a cycle:
+//! `{"a": {"b": "$ref:/a"}}` depends on itself. Pointing into one's own
+//! subtree is not: a node may depend on its own children.
+//!
+//! # Size Limits
+//!
+//! Graph extraction is bounded by `CycleOptions`: at most `max_nodes`
+//! nodes, `max_edges` edges and `max_depth` levels of AST nesting
+//! (defaults `config::MAX_GRAPH_NODES`, `config::MAX_GRAPH_EDGES` and
+//! `config::MAX_AST_DEPTH`). Crossing any of them stops extraction with
+//! `GraphTooLarge`, which the pipeline counts as a cycle, so an oversized
+//! AST fails deterministically instead of running unbounded. The limits
+//! are consensus-relevant.
+//!
+//! # Deterministic Construction
+//!
+//! Graph construction never depends on hash or source key order: named
+//! nodes are added in sorted order, each node's edges are added sorted by
+//! target, pointer nodes in sorted pointer order with their edges sorted,
+//! and indexed nodes in sorted reference order. `find_cycles_detail`
+//! output is sorted as well, so it is identical across runs, platforms and
+//! serde_json feature sets (e.g. `preserve_order`).
 
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use thiserror::Error;
 
+use super::canonicalize::{parse_ast, CanonicalizeError};
+use crate::types::config::{MAX_AST_DEPTH, MAX_GRAPH_EDGES, MAX_GRAPH_NODES};
+
+/// The kind of dependency an edge represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EdgeKind {
+    /// A data reference between variables
+    Reference,
+    /// A template/macro expansion
+    Expansion,
+}
+
+/// Options controlling dependency graph extraction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleOptions {
+    /// Object key whose string value names a template to expand
+    /// (e.g. `"$expand"`); `None` disables expansion edges
+    pub expand_key: Option<String>,
+    /// Most edges the graph may have; more is `GraphTooLarge`
+    pub max_edges: usize,
+    /// Most nodes the graph may have; more is `GraphTooLarge`
+    pub max_nodes: usize,
+    /// Deepest nesting the AST may have; deeper is `GraphTooLarge`
+    pub max_depth: usize,
+}
+
+impl Default for CycleOptions {
+    fn default() -> Self {
+        Self {
+            expand_key: None,
+            max_edges: MAX_GRAPH_EDGES,
+            max_nodes: MAX_GRAPH_NODES,
+            max_depth: MAX_AST_DEPTH,
+        }
+    }
+}
+
+impl CycleOptions {
+    /// Options modeling `$expand` template expansion
+    pub fn with_expansion() -> Self {
+        Self {
+            expand_key: Some("$expand".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
 /// Errors that can occur during cycle detection
 #[derive(Debug, Error)]
 pub enum CycleDetectionError {
@@ -19,6 +197,8 @@ pub enum CycleDetectionError {
     JsonParseError(#[from] serde_json::Error),
     #[error("Invalid AST structure")]
     InvalidAstStructure,
+    #[error("Dependency graph too large: more than {limit} {kind}")]
+    GraphTooLarge { kind: &'static str, limit: usize },
 }
 
 /// Detect cycles in proposal logic by analyzing the AST
@@ -30,7 +210,8 @@ pub enum CycleDetectionError {
 ///
 /// # AST Format
 ///
-/// The AST should be a JSON object where:
+/// The AST should be a JSON object (or an array of them; see "Top-Level
+/// Arrays and Scalars") where:
 /// - Keys are variable/state names
 /// - Values can reference other variables via `$ref` or `depends_on`
 ///
@@ -49,7 +230,39 @@ pub enum CycleDetectionError {
 /// ```
 pub fn detect_cycles(ast_json: &str) -> Result<bool, CycleDetectionError> {
     let ast: Value = serde_json::from_str(ast_json)?;
-    let graph = extract_dependency_graph(&ast)?;
+    detect_cycles_value(&ast)
+}
+
+/// Detect cycles in an already-parsed AST
+pub fn detect_cycles_value(ast: &Value) -> Result<bool, CycleDetectionError> {
+    detect_cycles_value_with(ast, &CycleOptions::default())
+}
+
+/// Detect cycles with explicit graph extraction options
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{detect_cycles_with, CycleOptions};
+///
+/// let ast = r#"{"template_a": {"body": {"$expand": "template_a"}}}"#;
+/// assert!(!detect_cycles_with(ast, &CycleOptions::default()).unwrap());
+/// assert!(detect_cycles_with(ast, &CycleOptions::with_expansion()).unwrap());
+/// ```
+pub fn detect_cycles_with(
+    ast_json: &str,
+    options: &CycleOptions,
+) -> Result<bool, CycleDetectionError> {
+    let ast: Value = serde_json::from_str(ast_json)?;
+    detect_cycles_value_with(&ast, options)
+}
+
+/// Detect cycles in an already-parsed AST with explicit options
+pub fn detect_cycles_value_with(
+    ast: &Value,
+    options: &CycleOptions,
+) -> Result<bool, CycleDetectionError> {
+    let graph = extract_dependency_graph(ast, options)?;
 
     // Run Tarjan's SCC algorithm
     let sccs = tarjan_scc(&graph);
@@ -73,52 +286,332 @@ pub fn detect_cycles(ast_json: &str) -> Result<bool, CycleDetectionError> {
     Ok(false)
 }
 
+/// Node and edge counts of an AST's dependency graph, with default options
+pub(crate) fn dependency_graph_size(ast: &Value) -> Result<(usize, usize), CycleDetectionError> {
+    let graph = extract_dependency_graph(ast, &CycleOptions::default())?;
+    Ok((graph.node_count(), graph.edge_count()))
+}
+
 /// Extract a directed dependency graph from an AST
 ///
 /// Nodes are variable/state names.
 /// Edges represent dependencies (A -> B means A depends on B).
-fn extract_dependency_graph(ast: &Value) -> Result<DiGraph<String, ()>, CycleDetectionError> {
+///
+/// The AST's depth is checked before anything recurses into it, and the
+/// node and edge counts on every insertion, so an oversized AST bails out
+/// as soon as it crosses a `CycleOptions` cap, before the rest is built.
+fn extract_dependency_graph(
+    ast: &Value,
+    options: &CycleOptions,
+) -> Result<DiGraph<String, EdgeKind>, CycleDetectionError> {
+    check_depth(ast, options.max_depth)?;
     let mut graph = DiGraph::new();
-    let mut node_indices: HashMap<String, NodeIndex> = HashMap::new();
+    let mut node_indices: BTreeMap<String, NodeIndex> = BTreeMap::new();
 
     // First pass: create nodes for all top-level keys
-    if let Value::Object(map) = ast {
-        for key in map.keys() {
-            let idx = graph.add_node(key.clone());
-            node_indices.insert(key.clone(), idx);
+    let definitions = named_definitions(ast);
+    for key in definitions.keys() {
+        let idx = add_node(&mut graph, key, options)?;
+        node_indices.insert(key.clone(), idx);
+    }
+
+    // Pointer nodes, and every reference inside a pointed-to subtree
+    let mut located = Vec::new();
+    collect_located_dependencies(ast, String::new(), options, &mut located);
+    let pointers: BTreeSet<&String> = located
+        .iter()
+        .map(|(_, target, _)| target)
+        .filter(|target| is_pointer(target) && ast.pointer(target).is_some())
+        .collect();
+    let pointer_indices = pointers
+        .into_iter()
+        .map(|pointer| Ok((pointer.clone(), add_node(&mut graph, pointer, options)?)))
+        .collect::<Result<BTreeMap<String, NodeIndex>, CycleDetectionError>>()?;
+    let resolve = |dep: &str| {
+        if is_pointer(dep) {
+            pointer_indices.get(dep).copied()
+        } else {
+            node_indices.get(dep).copied()
         }
+    };
 
-        // Second pass: add edges for dependencies
-        for (key, value) in map.iter() {
-            let from_idx = node_indices[key];
-            let deps = extract_dependencies(value);
+    // Second pass: add edges for dependencies, in sorted key order
+    for (key, &from_idx) in &node_indices {
+        let mut deps: Vec<_> = definitions[key]
+            .iter()
+            .flat_map(|value| extract_dependencies(value, options))
+            .collect();
+        deps.sort();
 
-            for dep in deps {
-                // Only add edge if the dependency exists as a node
-                if let Some(&to_idx) = node_indices.get(&dep) {
-                    graph.add_edge(from_idx, to_idx, ());
-                }
+        for (dep, kind) in deps {
+            // Only add edge if the dependency exists as a node
+            if let Some(to_idx) = resolve(&dep) {
+                add_edge(&mut graph, from_idx, to_idx, kind, options)?;
             }
         }
     }
 
+    // Edges from each pointer node to what its subtree references
+    let mut pointer_edges = Vec::new();
+    for (location, target, kind) in &located {
+        let Some(to_idx) = resolve(target) else {
+            continue;
+        };
+        // The location itself and each of its ancestors
+        let enclosing = location
+            .match_indices('/')
+            .map(|(i, _)| &location[..i])
+            .chain([location.as_str()]);
+        for pointer in enclosing {
+            if let Some(&from_idx) = pointer_indices.get(pointer) {
+                pointer_edges.push((pointer, to_idx, *kind, from_idx));
+            }
+        }
+    }
+    pointer_edges.sort();
+    for (_, to_idx, kind, from_idx) in pointer_edges {
+        add_edge(&mut graph, from_idx, to_idx, kind, options)?;
+    }
+
+    // Index references between array elements
+    let mut elements = BTreeSet::new();
+    let mut index_refs = Vec::new();
+    collect_index_refs(ast, "", None, &mut elements, &mut index_refs);
+    index_refs.sort();
+
+    let mut element_indices: BTreeMap<String, NodeIndex> = BTreeMap::new();
+    for (from, to) in index_refs {
+        if !elements.contains(&to) {
+            continue;
+        }
+        let mut element = |name: String| match element_indices.get(&name) {
+            Some(&idx) => Ok(idx),
+            None => {
+                let idx = add_node(&mut graph, &name, options)?;
+                element_indices.insert(name, idx);
+                Ok::<_, CycleDetectionError>(idx)
+            }
+        };
+        let from_idx = element(from)?;
+        let to_idx = element(to)?;
+        add_edge(&mut graph, from_idx, to_idx, EdgeKind::Reference, options)?;
+    }
+
     Ok(graph)
 }
 
+/// Add a node, failing if the graph already has `CycleOptions::max_nodes`
+fn add_node(
+    graph: &mut DiGraph<String, EdgeKind>,
+    name: &str,
+    options: &CycleOptions,
+) -> Result<NodeIndex, CycleDetectionError> {
+    if graph.node_count() >= options.max_nodes {
+        return Err(CycleDetectionError::GraphTooLarge {
+            kind: "nodes",
+            limit: options.max_nodes,
+        });
+    }
+    Ok(graph.add_node(name.to_string()))
+}
+
+/// Add an edge, failing if the graph already has `CycleOptions::max_edges`
+fn add_edge(
+    graph: &mut DiGraph<String, EdgeKind>,
+    from: NodeIndex,
+    to: NodeIndex,
+    kind: EdgeKind,
+    options: &CycleOptions,
+) -> Result<(), CycleDetectionError> {
+    if graph.edge_count() >= options.max_edges {
+        return Err(CycleDetectionError::GraphTooLarge {
+            kind: "edges",
+            limit: options.max_edges,
+        });
+    }
+    graph.add_edge(from, to, kind);
+    Ok(())
+}
+
+/// Fail if any value in `ast` is nested deeper than `max_depth`
+///
+/// The root is at depth 0 and its direct children at depth 1, as in
+/// `ast_metrics`. Iterative, so even a pathologically deep `Value` cannot
+/// overflow the stack here.
+fn check_depth(ast: &Value, max_depth: usize) -> Result<(), CycleDetectionError> {
+    let mut stack = vec![(ast, 0)];
+    while let Some((value, depth)) = stack.pop() {
+        if depth > max_depth {
+            return Err(CycleDetectionError::GraphTooLarge {
+                kind: "levels of nesting",
+                limit: max_depth,
+            });
+        }
+        match value {
+            Value::Object(map) => stack.extend(map.values().map(|v| (v, depth + 1))),
+            Value::Array(arr) => stack.extend(arr.iter().map(|v| (v, depth + 1))),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Names that a `$ref:name` (or `depends_on`/`references`/`ref`) can target
+///
+/// These are the top-level keys of an object AST, or of the object
+/// elements of a top-level array, in sorted order; a scalar AST has none.
+/// Nested named references are not supported, and array elements are
+/// addressed by relative `[i]` index instead.
+fn named_nodes(ast: &Value) -> Vec<String> {
+    named_definitions(ast).into_keys().collect()
+}
+
+/// Named nodes with every value defining them, in document order
+fn named_definitions(ast: &Value) -> BTreeMap<String, Vec<&Value>> {
+    fn collect<'a>(value: &'a Value, definitions: &mut BTreeMap<String, Vec<&'a Value>>) {
+        match value {
+            Value::Object(map) => {
+                for (key, v) in map {
+                    definitions.entry(key.clone()).or_default().push(v);
+                }
+            }
+            Value::Array(arr) => {
+                for item in arr {
+                    collect(item, definitions);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut definitions = BTreeMap::new();
+    collect(ast, &mut definitions);
+    definitions
+}
+
+/// List the node names a reference in this AST could legally target
+///
+/// Returned in sorted order, for editor autocomplete and reference
+/// validation. These are exactly the named nodes of the dependency graph.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::referenceable_nodes;
+///
+/// let ast = r#"{"total": {"value": "$ref:fee"}, "fee": {"value": 5}}"#;
+/// assert_eq!(referenceable_nodes(ast).unwrap(), vec!["fee", "total"]);
+/// ```
+pub fn referenceable_nodes(ast_json: &str) -> Result<Vec<String>, CanonicalizeError> {
+    Ok(named_nodes(&parse_ast(ast_json)?))
+}
+
+/// Collect `(from, to)` index references between array elements
+///
+/// `element` is the innermost enclosing array element, as
+/// `(array path, element name)`. Every element name seen is recorded in
+/// `elements` so out-of-range references can be dropped.
+fn collect_index_refs(
+    value: &Value,
+    path: &str,
+    element: Option<(&str, &str)>,
+    elements: &mut BTreeSet<String>,
+    refs: &mut Vec<(String, String)>,
+) {
+    let push_ref = |target: &str, refs: &mut Vec<(String, String)>| {
+        if let (Some(index), Some((array_path, name))) = (parse_index_ref(target), element) {
+            refs.push((name.to_string(), format!("{}[{}]", array_path, index)));
+        }
+    };
+
+    match value {
+        Value::String(s) => {
+            if let Some(target) = ref_target(s) {
+                push_ref(target, refs);
+            }
+        }
+        Value::Object(map) => {
+            if let Some(Value::String(target)) = map.get("$ref") {
+                push_ref(target, refs);
+            }
+            for (key, v) in map {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                collect_index_refs(v, &child, element, elements, refs);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, item) in arr.iter().enumerate() {
+                let name = format!("{}[{}]", path, i);
+                collect_index_refs(item, &name, Some((path, &name)), elements, refs);
+                elements.insert(name);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Target of a `$ref:` reference string, or `None` if `s` is not one
+///
+/// See the module docs for the whitespace rule.
+pub(crate) fn ref_target(s: &str) -> Option<&str> {
+    let rest = s
+        .trim()
+        .strip_prefix("$ref")?
+        .trim_start()
+        .strip_prefix(':')?;
+    Some(rest.trim())
+}
+
+/// Parse an index reference target of the form `[i]`
+fn parse_index_ref(target: &str) -> Option<usize> {
+    let digits = target.strip_prefix('[')?.strip_suffix(']')?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
 /// Extract variable references from a JSON value
 ///
 /// Looks for:
 /// - `$ref:varname` strings
 /// - `depends_on: [...]` arrays
 /// - `references: varname` fields
-fn extract_dependencies(value: &Value) -> Vec<String> {
+/// - `<expand_key>: template` fields, when expansion is enabled
+fn extract_dependencies(value: &Value, options: &CycleOptions) -> Vec<(String, EdgeKind)> {
+    let mut deps = direct_dependencies(value, options);
+
+    match value {
+        Value::Object(map) => {
+            // Recursively check all values
+            for v in map.values() {
+                deps.extend(extract_dependencies(v, options));
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                deps.extend(extract_dependencies(item, options));
+            }
+        }
+        _ => {}
+    }
+
+    deps
+}
+
+/// Dependencies `value` itself declares, not counting its children
+fn direct_dependencies(value: &Value, options: &CycleOptions) -> Vec<(String, EdgeKind)> {
     let mut deps = Vec::new();
 
     match value {
         Value::String(s) => {
             // Check for $ref:varname pattern
-            if let Some(varname) = s.strip_prefix("$ref:") {
-                deps.push(varname.to_string());
+            if let Some(varname) = ref_target(s) {
+                deps.push((varname.to_string(), EdgeKind::Reference));
             }
         }
         Value::Object(map) => {
@@ -126,63 +619,172 @@ fn extract_dependencies(value: &Value) -> Vec<String> {
             if let Some(Value::Array(arr)) = map.get("depends_on") {
                 for item in arr {
                     if let Value::String(s) = item {
-                        deps.push(s.clone());
+                        deps.push((s.clone(), EdgeKind::Reference));
                     }
                 }
             }
             if let Some(Value::String(s)) = map.get("references") {
-                deps.push(s.clone());
+                deps.push((s.clone(), EdgeKind::Reference));
             }
             if let Some(Value::String(s)) = map.get("ref") {
-                deps.push(s.clone());
+                deps.push((s.clone(), EdgeKind::Reference));
             }
+            if let Some(key) = &options.expand_key {
+                if let Some(Value::String(s)) = map.get(key) {
+                    deps.push((s.clone(), EdgeKind::Expansion));
+                }
+            }
+        }
+        _ => {}
+    }
 
-            // Recursively check all values
-            for v in map.values() {
-                deps.extend(extract_dependencies(v));
+    deps
+}
+
+/// Collect `(location, target, kind)` for every dependency in `value`
+///
+/// `location` is the JSON Pointer of the value declaring the dependency.
+fn collect_located_dependencies(
+    value: &Value,
+    location: String,
+    options: &CycleOptions,
+    deps: &mut Vec<(String, String, EdgeKind)>,
+) {
+    for (target, kind) in direct_dependencies(value, options) {
+        deps.push((location.clone(), target, kind));
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                let token = key.replace('~', "~0").replace('/', "~1");
+                collect_located_dependencies(v, format!("{}/{}", location, token), options, deps);
             }
         }
         Value::Array(arr) => {
-            for item in arr {
-                deps.extend(extract_dependencies(item));
+            for (i, item) in arr.iter().enumerate() {
+                collect_located_dependencies(item, format!("{}/{}", location, i), options, deps);
             }
         }
         _ => {}
     }
+}
 
-    deps
+/// Whether a reference target is a JSON Pointer rather than a name
+fn is_pointer(target: &str) -> bool {
+    target.starts_with('/')
 }
 
 /// Get detailed information about cycles found in the AST
+///
+/// Each cycle lists its nodes in sorted order, and cycles are sorted, so
+/// the output does not depend on key order in the source.
 pub fn find_cycles_detail(ast_json: &str) -> Result<Vec<Vec<String>>, CycleDetectionError> {
     let ast: Value = serde_json::from_str(ast_json)?;
-    let graph = extract_dependency_graph(&ast)?;
-    let sccs = tarjan_scc(&graph);
+    let cycles = strongly_connected_components(&ast, &CycleOptions::default())?
+        .into_iter()
+        .filter(|(_, cyclic)| *cyclic)
+        .map(|(nodes, _)| nodes)
+        .collect();
+    Ok(cycles)
+}
 
-    let mut cycles = Vec::new();
+/// Node names along the first dependency cycle, in dependency order
+///
+/// The cycle is taken from the first cyclic SCC in `find_cycles_detail`
+/// order: the path starts at that SCC's smallest node name and follows the
+/// shortest loop back to it, preferring smaller names on ties, so each
+/// node depends on the next and the last depends on the first. A self-edge
+/// is a one-node path. `None` if the graph is acyclic.
+pub(crate) fn first_cycle_path(
+    ast: &Value,
+    options: &CycleOptions,
+) -> Result<Option<Vec<String>>, CycleDetectionError> {
+    let graph = extract_dependency_graph(ast, options)?;
+    let by_name = |idx: &NodeIndex| (graph[*idx].clone(), *idx);
 
-    for scc in sccs {
-        if scc.len() > 1 {
-            // Multi-node SCC
-            let cycle: Vec<String> = scc.iter()
-                .map(|&idx| graph[idx].clone())
-                .collect();
-            cycles.push(cycle);
-        } else if scc.len() == 1 {
-            let node = scc[0];
-            if graph.contains_edge(node, node) {
-                // Self-reference
-                cycles.push(vec![graph[node].clone()]);
+    let first = tarjan_scc(&graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1 || graph.contains_edge(scc[0], scc[0]))
+        .map(|mut scc| {
+            scc.sort_by_key(by_name);
+            scc
+        })
+        .min_by_key(|scc| {
+            scc.iter()
+                .map(|idx| graph[*idx].clone())
+                .collect::<Vec<_>>()
+        });
+    let Some(scc) = first else {
+        return Ok(None);
+    };
+    let start = scc[0];
+    if graph.contains_edge(start, start) {
+        return Ok(Some(vec![graph[start].clone()]));
+    }
+
+    // Breadth-first within the SCC, which always leads back to the start
+    let members: BTreeSet<NodeIndex> = scc.into_iter().collect();
+    let mut parents: BTreeMap<NodeIndex, NodeIndex> = BTreeMap::new();
+    let mut queue = std::collections::VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let mut next: Vec<NodeIndex> = graph
+            .neighbors(node)
+            .filter(|n| members.contains(n))
+            .collect();
+        next.sort_by_key(by_name);
+        next.dedup();
+        for neighbor in next {
+            if neighbor == start {
+                let mut path = vec![graph[node].clone()];
+                let mut current = node;
+                while let Some(&parent) = parents.get(&current) {
+                    path.push(graph[parent].clone());
+                    current = parent;
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+            if let std::collections::btree_map::Entry::Vacant(entry) = parents.entry(neighbor) {
+                entry.insert(node);
+                queue.push_back(neighbor);
             }
         }
     }
+    unreachable!(
+        "a strongly connected component with more than one node has a cycle through each node"
+    )
+}
 
-    Ok(cycles)
+/// Every SCC of the dependency graph, with whether it forms a cycle
+///
+/// An SCC is cyclic if it has more than one node or its single node has a
+/// self-edge. Nodes within an SCC and the SCCs themselves are sorted, so
+/// the output does not depend on key order in the source.
+pub(crate) fn strongly_connected_components(
+    ast: &Value,
+    options: &CycleOptions,
+) -> Result<Vec<(Vec<String>, bool)>, CycleDetectionError> {
+    let graph = extract_dependency_graph(ast, options)?;
+
+    let mut sccs: Vec<(Vec<String>, bool)> = tarjan_scc(&graph)
+        .into_iter()
+        .map(|scc| {
+            let cyclic = scc.len() > 1 || graph.contains_edge(scc[0], scc[0]);
+            let mut nodes: Vec<String> = scc.iter().map(|&idx| graph[idx].clone()).collect();
+            nodes.sort();
+            (nodes, cyclic)
+        })
+        .collect();
+    sccs.sort();
+
+    Ok(sccs)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
 
     #[test]
     fn test_no_cycles() {
@@ -264,6 +866,291 @@ mod tests {
         assert_eq!(cycles.len(), 2);
     }
 
+    /// Build an AST with `full` nodes depending on all `width` nodes, plus
+    /// one extra node carrying `extra` edges
+    fn dense_ast(width: usize, full: usize, extra: usize) -> String {
+        let names: Vec<String> = (0..width).map(|i| format!("\"n{}\"", i)).collect();
+        let mut ast: HashMap<String, Value> = HashMap::new();
+        for i in 0..width {
+            let deps = if i < full {
+                names.join(",")
+            } else {
+                String::new()
+            };
+            ast.insert(
+                format!("n{}", i),
+                serde_json::from_str(&format!(r#"{{"depends_on": [{}]}}"#, deps)).unwrap(),
+            );
+        }
+        let extra_deps = names[..extra].join(",");
+        ast.insert(
+            "extra".to_string(),
+            serde_json::from_str(&format!(r#"{{"depends_on": [{}]}}"#, extra_deps)).unwrap(),
+        );
+        serde_json::to_string(&ast).unwrap()
+    }
+
+    #[test]
+    fn test_parse_error_keeps_position_and_source() {
+        use std::error::Error as _;
+
+        let err = detect_cycles("{\"a\": {\"value\": }}").unwrap_err();
+        assert!(err.to_string().contains("line 1 column 17"), "{}", err);
+        assert!(err.source().is_some_and(|e| e.is::<serde_json::Error>()));
+    }
+
+    #[test]
+    fn test_edge_limit_boundary() {
+        // Exactly MAX_GRAPH_EDGES edges is allowed
+        let width = 1000;
+        let full = MAX_GRAPH_EDGES / width;
+        assert!(detect_cycles(&dense_ast(width, full, 0)).is_ok());
+
+        // One edge over fails early
+        let err = detect_cycles(&dense_ast(width, full, 1)).unwrap_err();
+        assert!(matches!(
+            err,
+            CycleDetectionError::GraphTooLarge {
+                kind: "edges",
+                limit: MAX_GRAPH_EDGES
+            }
+        ));
+    }
+
+    #[test]
+    fn test_node_limit_boundary() {
+        let chain = |n: usize| {
+            let map: serde_json::Map<String, Value> = (0..n)
+                .map(|i| {
+                    (
+                        format!("n{}", i),
+                        json!({"value": format!("$ref:n{}", i + 1)}),
+                    )
+                })
+                .collect();
+            Value::Object(map)
+        };
+        let options = CycleOptions {
+            max_nodes: 100,
+            ..Default::default()
+        };
+
+        // Exactly max_nodes nodes is allowed
+        assert!(!detect_cycles_value_with(&chain(100), &options).unwrap());
+
+        let err = detect_cycles_value_with(&chain(101), &options).unwrap_err();
+        assert!(matches!(
+            err,
+            CycleDetectionError::GraphTooLarge {
+                kind: "nodes",
+                limit: 100
+            }
+        ));
+
+        // Pointer and indexed nodes count too
+        let ast = json!({"a": {"value": "$ref:/b/x"}, "b": {"x": ["$ref:[1]", 1]}});
+        assert_eq!(dependency_graph_size(&ast).unwrap().0, 5);
+        let options = CycleOptions {
+            max_nodes: 4,
+            ..Default::default()
+        };
+        assert!(detect_cycles_value_with(&ast, &options).is_err());
+    }
+
+    #[test]
+    fn test_depth_limit_boundary() {
+        let nested =
+            |depth: usize| (0..depth).fold(json!("$ref:a"), |inner, _| json!({"a": inner}));
+
+        // The innermost string sits at exactly max_depth
+        assert!(detect_cycles_value(&nested(MAX_AST_DEPTH)).unwrap());
+
+        let err = detect_cycles_value(&nested(MAX_AST_DEPTH + 1)).unwrap_err();
+        assert!(matches!(
+            err,
+            CycleDetectionError::GraphTooLarge {
+                kind: "levels of nesting",
+                limit: MAX_AST_DEPTH
+            }
+        ));
+
+        // No AST the parser accepts is too deep
+        let deepest = format!("{}1{}", "[".repeat(200), "]".repeat(200));
+        assert!(serde_json::from_str::<Value>(&deepest).is_err());
+        let parseable = (1..200)
+            .map(|depth| format!("{}1{}", "[".repeat(depth), "]".repeat(depth)))
+            .take_while(|ast| serde_json::from_str::<Value>(ast).is_ok())
+            .last()
+            .unwrap();
+        assert!(detect_cycles(&parseable).is_ok());
+    }
+
+    #[test]
+    fn test_self_expanding_template() {
+        let ast = r#"{
+            "template_a": {"body": [{"$expand": "template_b"}]},
+            "template_b": {"body": {"$expand": "template_a"}}
+        }"#;
+
+        // Only an expansion loop: invisible unless expansion edges are modeled
+        assert!(!detect_cycles(ast).unwrap());
+        assert!(detect_cycles_with(ast, &CycleOptions::with_expansion()).unwrap());
+    }
+
+    #[test]
+    fn test_finite_template_chain() {
+        let ast = r#"{
+            "template_a": {"$expand": "template_b"},
+            "template_b": {"$expand": "template_c"},
+            "template_c": {"value": 1}
+        }"#;
+
+        assert!(!detect_cycles_with(ast, &CycleOptions::with_expansion()).unwrap());
+    }
+
+    #[test]
+    fn test_custom_expand_key() {
+        let ast = r#"{"m": {"@macro": "m"}}"#;
+        let options = CycleOptions {
+            expand_key: Some("@macro".to_string()),
+            ..Default::default()
+        };
+
+        assert!(detect_cycles_with(ast, &options).unwrap());
+        assert!(!detect_cycles_with(ast, &CycleOptions::with_expansion()).unwrap());
+    }
+
+    #[test]
+    fn test_array_index_cycle() {
+        let ast = r#"[{"$ref": "[1]"}, {"$ref": "[0]"}]"#;
+        assert!(detect_cycles(ast).unwrap());
+
+        let cycles = find_cycles_detail(ast).unwrap();
+        assert_eq!(cycles.len(), 1);
+        let mut nodes = cycles[0].clone();
+        nodes.sort();
+        assert_eq!(nodes, vec!["[0]", "[1]"]);
+    }
+
+    #[test]
+    fn test_array_index_chain_acyclic() {
+        let ast = r#"["$ref:[1]", {"$ref": "[2]"}, {"value": 3}, "$ref:[7]"]"#;
+        assert!(!detect_cycles(ast).unwrap());
+    }
+
+    #[test]
+    fn test_nested_array_field_cycle() {
+        let ast = r#"{
+            "plan": {"steps": [{"next": "$ref:[1]"}, {"next": "$ref:[2]"}, {"next": "$ref:[0]"}]},
+            "other": {"steps": [{"next": "$ref:[1]"}, {"value": 1}]}
+        }"#;
+        assert!(detect_cycles(ast).unwrap());
+
+        let cycles = find_cycles_detail(ast).unwrap();
+        assert_eq!(cycles.len(), 1);
+        let mut nodes = cycles[0].clone();
+        nodes.sort();
+        assert_eq!(
+            nodes,
+            vec!["plan.steps[0]", "plan.steps[1]", "plan.steps[2]"]
+        );
+    }
+
+    #[test]
+    fn test_index_refs_resolve_to_innermost_array() {
+        // [0][1] refers to [0][0], not the top-level [0] that contains it
+        assert!(!detect_cycles(r#"[[1, "$ref:[0]"]]"#).unwrap());
+        assert!(detect_cycles(r#"[["$ref:[0]"]]"#).unwrap());
+    }
+
+    fn graph_node_names(ast_json: &str) -> Vec<String> {
+        let ast: Value = serde_json::from_str(ast_json).unwrap();
+        let graph = extract_dependency_graph(&ast, &CycleOptions::default()).unwrap();
+        let mut names: Vec<String> = graph.node_weights().cloned().collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_referenceable_nodes_flat() {
+        let ast = r#"{"c": {"value": "$ref:a"}, "a": {"value": 1}, "b": {"depends_on": ["a"]}}"#;
+        let names = referenceable_nodes(ast).unwrap();
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert_eq!(names, graph_node_names(ast));
+    }
+
+    #[test]
+    fn test_referenceable_nodes_nested() {
+        let ast = r#"{
+            "plan": {"steps": {"first": {"value": "$ref:budget"}}, "owner": "dao"},
+            "budget": {"limits": {"daily": 10}}
+        }"#;
+        let names = referenceable_nodes(ast).unwrap();
+        assert_eq!(names, vec!["budget", "plan"]);
+        assert_eq!(names, graph_node_names(ast));
+
+        assert!(referenceable_nodes("[1, 2]").unwrap().is_empty());
+        assert!(referenceable_nodes("{bad").is_err());
+    }
+
+    #[test]
+    fn test_ref_whitespace_variants() {
+        for variant in [
+            "$ref:a",
+            "$ref: a",
+            "$ref:a ",
+            " $ref:a",
+            "$ref : a",
+            " \t$ref :\n a  ",
+        ] {
+            assert_eq!(ref_target(variant), Some("a"), "{:?}", variant);
+
+            let ast = json!({"a": {"value": "$ref:b"}, "b": {"value": variant}});
+            assert!(detect_cycles_value(&ast).unwrap(), "{:?}", variant);
+            assert_eq!(
+                find_cycles_detail(&ast.to_string()).unwrap(),
+                vec![vec!["a", "b"]]
+            );
+        }
+
+        // Indexed references trim the same way
+        assert!(detect_cycles(r#"[" $ref: [1] ", "$ref :[0]"]"#).unwrap());
+
+        assert_eq!(ref_target("$ref: a b"), Some("a b"));
+        for not_ref in ["$Ref:a", "$ refs:a", "$refs:a", "$ref a", "ref:a"] {
+            assert_eq!(ref_target(not_ref), None, "{:?}", not_ref);
+        }
+    }
+
+    #[test]
+    fn test_nested_key_does_not_shadow_top_level() {
+        // `$ref:a` in b binds to the top-level a, not b's nested a
+        let ast = r#"{"a": {"value": "$ref:b"}, "b": {"a": {"value": 1}, "next": "$ref:a"}}"#;
+        assert!(detect_cycles(ast).unwrap());
+        assert_eq!(find_cycles_detail(ast).unwrap(), vec![vec!["a", "b"]]);
+
+        // A reference inside the nested a is an edge from its top-level node
+        let ast = r#"{"a": {"value": 1}, "b": {"a": {"value": "$ref:a"}}}"#;
+        assert!(!detect_cycles(ast).unwrap());
+        let ast = r#"{"a": {"value": 1}, "b": {"a": {"value": "$ref:b"}}}"#;
+        assert_eq!(find_cycles_detail(ast).unwrap(), vec![vec!["b"]]);
+    }
+
+    #[test]
+    fn test_nested_keys_create_no_nodes() {
+        let ast = r#"{"b": {"a": {"value": 1}}, "c": {"value": "$ref:a", "depends_on": ["a"]}}"#;
+        assert_eq!(referenceable_nodes(ast).unwrap(), vec!["b", "c"]);
+        assert!(!detect_cycles(ast).unwrap());
+
+        // Without a top-level a, a nested a cannot close a loop
+        let ast = r#"{"b": {"a": {"value": "$ref:c"}}, "c": {"value": "$ref:a"}}"#;
+        assert!(!detect_cycles(ast).unwrap());
+        assert_eq!(
+            dependency_graph_size(&serde_json::from_str(ast).unwrap()).unwrap(),
+            (2, 1)
+        );
+    }
+
     #[test]
     fn test_reference_to_nonexistent() {
         // Reference to non-existent variable should not cause issues
@@ -273,4 +1160,142 @@ mod tests {
 
         assert!(!detect_cycles(ast).unwrap());
     }
+
+    #[test]
+    fn test_cycle_detail_independent_of_key_order() {
+        let orders = [
+            r#"{"c": {"value": "$ref:a"}, "a": {"depends_on": ["b", "c"]}, "b": {"value": "$ref:c"}, "z": {"value": "$ref:z"}}"#,
+            r#"{"z": {"value": "$ref:z"}, "b": {"value": "$ref:c"}, "a": {"depends_on": ["c", "b"]}, "c": {"value": "$ref:a"}}"#,
+            r#"{"a": {"depends_on": ["b", "c"]}, "z": {"value": "$ref:z"}, "c": {"value": "$ref:a"}, "b": {"value": "$ref:c"}}"#,
+        ];
+
+        let expected = vec![
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec!["z".to_string()],
+        ];
+        for ast in orders {
+            assert_eq!(find_cycles_detail(ast).unwrap(), expected, "{}", ast);
+        }
+    }
+
+    #[test]
+    fn test_top_level_array_of_rules() {
+        // Rules in separate elements reference each other by name
+        let ast = r#"[{"a": {"value": "$ref:b"}}, {"b": {"depends_on": ["a"]}}]"#;
+        assert!(detect_cycles(ast).unwrap());
+        assert_eq!(
+            find_cycles_detail(ast).unwrap(),
+            vec![vec!["a".to_string(), "b".to_string()]]
+        );
+        assert_eq!(referenceable_nodes(ast).unwrap(), vec!["a", "b"]);
+
+        // ...or by element index
+        assert!(detect_cycles(r#"[{"rule": "$ref:[1]"}, {"rule": "$ref:[0]"}]"#).unwrap());
+
+        // Nested arrays of rule objects share the same namespace
+        assert!(detect_cycles(r#"[[{"a": "$ref:b"}], [[{"b": "$ref:a"}]]]"#).unwrap());
+
+        assert!(!detect_cycles(r#"[{"a": {"value": 1}}, {"b": {"value": "$ref:a"}}]"#).unwrap());
+    }
+
+    #[test]
+    fn test_split_definitions_merge_edges() {
+        // The second definition of a cannot hide the first one's edge
+        let ast =
+            r#"[{"a": {"value": "$ref:b"}}, {"a": {"value": 1}}, {"b": {"value": "$ref:a"}}]"#;
+        assert!(detect_cycles(ast).unwrap());
+        assert_eq!(
+            dependency_graph_size(&serde_json::from_str(ast).unwrap()).unwrap(),
+            (2, 2)
+        );
+    }
+
+    #[test]
+    fn test_scalar_ast_has_no_nodes() {
+        for ast in [r#""$ref:a""#, "42", "true", "null", r#"[1, "x", null]"#] {
+            assert!(!detect_cycles(ast).unwrap(), "{ast}");
+            assert!(referenceable_nodes(ast).unwrap().is_empty(), "{ast}");
+        }
+    }
+
+    #[test]
+    fn test_pointer_cycle_between_nested_rules() {
+        let ast = r#"{"rules": [{"if": "$ref:/rules/1"}, {"then": "$ref:/rules/0/if"}]}"#;
+        assert!(detect_cycles(ast).unwrap());
+        assert_eq!(
+            find_cycles_detail(ast).unwrap(),
+            vec![vec!["/rules/0/if", "/rules/1"]]
+        );
+
+        // Deeper, through depends_on, with escaped tokens
+        let ast = r#"{"a/b": {"when": {"depends_on": ["/c~0d/0"]}}, "c~d": [{"then": "$ref:/a~1b/when"}]}"#;
+        assert_eq!(
+            find_cycles_detail(ast).unwrap(),
+            vec![vec!["/a~1b/when", "/c~0d/0"]]
+        );
+
+        // Pointers and names close loops together
+        let ast = r#"{"a": {"value": "$ref:/b/x"}, "b": {"x": "$ref:a"}}"#;
+        assert_eq!(find_cycles_detail(ast).unwrap(), vec![vec!["/b/x", "a"]]);
+    }
+
+    #[test]
+    fn test_pointer_into_own_subtree_is_cycle() {
+        for ast in [
+            r#"{"a": {"b": "$ref:/a"}}"#,
+            r#"{"rules": [{"if": {"not": "$ref:/rules/0"}}]}"#,
+            r#"{"rules": [{"if": {"depends_on": ["/rules/0/if"]}}]}"#,
+            r#"{"rules": [{"if": "$ref:/rules/0/if"}]}"#,
+        ] {
+            assert!(detect_cycles(ast).unwrap(), "{}", ast);
+        }
+    }
+
+    #[test]
+    fn test_pointers_to_sibling_branches_acyclic() {
+        let ast = r#"{"rules": [
+            {"if": "$ref:/rules/1/cond"},
+            {"cond": {"value": 1}, "then": "$ref:/rules/0/if"}
+        ]}"#;
+        assert!(!detect_cycles(ast).unwrap());
+        let graph = dependency_graph_size(&serde_json::from_str(ast).unwrap()).unwrap();
+        assert_eq!(graph, (3, 3));
+
+        // Depending on one's own children is not a cycle
+        let ast = r#"{"a": {"x": "$ref:/a/y", "y": {"value": 1}}, "b": {"value": "$ref:/a"}}"#;
+        assert!(!detect_cycles(ast).unwrap());
+
+        // Unresolvable pointers are ignored, and never alias names
+        let ast = r#"{"/a": {"value": "$ref:/a"}, "b": {"value": "$ref:/rules/7"}}"#;
+        assert!(!detect_cycles(ast).unwrap());
+        assert_eq!(
+            dependency_graph_size(&serde_json::from_str(ast).unwrap()).unwrap(),
+            (2, 0)
+        );
+    }
+
+    #[test]
+    fn test_first_cycle_path_follows_edges() {
+        let path = |ast: &str| {
+            first_cycle_path(
+                &serde_json::from_str(ast).unwrap(),
+                &CycleOptions::default(),
+            )
+            .unwrap()
+        };
+
+        // Starts at the smallest name and follows the dependencies
+        let ast =
+            r#"{"c": {"value": "$ref:b"}, "a": {"value": "$ref:c"}, "b": {"value": "$ref:a"}}"#;
+        assert_eq!(path(ast).unwrap(), vec!["a", "c", "b"]);
+
+        // The shortest loop through the start, from the first cyclic SCC
+        let ast = r#"{"a": {"depends_on": ["d", "b"]}, "b": {"value": "$ref:c"}, "c": {"value": "$ref:a"}, "d": {"value": "$ref:a"}, "x": {"value": "$ref:x"}}"#;
+        assert_eq!(path(ast).unwrap(), vec!["a", "d"]);
+        assert_eq!(path(r#"{"x": {"value": "$ref:x"}}"#).unwrap(), vec!["x"]);
+        assert_eq!(
+            path(r#"{"a": {"value": "$ref:b"}, "b": {"value": 1}}"#),
+            None
+        );
+    }
 }
@@ -0,0 +1,128 @@
+//! Time-Bounded Verification
+//!
+//! Static limits (payload size, graph edges) bound the cost of every known
+//! input, but an oracle's report window is a hard deadline. Verification
+//! here runs on a worker thread and gives up once the budget is spent, so
+//! a pathological input that slips past the static guards costs at most
+//! `max` of report latency.
+//!
+//! A timed-out worker cannot be cancelled: it keeps running in the
+//! background until verification finishes and its result is discarded. A
+//! worker that panics is reported as an error rather than re-raised on
+//! the caller's thread.
+//!
+//! Requires the `threaded` feature.
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+use super::verify_proposal;
+use crate::types::{ChannelAVerdict, Proposal};
+
+/// Why time-bounded verification produced no verdict
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DeadlineError {
+    /// Verification did not finish within its time budget
+    #[error("Verification exceeded its {budget:?} budget")]
+    Timeout {
+        /// The budget that was exceeded
+        budget: Duration,
+    },
+    /// The verification worker panicked before producing a verdict
+    #[error("Verification worker panicked")]
+    WorkerFailed,
+}
+
+/// Verify a proposal, giving up after `max`
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::verify_with_deadline;
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+/// use std::time::Duration;
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let verdict = verify_with_deadline(&proposal, Duration::from_secs(5)).unwrap();
+/// assert!(verdict.pass);
+/// ```
+pub fn verify_with_deadline(
+    proposal: &Proposal,
+    max: Duration,
+) -> Result<ChannelAVerdict, DeadlineError> {
+    let proposal = proposal.clone();
+    run_with_deadline(move || verify_proposal(&proposal), max)
+}
+
+/// Run `stage` on a worker thread, waiting at most `max` for its result
+fn run_with_deadline<T, F>(stage: F, max: Duration) -> Result<T, DeadlineError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone if the deadline already passed
+        let _ = tx.send(stage());
+    });
+
+    match rx.recv_timeout(max) {
+        Ok(result) => Ok(result),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(DeadlineError::Timeout { budget: max }),
+        // The sender was dropped without sending, so `stage` unwound
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(DeadlineError::WorkerFailed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+
+    fn proposal() -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "transfer", "amount": 100}"#.to_string(),
+            "Transfer 100 tokens to the community fund".to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_fast_proposal_completes() {
+        let verdict = verify_with_deadline(&proposal(), Duration::from_secs(10)).unwrap();
+        assert_eq!(verdict, verify_proposal(&proposal()));
+    }
+
+    #[test]
+    fn test_slow_stage_times_out() {
+        let p = proposal();
+        let slow = move || {
+            thread::sleep(Duration::from_millis(500));
+            verify_proposal(&p)
+        };
+
+        let budget = Duration::from_millis(20);
+        assert_eq!(
+            run_with_deadline(slow, budget),
+            Err(DeadlineError::Timeout { budget })
+        );
+    }
+
+    #[test]
+    fn test_worker_panic_is_an_error() {
+        let failing = || -> ChannelAVerdict { panic!("stage failed") };
+        assert_eq!(
+            run_with_deadline(failing, Duration::from_secs(10)),
+            Err(DeadlineError::WorkerFailed)
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Declared Decidability Class
+//!
+//! A proposer may self-declare a decidability class in a top-level AST
+//! field (`"decidability_class": "II"` by default). The declaration is never
+//! trusted for routing; it is checked against Channel A's own structural
+//! findings and a conflict is reported as a `ClassMismatch` advisory.
+//!
+//! A paradox or dependency cycle makes a proposal undecidable by
+//! construction, which implies Class III. Any other declaration alongside
+//! such a finding is a mismatch. Missing or unparseable declarations are
+//! treated as undeclared, and the default routing applies.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::{ChannelAVerdict, DecidabilityClass};
+
+/// Default AST field holding the declared class
+pub const DECLARED_CLASS_KEY: &str = "decidability_class";
+
+/// A self-declared class that conflicts with detected findings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassMismatch {
+    /// Class the proposer declared
+    pub declared: DecidabilityClass,
+    /// Class implied by Channel A findings
+    pub detected: DecidabilityClass,
+}
+
+/// Read the declared class from a top-level AST field
+///
+/// Accepts the serialized class names `"I"`, `"II"` and `"III"`.
+pub fn declared_class(ast: &Value, key: &str) -> Option<DecidabilityClass> {
+    ast.get(key)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+/// Compare a declared class against a verdict's structural findings
+pub fn check_declared_class(
+    declared: DecidabilityClass,
+    verdict: &ChannelAVerdict,
+) -> Option<ClassMismatch> {
+    let detected = implied_class(verdict)?;
+    (declared != detected).then_some(ClassMismatch { declared, detected })
+}
+
+/// Class forced by the findings, if any
+fn implied_class(verdict: &ChannelAVerdict) -> Option<DecidabilityClass> {
+    (verdict.paradox_found || verdict.cycle_found).then_some(DecidabilityClass::III)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_declared_class_parsing() {
+        assert_eq!(
+            declared_class(&json!({"decidability_class": "I"}), DECLARED_CLASS_KEY),
+            Some(DecidabilityClass::I)
+        );
+        assert_eq!(
+            declared_class(&json!({"class": "III"}), "class"),
+            Some(DecidabilityClass::III)
+        );
+        assert_eq!(
+            declared_class(&json!({"action": "x"}), DECLARED_CLASS_KEY),
+            None
+        );
+        assert_eq!(
+            declared_class(&json!({"decidability_class": "IV"}), DECLARED_CLASS_KEY),
+            None
+        );
+        assert_eq!(
+            declared_class(&json!([{"decidability_class": "I"}]), DECLARED_CLASS_KEY),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_declared_class() {
+        let clean = ChannelAVerdict::pass(50);
+        let paradox = ChannelAVerdict::fail(50, true, false);
+
+        assert_eq!(check_declared_class(DecidabilityClass::II, &clean), None);
+        assert_eq!(check_declared_class(DecidabilityClass::III, &paradox), None);
+        assert_eq!(
+            check_declared_class(DecidabilityClass::II, &paradox),
+            Some(ClassMismatch {
+                declared: DecidabilityClass::II,
+                detected: DecidabilityClass::III,
+            })
+        );
+    }
+}
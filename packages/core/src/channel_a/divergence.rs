@@ -0,0 +1,218 @@
+//! Divergence Explanation
+//!
+//! When two oracles report different verdicts for what should be the same
+//! proposal, an adjudicator needs to know where the pipelines first parted:
+//! canonical bytes, complexity score, or detector findings. `explain_divergence`
+//! re-runs every stage on both inputs and reports each difference.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    canonicalize, compute_complexity, verify_proposal, CanonicalPayload, CanonicalizeError,
+};
+use crate::types::{ChannelAVerdict, Proposal};
+
+/// Section of the canonical payload a byte offset falls in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PayloadSection {
+    /// The canonical form version prefix
+    Version,
+    /// The canonical AST JSON
+    Ast,
+    /// The `.` separator between AST and text
+    Separator,
+    /// The normalized text
+    Text,
+}
+
+/// First point at which two canonical payloads differ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayloadDivergence {
+    /// Byte offset of the first differing byte (or the shorter length)
+    pub offset: usize,
+    /// Section of payload A the offset falls in
+    pub section: PayloadSection,
+    /// Length of payload A in bytes
+    pub len_a: usize,
+    /// Length of payload B in bytes
+    pub len_b: usize,
+}
+
+/// Stage-by-stage comparison of two proposals
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DivergenceReport {
+    /// Canonical hash of A (hex)
+    pub hash_a: String,
+    /// Canonical hash of B (hex)
+    pub hash_b: String,
+    /// Where the canonical payloads differ, if they do
+    pub payload: Option<PayloadDivergence>,
+    /// Complexity score of A
+    pub complexity_a: u64,
+    /// Complexity score of B
+    pub complexity_b: u64,
+    /// Full verdict for A
+    pub verdict_a: ChannelAVerdict,
+    /// Full verdict for B
+    pub verdict_b: ChannelAVerdict,
+}
+
+impl DivergenceReport {
+    /// Whether any stage differs
+    pub fn is_divergent(&self) -> bool {
+        self.payload.is_some() || self.verdict_a != self.verdict_b
+    }
+
+    /// Signed complexity difference (`b - a`)
+    pub fn complexity_delta(&self) -> i128 {
+        self.complexity_b as i128 - self.complexity_a as i128
+    }
+
+    /// Whether the paradox findings differ
+    pub fn paradox_differs(&self) -> bool {
+        self.verdict_a.paradox_found != self.verdict_b.paradox_found
+    }
+
+    /// Whether the cycle findings differ
+    pub fn cycle_differs(&self) -> bool {
+        self.verdict_a.cycle_found != self.verdict_b.cycle_found
+    }
+}
+
+/// Explain how two proposals diverge through the Channel A pipeline
+///
+/// Fails if either proposal cannot be canonicalized.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::explain_divergence;
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let a = Proposal::new("r1".into(), r#"{"a":1}"#.into(), "Fund  it".into(), GovernanceLayer::L2Operational);
+/// let b = Proposal::new("r1".into(), r#"{ "a": 1 }"#.into(), "fund it".into(), GovernanceLayer::L2Operational);
+///
+/// let report = explain_divergence(&a, &b).unwrap();
+/// assert!(!report.is_divergent());
+/// ```
+pub fn explain_divergence(
+    a: &Proposal,
+    b: &Proposal,
+) -> Result<DivergenceReport, CanonicalizeError> {
+    let payload_a = canonicalize(a)?;
+    let payload_b = canonicalize(b)?;
+
+    Ok(DivergenceReport {
+        hash_a: payload_a.hash_hex(),
+        hash_b: payload_b.hash_hex(),
+        payload: diff_payloads(&payload_a, &payload_b),
+        complexity_a: compute_complexity(&payload_a.bytes),
+        complexity_b: compute_complexity(&payload_b.bytes),
+        verdict_a: verify_proposal(a),
+        verdict_b: verify_proposal(b),
+    })
+}
+
+fn diff_payloads(a: &CanonicalPayload, b: &CanonicalPayload) -> Option<PayloadDivergence> {
+    if a.bytes == b.bytes {
+        return None;
+    }
+
+    let offset = a
+        .bytes
+        .iter()
+        .zip(&b.bytes)
+        .position(|(x, y)| x != y)
+        .unwrap_or_else(|| a.bytes.len().min(b.bytes.len()));
+
+    let section = if offset < a.ast_range.start {
+        PayloadSection::Version
+    } else if offset < a.ast_range.end {
+        PayloadSection::Ast
+    } else if offset < a.text_range.start {
+        PayloadSection::Separator
+    } else {
+        PayloadSection::Text
+    };
+
+    Some(PayloadDivergence {
+        offset,
+        section,
+        len_a: a.bytes.len(),
+        len_b: b.bytes.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_identical_inputs() {
+        let a = proposal(r#"{"action": "transfer"}"#, "Transfer 100 tokens");
+        let report = explain_divergence(&a, &a.clone()).unwrap();
+
+        assert!(!report.is_divergent());
+        assert_eq!(report.hash_a, report.hash_b);
+        assert_eq!(report.complexity_delta(), 0);
+    }
+
+    #[test]
+    fn test_whitespace_only_difference() {
+        let a = proposal(
+            r#"{"action":"transfer","amount":100}"#,
+            "Transfer 100 tokens",
+        );
+        let b = proposal(
+            "{\n  \"amount\": 100,\n  \"action\": \"transfer\"\n}",
+            "  Transfer\t100   tokens\n",
+        );
+        let report = explain_divergence(&a, &b).unwrap();
+
+        assert!(report.payload.is_none());
+        assert!(!report.is_divergent());
+    }
+
+    #[test]
+    fn test_different_proposal() {
+        let a = proposal(r#"{"action": "transfer"}"#, "Send 100 tokens");
+        let b = proposal(
+            r#"{"action": "transfer"}"#,
+            "This proposal passes iff it fails",
+        );
+        let report = explain_divergence(&a, &b).unwrap();
+
+        assert!(report.is_divergent());
+        assert_ne!(report.hash_a, report.hash_b);
+        let payload = report.payload.as_ref().unwrap();
+        assert_eq!(payload.section, PayloadSection::Text);
+        // One version byte, then the AST and separator
+        assert_eq!(payload.offset, 1 + r#"{"action":"transfer"}."#.len());
+        assert!(report.paradox_differs());
+        assert!(!report.cycle_differs());
+        assert_eq!(
+            report.complexity_delta(),
+            report.complexity_b as i128 - report.complexity_a as i128
+        );
+    }
+
+    #[test]
+    fn test_ast_difference_located() {
+        let a = proposal(r#"{"a": 1}"#, "Same text");
+        let b = proposal(r#"{"a": 2}"#, "Same text");
+        let payload = explain_divergence(&a, &b).unwrap().payload.unwrap();
+
+        assert_eq!(payload.section, PayloadSection::Ast);
+        assert_eq!(payload.offset, 1 + r#"{"a":"#.len());
+    }
+}
@@ -0,0 +1,123 @@
+//! Pass Explanations
+//!
+//! A failing verdict comes with reasons (see `record`); a passing one is
+//! otherwise silent. `explain_pass` gives authors positive confirmation of
+//! what was checked and by how much the proposal cleared each check:
+//! complexity headroom, the paradox patterns that ran without matching,
+//! and the size of the dependency graph found to be acyclic.
+//!
+//! Reports are advisory and never affect the verdict.
+
+use serde::{Deserialize, Serialize};
+
+use super::canonicalize::{normalize_text, parse_ast};
+use super::complexity::{complexity_headroom, ComplexityHeadroom};
+use super::cycles::dependency_graph_size;
+use super::paradox::{find_paradox_matches, get_paradox_pattern_ids, ParadoxMatch};
+use super::verify_proposal;
+use crate::types::{config, Proposal};
+
+/// Why a proposal passed Channel A
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PassReport {
+    /// Complexity score of the canonical payload
+    pub complexity_score: u64,
+    /// Complexity limit the score was checked against
+    pub max_complexity: u64,
+    /// Margin below the complexity limit
+    pub headroom: ComplexityHeadroom,
+    /// Number of built-in paradox patterns checked
+    pub paradox_patterns_checked: usize,
+    /// Paradox patterns that matched (always empty for a pass)
+    pub paradox_matches: Vec<ParadoxMatch>,
+    /// Nodes in the dependency graph
+    pub graph_nodes: usize,
+    /// Edges in the dependency graph, none of which form a cycle
+    pub graph_edges: usize,
+}
+
+/// Explain why a proposal passes Channel A
+///
+/// `None` if the proposal does not pass; use `verify_and_record` for the
+/// reasons a proposal failed.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::explain_pass;
+/// use constitution_dao_core::{GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"fee": {"value": 5}, "total": {"value": "$ref:fee"}}"#.to_string(),
+///     "Set the fee to 5".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let report = explain_pass(&proposal).unwrap();
+/// assert!(report.paradox_matches.is_empty());
+/// assert_eq!((report.graph_nodes, report.graph_edges), (2, 1));
+/// ```
+pub fn explain_pass(proposal: &Proposal) -> Option<PassReport> {
+    let verdict = verify_proposal(proposal);
+    if !verdict.pass {
+        return None;
+    }
+    let ast = parse_ast(&proposal.logic_ast).ok()?;
+    let (graph_nodes, graph_edges) = dependency_graph_size(&ast).ok()?;
+
+    Some(PassReport {
+        complexity_score: verdict.complexity_score,
+        max_complexity: config::MAX_COMPLEXITY,
+        headroom: complexity_headroom(verdict.complexity_score),
+        paradox_patterns_checked: get_paradox_pattern_ids().len(),
+        paradox_matches: find_paradox_matches(&normalize_text(&proposal.text)),
+        graph_nodes,
+        graph_edges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_pass_report() {
+        let p = proposal(
+            r#"{"fee": {"value": 5}, "cap": {"value": 100}, "total": {"depends_on": ["fee", "cap"]}}"#,
+            "Set the fee to 5 with a cap of 100",
+        );
+        let report = explain_pass(&p).unwrap();
+
+        let verdict = verify_proposal(&p);
+        assert_eq!(report.complexity_score, verdict.complexity_score);
+        assert_eq!(
+            report.headroom.headroom,
+            config::MAX_COMPLEXITY - verdict.complexity_score
+        );
+        assert_eq!(
+            report.paradox_patterns_checked,
+            get_paradox_pattern_ids().len()
+        );
+        assert!(report.paradox_matches.is_empty());
+        assert_eq!(report.graph_nodes, 3);
+        assert_eq!(report.graph_edges, 2);
+    }
+
+    #[test]
+    fn test_no_report_for_failures() {
+        assert!(explain_pass(&proposal("{}", "This statement is false")).is_none());
+        assert!(explain_pass(&proposal(r#"{"a": {"value": "$ref:a"}}"#, "Loop")).is_none());
+        assert!(explain_pass(&proposal("{not json", "Broken")).is_none());
+    }
+}
@@ -0,0 +1,293 @@
+//! Fraud Proof Verification
+//!
+//! `verify_fraud_proof` is the authoritative check for a challenge to a
+//! Channel A verdict. It trusts nothing but the witness: the canonical
+//! payload must hash to the challenged proposal ID, and the verdict is
+//! recomputed from that payload (see `verify_canonical_with`). The
+//! proof's own `actual_verdict` is the challenger's claim and is ignored.
+//!
+//! The payload does not record the governance layer or the rules it was
+//! verified under, so the proof names both. The verifier supplies the
+//! configuration; a proof made under different rules cannot be checked.
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::canonicalize::split_payload;
+use super::{verify_canonical_with, CanonicalizeError, VerificationConfig};
+use crate::types::{ChannelAVerdict, FraudProof};
+
+/// Why a fraud proof witness cannot be checked
+#[derive(Debug, Error)]
+pub enum WitnessError {
+    #[error("Canonical payload is not valid hex: {0}")]
+    InvalidHex(#[source] hex::FromHexError),
+    #[error("Canonical payload does not hash to the challenged proposal ID")]
+    HashMismatch,
+    #[error("Fraud proof was made under different verification rules")]
+    RulesMismatch,
+    #[error("Malformed canonical payload: {0}")]
+    InvalidPayload(#[from] CanonicalizeError),
+}
+
+/// Outcome of checking a fraud proof
+#[derive(Debug)]
+pub enum FraudProofResult {
+    /// The claimed verdict differs from the recomputed one; the oracle was wrong
+    ChallengeSucceeded {
+        /// The verdict recomputed from the witness
+        recomputed: ChannelAVerdict,
+    },
+    /// The claimed verdict matches the recomputed one; the challenge fails
+    ChallengeInvalid,
+    /// The witness cannot be checked, so neither side is at fault
+    MalformedWitness(WitnessError),
+}
+
+impl FraudProofResult {
+    /// Whether the challenged oracle should be slashed
+    pub fn is_successful(&self) -> bool {
+        matches!(self, FraudProofResult::ChallengeSucceeded { .. })
+    }
+}
+
+/// Check a fraud proof by recomputing the verdict from its witness
+///
+/// The whole verdict is compared, complexity score included: every field
+/// is deterministic, so any difference is a wrong verdict. `config` is the
+/// configuration the challenged oracle was bound to; its `rules_hash` must
+/// match the proof's.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{canonicalize, verify_fraud_proof, VerificationConfig};
+/// use constitution_dao_core::{verify_proposal, ChannelAVerdict, FraudProof, FraudProofWitness, GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer"}"#.to_string(),
+///     "This statement is false".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+/// let payload = canonicalize(&proposal).unwrap();
+/// let actual = verify_proposal(&proposal);
+/// let config = VerificationConfig::default();
+///
+/// // The oracle claimed a pass for a paradoxical proposal
+/// let proof = FraudProof {
+///     proposal_id: payload.hash,
+///     claimed_verdict: ChannelAVerdict::pass(actual.complexity_score),
+///     actual_verdict: actual,
+///     layer: proposal.layer,
+///     rules_hash: config.rules_hash(),
+///     witness: FraudProofWitness {
+///         canonical_payload: hex::encode(&payload.bytes),
+///         computation_trace: Vec::new(),
+///     },
+/// };
+/// assert!(verify_fraud_proof(&proof, &config).is_successful());
+/// ```
+pub fn verify_fraud_proof(proof: &FraudProof, config: &VerificationConfig) -> FraudProofResult {
+    if config.rules_hash() != proof.rules_hash {
+        return FraudProofResult::MalformedWitness(WitnessError::RulesMismatch);
+    }
+    let payload = match hex::decode(&proof.witness.canonical_payload) {
+        Ok(payload) => payload,
+        Err(e) => return FraudProofResult::MalformedWitness(WitnessError::InvalidHex(e)),
+    };
+    let hash: [u8; 32] = Sha256::digest(&payload).into();
+    if hash != proof.proposal_id {
+        return FraudProofResult::MalformedWitness(WitnessError::HashMismatch);
+    }
+    // `verify_canonical` hard-fails a malformed payload; here that voids the challenge
+    if let Err(e) = split_payload(&payload) {
+        return FraudProofResult::MalformedWitness(e.into());
+    }
+
+    let recomputed = verify_canonical_with(&payload, proof.layer, config).verdict;
+    if recomputed == proof.claimed_verdict {
+        FraudProofResult::ChallengeInvalid
+    } else {
+        FraudProofResult::ChallengeSucceeded { recomputed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel_a::{canonicalize, verify_proposal_with, ComplexityLimits};
+    use crate::types::{FraudProofWitness, GovernanceLayer, Proposal};
+    use crate::verify_proposal;
+
+    fn proof(ast: &str, text: &str, claimed: ChannelAVerdict) -> FraudProof {
+        proof_in(
+            ast,
+            text,
+            GovernanceLayer::L2Operational,
+            &VerificationConfig::default(),
+            claimed,
+        )
+    }
+
+    fn proof_in(
+        ast: &str,
+        text: &str,
+        layer: GovernanceLayer,
+        config: &VerificationConfig,
+        claimed: ChannelAVerdict,
+    ) -> FraudProof {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            layer,
+        );
+        let payload = canonicalize(&proposal).unwrap();
+        FraudProof {
+            proposal_id: payload.hash,
+            claimed_verdict: claimed,
+            actual_verdict: verify_proposal_with(&proposal, config).verdict,
+            layer,
+            rules_hash: config.rules_hash(),
+            witness: FraudProofWitness {
+                canonical_payload: hex::encode(&payload.bytes),
+                computation_trace: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_successful_challenge() {
+        // A cycle the oracle claimed not to see
+        let ast = r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#;
+        let p = proof(ast, "Circular budget", ChannelAVerdict::pass(40));
+        match verify_fraud_proof(&p, &VerificationConfig::default()) {
+            FraudProofResult::ChallengeSucceeded { recomputed } => {
+                assert_eq!(recomputed, p.actual_verdict);
+                assert!(recomputed.cycle_found);
+            }
+            other => panic!("expected success, got {:?}", other),
+        }
+
+        // A right pass/fail with a wrong complexity score is still wrong
+        let p = proof(
+            r#"{"action": "transfer"}"#,
+            "Transfer 100 tokens",
+            ChannelAVerdict::pass(1),
+        );
+        assert!(verify_fraud_proof(&p, &VerificationConfig::default()).is_successful());
+    }
+
+    #[test]
+    fn test_frivolous_challenge() {
+        let honest = verify_proposal(&Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "transfer"}"#.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L2Operational,
+        ));
+        let mut p = proof(r#"{"action": "transfer"}"#, "Transfer 100 tokens", honest);
+
+        // The challenger's claimed actual verdict is not trusted
+        p.actual_verdict = ChannelAVerdict::fail(0, true, true);
+        assert!(matches!(
+            verify_fraud_proof(&p, &VerificationConfig::default()),
+            FraudProofResult::ChallengeInvalid
+        ));
+    }
+
+    #[test]
+    fn test_recheck_uses_proof_layer_and_rules() {
+        let ast = r#"{"action": "transfer"}"#;
+        let text = "Transfer 100 tokens";
+        let default = VerificationConfig::default();
+
+        // An honest rejection of an L0 proposal stands
+        let honest = ChannelAVerdict::fail(0, false, false);
+        let p = proof_in(ast, text, GovernanceLayer::L0Immutable, &default, honest);
+        assert!(matches!(
+            verify_fraud_proof(&p, &default),
+            FraudProofResult::ChallengeInvalid
+        ));
+
+        // Under a lower L1 limit, an honest complexity failure stands too
+        let score = verify_proposal(&Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L1Constitutional,
+        ))
+        .complexity_score;
+        let strict = VerificationConfig {
+            complexity_limits: ComplexityLimits::default()
+                .with_limit(GovernanceLayer::L1Constitutional, score - 1),
+            ..Default::default()
+        };
+        let honest = ChannelAVerdict::fail(score, false, false);
+        let p = proof_in(
+            ast,
+            text,
+            GovernanceLayer::L1Constitutional,
+            &strict,
+            honest,
+        );
+        assert!(matches!(
+            verify_fraud_proof(&p, &strict),
+            FraudProofResult::ChallengeInvalid
+        ));
+        assert!(matches!(
+            verify_fraud_proof(&p, &default),
+            FraudProofResult::MalformedWitness(WitnessError::RulesMismatch)
+        ));
+
+        // A pass claimed under those rules is wrong
+        let p = proof_in(
+            ast,
+            text,
+            GovernanceLayer::L1Constitutional,
+            &strict,
+            ChannelAVerdict::pass(score),
+        );
+        assert!(verify_fraud_proof(&p, &strict).is_successful());
+    }
+
+    #[test]
+    fn test_malformed_witness() {
+        let valid = proof(
+            r#"{"action": "transfer"}"#,
+            "Transfer",
+            ChannelAVerdict::pass(1),
+        );
+
+        let mut p = valid.clone();
+        p.witness.canonical_payload = "not hex!".to_string();
+        match verify_fraud_proof(&p, &VerificationConfig::default()) {
+            FraudProofResult::MalformedWitness(e @ WitnessError::InvalidHex(_)) => {
+                assert_eq!(
+                    e.to_string(),
+                    "Canonical payload is not valid hex: Invalid character 'n' at position 0"
+                );
+            }
+            other => panic!("expected invalid hex, got {:?}", other),
+        }
+
+        // A payload for a different proposal
+        let mut p = valid.clone();
+        p.proposal_id = [0u8; 32];
+        assert!(matches!(
+            verify_fraud_proof(&p, &VerificationConfig::default()),
+            FraudProofResult::MalformedWitness(WitnessError::HashMismatch)
+        ));
+
+        // A payload that hashes correctly but is not canonical
+        let mut p = valid;
+        let bytes = b"{not json.transfer".to_vec();
+        p.proposal_id = Sha256::digest(&bytes).into();
+        p.witness.canonical_payload = hex::encode(&bytes);
+        assert!(matches!(
+            verify_fraud_proof(&p, &VerificationConfig::default()),
+            FraudProofResult::MalformedWitness(WitnessError::InvalidPayload(_))
+        ));
+    }
+}
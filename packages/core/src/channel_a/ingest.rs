@@ -0,0 +1,148 @@
+//! Proposal Ingest
+//!
+//! The oracle's entry point for raw proposal data pulled from the chain: a
+//! JSON array of proposals is parsed, validated, and canonicalized item by
+//! item, so a single malformed or oversized proposal cannot fail the batch.
+
+use serde_json::Value;
+use thiserror::Error;
+
+use super::canonicalize::{canonicalize, CanonicalizeError};
+use crate::types::{config::MAX_PAYLOAD_BYTES, Proposal};
+
+/// Why a proposal (or the whole input) could not be ingested
+#[derive(Debug, Error)]
+pub enum IngestError {
+    #[error("Input is not a JSON array of proposals: {0}")]
+    NotAnArray(#[source] serde_json::Error),
+    #[error("Invalid proposal: {0}")]
+    InvalidProposal(#[source] serde_json::Error),
+    #[error("Proposal has no proposer")]
+    MissingProposer,
+    #[error("Proposal is {size} bytes, above the {limit} byte limit")]
+    Oversized { size: usize, limit: usize },
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
+
+/// Outcome for one ingested proposal: the proposal (with `id` set) and its canonical hash
+pub type IngestResult = Result<(Proposal, [u8; 32]), IngestError>;
+
+/// Parse, validate, and canonicalize a JSON array of proposals
+///
+/// Returns one result per array element, in order. A successful item
+/// carries the proposal with its `id` set to the canonical hash. The outer
+/// error is reserved for input that is not a JSON array at all.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::ingest_proposals;
+///
+/// let json = r#"[
+///     {"id": "0000000000000000000000000000000000000000000000000000000000000000",
+///      "proposer": "rAddr", "logic_ast": "{\"a\":1}", "text": "Fund it",
+///      "layer": "L2Operational", "created_at": 0, "status": "Pending"},
+///     {"proposer": "rAddr"}
+/// ]"#;
+///
+/// let results = ingest_proposals(json).unwrap();
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn ingest_proposals(json: &str) -> Result<Vec<IngestResult>, IngestError> {
+    let items: Vec<Value> = serde_json::from_str(json).map_err(IngestError::NotAnArray)?;
+    Ok(items.into_iter().map(ingest_one).collect())
+}
+
+fn ingest_one(item: Value) -> IngestResult {
+    let proposal: Proposal = serde_json::from_value(item).map_err(IngestError::InvalidProposal)?;
+
+    if proposal.proposer.trim().is_empty() {
+        return Err(IngestError::MissingProposer);
+    }
+
+    // Checked on the raw fields so an oversized proposal is never canonicalized
+    let size = proposal.logic_ast.len() + proposal.text.len();
+    if size > MAX_PAYLOAD_BYTES {
+        return Err(IngestError::Oversized {
+            size,
+            limit: MAX_PAYLOAD_BYTES,
+        });
+    }
+
+    let hash = canonicalize(&proposal)?.hash;
+    Ok((proposal.with_id(hash), hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+
+    fn proposal_json(proposer: &str, logic_ast: &str, text: &str) -> Value {
+        serde_json::to_value(Proposal::new(
+            proposer.to_string(),
+            logic_ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_mixed_array_per_item_results() {
+        let valid = proposal_json(
+            "rTestAddress123",
+            r#"{"action": "transfer"}"#,
+            "Transfer 100 tokens",
+        );
+        let bad_ast = proposal_json("rTestAddress123", r#"{"action": "#, "Broken AST");
+        let no_proposer = proposal_json("  ", r#"{}"#, "Anonymous");
+        let oversized = proposal_json("rTestAddress123", r#"{}"#, &"x".repeat(MAX_PAYLOAD_BYTES));
+        let not_a_proposal = serde_json::json!({"proposer": "rTestAddress123"});
+
+        let json =
+            Value::Array(vec![valid, bad_ast, no_proposer, oversized, not_a_proposal]).to_string();
+        let results = ingest_proposals(&json).unwrap();
+        assert_eq!(results.len(), 5);
+
+        let (proposal, hash) = results[0].as_ref().unwrap();
+        assert_eq!(proposal.id, *hash);
+        assert_eq!(*hash, canonicalize(proposal).unwrap().hash);
+
+        assert!(matches!(results[1], Err(IngestError::Canonicalize(_))));
+        assert!(matches!(results[2], Err(IngestError::MissingProposer)));
+        assert!(matches!(
+            results[3],
+            Err(IngestError::Oversized { size, limit: MAX_PAYLOAD_BYTES }) if size == MAX_PAYLOAD_BYTES + 2
+        ));
+        assert!(matches!(results[4], Err(IngestError::InvalidProposal(_))));
+    }
+
+    #[test]
+    fn test_non_array_input() {
+        assert!(matches!(
+            ingest_proposals(r#"{"a": 1}"#),
+            Err(IngestError::NotAnArray(_))
+        ));
+        assert!(matches!(
+            ingest_proposals("not json"),
+            Err(IngestError::NotAnArray(_))
+        ));
+        assert!(ingest_proposals("[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_serde_errors_are_sources() {
+        use std::error::Error as _;
+
+        let err = ingest_proposals(r#"{"a": 1}"#).unwrap_err();
+        assert!(err.source().is_some_and(|e| e.is::<serde_json::Error>()));
+
+        let results = ingest_proposals(r#"[{"proposer": "rAddr"}]"#).unwrap();
+        let err = results[0].as_ref().unwrap_err();
+        assert!(err.to_string().contains("missing field"), "{}", err);
+        assert!(err.source().is_some_and(|e| e.is::<serde_json::Error>()));
+    }
+}
@@ -0,0 +1,130 @@
+//! AST Structural Metrics
+//!
+//! Quick structural statistics of a proposal's logic AST for browsing UIs
+//! and fee calculators. These are metadata only and never affect a verdict.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::canonicalize::{parse_ast, CanonicalizeError};
+
+/// Structural metrics of a logic AST
+///
+/// The root value itself is not counted: `{}` has zero nodes and depth 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AstMetrics {
+    /// Number of values below the root (object members and array elements)
+    pub node_count: u64,
+    /// Deepest nesting level (direct children of the root are depth 1)
+    pub max_depth: u64,
+    /// Number of dependency references (`$ref:`, `depends_on`, `references`, `ref`)
+    pub edge_count: u64,
+    /// Nodes with no children (scalars and empty containers)
+    pub leaf_count: u64,
+}
+
+/// Compute structural metrics of an AST in a single traversal
+///
+/// Uses an explicit stack rather than recursion, so deeply nested input
+/// cannot overflow the call stack.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::ast_metrics;
+///
+/// let metrics = ast_metrics(r#"{"a": {"value": 1}, "b": "$ref:a"}"#).unwrap();
+/// assert_eq!(metrics.node_count, 3);
+/// assert_eq!(metrics.max_depth, 2);
+/// assert_eq!(metrics.edge_count, 1);
+/// ```
+pub fn ast_metrics(ast_json: &str) -> Result<AstMetrics, CanonicalizeError> {
+    let ast = parse_ast(ast_json)?;
+    let mut metrics = AstMetrics::default();
+
+    // (value, depth) pairs; the root sits at depth 0
+    let mut stack: Vec<(&Value, u64)> = vec![(&ast, 0)];
+
+    while let Some((value, depth)) = stack.pop() {
+        if depth > 0 {
+            metrics.node_count += 1;
+            metrics.max_depth = metrics.max_depth.max(depth);
+        }
+
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::Array(arr)) = map.get("depends_on") {
+                    metrics.edge_count += arr.iter().filter(|v| v.is_string()).count() as u64;
+                }
+                for key in ["references", "ref"] {
+                    if let Some(Value::String(_)) = map.get(key) {
+                        metrics.edge_count += 1;
+                    }
+                }
+                if map.is_empty() && depth > 0 {
+                    metrics.leaf_count += 1;
+                }
+                stack.extend(map.values().map(|v| (v, depth + 1)));
+            }
+            Value::Array(arr) => {
+                if arr.is_empty() && depth > 0 {
+                    metrics.leaf_count += 1;
+                }
+                stack.extend(arr.iter().map(|v| (v, depth + 1)));
+            }
+            Value::String(s) => {
+                if s.starts_with("$ref:") {
+                    metrics.edge_count += 1;
+                }
+                if depth > 0 {
+                    metrics.leaf_count += 1;
+                }
+            }
+            _ => {
+                if depth > 0 {
+                    metrics.leaf_count += 1;
+                }
+            }
+        }
+    }
+
+    Ok(metrics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_metrics() {
+        let ast = r#"{
+            "root": {"depends_on": ["a", "b"], "meta": {"tags": ["x", "y"]}},
+            "a": {"value": "$ref:c"},
+            "b": {"references": "c"},
+            "c": {"value": 1}
+        }"#;
+
+        let metrics = ast_metrics(ast).unwrap();
+        assert_eq!(
+            metrics,
+            AstMetrics {
+                // root, depends_on, "a", "b", meta, tags, "x", "y",
+                // a, value, b, references, c, value
+                node_count: 14,
+                max_depth: 4,
+                edge_count: 4,
+                leaf_count: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_empty_object_is_zero() {
+        assert_eq!(ast_metrics("{}").unwrap(), AstMetrics::default());
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        assert!(ast_metrics("{not json").is_err());
+    }
+}
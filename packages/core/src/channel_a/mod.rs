@@ -9,31 +9,140 @@
 //! - `canonicalize`: Produces deterministic representation of proposals
 //! - `complexity`: Measures proposal complexity via zlib compression
 //! - `paradox`: Detects self-referential paradoxes via regex
+//! - `clauses`: Detects paradoxes split across numbered clauses
 //! - `cycles`: Detects dependency cycles via Tarjan's SCC algorithm
+//! - `batch`: Parallel batch verification and epoch summaries
+//! - `metrics`: Structural AST metrics for proposal metadata
+//! - `self_test`: Golden-vector conformance check for the whole pipeline
+//! - `divergence`: Stage-by-stage explanation of disagreeing verdicts
+//! - `ingest`: Per-item validation and canonicalization of raw proposal arrays
+//! - `references`: Linter for reference-like values cycle detection ignores
+//! - `declared_class`: Checks a proposer's self-declared decidability class
+//! - `containment`: Advisory detection of padded resubmissions by text containment
+//! - `banned`: Governance-configured banned phrases (optional pipeline stage)
+//! - `safety`: Detects proposals that would disable verification once executed
+//! - `record`: Self-describing verification records for storage and audit
+//! - `explain`: Positive reports of what a passing proposal cleared
+//! - `paranoid`: Run-twice determinism check (`paranoid` feature)
+//! - `timing`: Per-stage wall-clock timings for production profiling
+//! - `trace`: Step-by-step computation traces for fraud proof witnesses
+//! - `fraud`: Authoritative checking of fraud proofs against their witness
+//! - `cache`: TTL cache of verdicts keyed by canonical hash and form version
+//! - `deadline`: Time-bounded verification on a worker thread (`threaded` feature)
+//! - `schema`: JSON Schema validation of logic ASTs (`schema` feature)
 
+mod banned;
+mod batch;
+mod cache;
 mod canonicalize;
+mod clauses;
 mod complexity;
-mod paradox;
+mod containment;
 mod cycles;
+#[cfg(feature = "threaded")]
+mod deadline;
+mod declared_class;
+mod divergence;
+mod explain;
+mod fraud;
+mod ingest;
+mod metrics;
+mod paradox;
+mod paranoid;
+mod record;
+mod references;
+mod safety;
+#[cfg(feature = "schema")]
+mod schema;
+mod self_test;
+mod timing;
+mod trace;
+
+pub use banned::{check_banned_keywords, BannedKeywordError, BannedKeywordList};
+pub use batch::{summarize_batch, summarize_batch_with, verify_batch, BatchSummary};
+pub use cache::TtlVerificationCache;
+pub use canonicalize::{
+    canonicalize, canonicalize_and_id, canonicalize_value, canonicalize_value_with,
+    canonicalize_with, is_normalized, quick_ast_check, ArraySemantics, CanonicalPayload,
+    CanonicalizeError, CanonicalizeOptions,
+};
+pub(crate) use canonicalize::{fold_compatibility, normalize_text};
+pub use clauses::{detect_clause_paradox, split_clauses, Clause};
+pub use complexity::{
+    ast_only_complexity, check_complexity, complexity_bucket, complexity_headroom,
+    complexity_headroom_with, complexity_in, compute_complexity, compute_complexity_breakdown,
+    compute_complexity_with_dictionary, convert_complexity, max_complexity, text_only_complexity,
+    ComplexityAlgorithm, ComplexityBreakdown, ComplexityHeadroom, ComplexityLimits, ComplexityUnit,
+    COMPLEXITY_FAILURE, DEFAULT_COMPLEXITY_DICTIONARY, NEAR_LIMIT_PERCENT,
+};
+pub use containment::{
+    text_containment, Containment, ContainmentResult, MIN_FLAGGED_STRENGTH, MIN_SHARED_TOKENS,
+};
+pub use cycles::{
+    detect_cycles, detect_cycles_value, detect_cycles_value_with, detect_cycles_with,
+    find_cycles_detail, referenceable_nodes, CycleDetectionError, CycleOptions, EdgeKind,
+};
+#[cfg(feature = "threaded")]
+pub use deadline::{verify_with_deadline, DeadlineError};
+pub use declared_class::{check_declared_class, declared_class, ClassMismatch, DECLARED_CLASS_KEY};
+pub use divergence::{explain_divergence, DivergenceReport, PayloadDivergence, PayloadSection};
+pub use explain::{explain_pass, PassReport};
+pub use fraud::{verify_fraud_proof, FraudProofResult, WitnessError};
+pub use ingest::{ingest_proposals, IngestError, IngestResult};
+pub use metrics::{ast_metrics, AstMetrics};
+pub use paradox::{
+    detect_paradox, find_paradox_matches, get_paradox_pattern_ids, get_paradox_patterns,
+    paradox_class, paradox_confidence, ruleset_impact, CompiledRuleset, ImpactReport, ParadoxClass,
+    ParadoxDetector, ParadoxMatch, ParadoxRule, ParadoxRuleset, RulesetDiff,
+};
+pub use record::{verify_and_record, RuleVersions, VerificationRecord};
+pub use references::{lint_references, lint_references_json, SuspiciousReference};
+pub use safety::detects_verification_disabling;
+#[cfg(feature = "schema")]
+pub use schema::{validate_ast_schema, SchemaError};
+pub use self_test::{self_test, SelfTestFailure};
+pub use timing::{verify_proposal_timed, StageTimings};
+pub use trace::verify_proposal_traced;
 
-pub use canonicalize::{canonicalize, CanonicalPayload};
-pub use complexity::{compute_complexity, check_complexity};
-pub use paradox::detect_paradox;
-pub use cycles::detect_cycles;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 
-use crate::types::{ChannelAVerdict, Proposal, config};
+use crate::types::{config, ChannelAVerdict, DecidabilityClass, GovernanceLayer, Proposal};
+
+/// Initialize all lazily-compiled detector state up front
+///
+/// Call once during startup so the first real verification does not pay
+/// regex compilation cost. Thread-safe and idempotent.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{detect_paradox, warm_up};
+///
+/// warm_up();
+/// assert!(detect_paradox("This statement is false"));
+/// ```
+pub fn warm_up() {
+    paradox::warm_up();
+    clauses::warm_up();
+}
 
 /// Verify a proposal through the full Channel A pipeline
 ///
 /// # Process (from spec v5.0)
 ///
+/// 0. Proposals targeting L0 (Immutable Core) FAIL before any other check
 /// 1. Canonicalize(ProposalTransaction) -> (CanonicalPayloadBytes, CanonicalHash)
 /// 2. ComputeComplexity(CanonicalPayloadBytes) -> complexity_score
 /// 3. DetectParadox(CanonicalPayloadBytes) -> paradox_found
 /// 4. DetectCycles(CanonicalPayloadBytes) -> cycle_found
-/// 5. If complexity_score > MAX_COMPLEXITY OR paradox_found OR cycle_found: FAIL
+/// 5. If complexity_score > the layer's limit OR paradox_found OR cycle_found: FAIL
 /// 6. Else: PASS
 ///
+/// The proposal is not modified: its `id` is neither read nor set. Use
+/// `canonicalize_and_id` to get a copy whose `id` is the canonical hash.
+///
 /// # Example
 ///
 /// ```
@@ -50,40 +159,474 @@ use crate::types::{ChannelAVerdict, Proposal, config};
 /// assert!(verdict.pass);
 /// ```
 pub fn verify_proposal(proposal: &Proposal) -> ChannelAVerdict {
-    // Step 1: Canonicalize
-    let canonical = match canonicalize(proposal) {
-        Ok(c) => c,
-        Err(_) => {
-            // Canonicalization failure is a hard fail
-            return ChannelAVerdict::fail(0, false, false);
+    verify_proposal_with_config(proposal, &VerificationConfig::default())
+}
+
+/// Verify a proposal under explicit verification parameters
+///
+/// For shadow verification: running the same proposal under testnet or
+/// proposed mainnet parameters without recompiling. Use
+/// `verify_proposal_with` for the reasons and advisory signals as well.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{verify_proposal_with_config, ComplexityLimits, VerificationConfig};
+/// use constitution_dao_core::{verify_proposal, GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let strict = VerificationConfig { complexity_limits: ComplexityLimits::uniform(10), ..Default::default() };
+/// assert!(!verify_proposal_with_config(&proposal, &strict).pass);
+/// assert!(verify_proposal(&proposal).pass);
+/// ```
+pub fn verify_proposal_with_config(
+    proposal: &Proposal,
+    config: &VerificationConfig,
+) -> ChannelAVerdict {
+    verify_proposal_with(proposal, config).verdict
+}
+
+/// Verify a proposal whose AST is already parsed
+///
+/// Runs the same pipeline as `verify_proposal` and returns an identical
+/// verdict for the equivalent string AST.
+pub fn verify_value(ast: &Value, text: &str, layer: GovernanceLayer) -> ChannelAVerdict {
+    verify_value_with(ast, text, layer, &VerificationConfig::default()).verdict
+}
+
+/// Operator-tunable settings for the verification pipeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationConfig {
+    /// Minimum pattern confidence for a paradox match to hard-fail
+    ///
+    /// Matches below it do not set `paradox_found` but flag the proposal
+    /// for review. The default, 0.0, fails on any match.
+    pub paradox_confidence_threshold: f64,
+    /// Canonicalization options; affect both the hash and the complexity score
+    pub canonicalize: CanonicalizeOptions,
+    /// Fail proposals containing suspected-but-unrecognized references
+    ///
+    /// Off by default: such values are ignored by cycle detection and only
+    /// reported in `DetailedVerdict::suspicious_references`.
+    pub reject_unknown_refs: bool,
+    /// Top-level AST field read as the proposer's declared decidability class
+    pub declared_class_key: String,
+    /// Phrases that fail verification when found in the normalized text
+    ///
+    /// Consensus-relevant: every oracle must use the same list.
+    pub banned_keywords: Option<BannedKeywordList>,
+    /// Complexity scoring algorithm
+    ///
+    /// Consensus-relevant: every oracle must use the same algorithm.
+    pub complexity_algorithm: ComplexityAlgorithm,
+    /// Complexity limit for each governance layer
+    ///
+    /// Consensus-relevant: every oracle must use the same limits.
+    pub complexity_limits: ComplexityLimits,
+    /// Longest canonical payload that is compressed for scoring
+    ///
+    /// Longer payloads score `COMPLEXITY_FAILURE` without compression.
+    /// Consensus-relevant: every oracle must use the same bound.
+    pub max_payload_bytes: usize,
+    /// Paradox rules to detect with instead of the built-in patterns
+    ///
+    /// Rules sharing an ID with a built-in pattern keep its confidence;
+    /// other rules have full confidence. A ruleset with an invalid pattern
+    /// does not compile, so a configuration naming one fails to load.
+    pub paradox_rules: Option<CompiledRuleset>,
+    /// Most edges the dependency graph may have before it counts as cyclic
+    pub max_graph_edges: usize,
+    /// Most nodes the dependency graph may have before it counts as cyclic
+    pub max_graph_nodes: usize,
+    /// Deepest AST nesting cycle detection analyzes; deeper counts as cyclic
+    pub max_ast_depth: usize,
+    /// Canonical form version written into the payload prefix
+    ///
+    /// Only the prefix byte changes: the normalization rules of earlier
+    /// forms are not reproduced. Useful for rehearsing a version bump.
+    pub canonical_form_version: u8,
+}
+
+/// Former name of `VerificationConfig`
+pub type VerifyConfig = VerificationConfig;
+
+impl Default for VerificationConfig {
+    fn default() -> Self {
+        Self {
+            paradox_confidence_threshold: 0.0,
+            canonicalize: CanonicalizeOptions::default(),
+            reject_unknown_refs: false,
+            declared_class_key: DECLARED_CLASS_KEY.to_string(),
+            banned_keywords: None,
+            complexity_algorithm: ComplexityAlgorithm::default(),
+            complexity_limits: ComplexityLimits::default(),
+            max_payload_bytes: config::MAX_PAYLOAD_BYTES,
+            paradox_rules: None,
+            max_graph_edges: config::MAX_GRAPH_EDGES,
+            max_graph_nodes: config::MAX_GRAPH_NODES,
+            max_ast_depth: config::MAX_AST_DEPTH,
+            canonical_form_version: config::CANONICAL_FORM_VERSION,
         }
-    };
+    }
+}
 
+impl VerificationConfig {
+    /// SHA-256 of the serialized configuration
+    ///
+    /// Identifies the rules a verdict was reached under: two oracles agree
+    /// on every verdict exactly when their hashes match.
+    pub fn rules_hash(&self) -> [u8; 32] {
+        let serialized = serde_json::to_vec(self).expect("configuration serializes to JSON");
+        Sha256::digest(serialized).into()
+    }
+
+    /// Graph extraction options carrying the configured size caps
+    fn cycle_options(&self) -> CycleOptions {
+        CycleOptions {
+            max_edges: self.max_graph_edges,
+            max_nodes: self.max_graph_nodes,
+            max_depth: self.max_ast_depth,
+            ..Default::default()
+        }
+    }
+}
+
+/// Why a proposal failed Channel A
+///
+/// `ChannelAVerdict` stays binary for on-chain compatibility; the reason is
+/// reported alongside it so a malformed proposal can be told apart from one
+/// that genuinely failed a check. When several checks fail, the reason is
+/// the first in pipeline order; the verdict's flags still report each one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureReason {
+    /// The proposal targets L0 (Immutable Core), which proposals cannot change
+    L0NotTargetable,
+    /// The logic AST could not be parsed or canonicalized; no check ran
+    CanonicalizationError,
+    /// The complexity score is above the layer's limit (or could not be computed)
+    ComplexityExceeded,
+    /// A paradox matched at or above the confidence threshold
+    ParadoxFound,
+    /// The dependency graph has a cycle (or was too large to analyze)
+    CycleFound,
+    /// Unrecognized reference syntax under `VerificationConfig::reject_unknown_refs`
+    UnknownReferences,
+    /// A governance-banned phrase appears in the text
+    BannedContent,
+}
+
+/// A verdict plus the signals that did not reach the hard gate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetailedVerdict {
+    /// The Channel A verdict
+    pub verdict: ChannelAVerdict,
+    /// Why the verdict failed; `None` exactly when it passed
+    pub failure_reason: Option<FailureReason>,
+    /// A paradox matched below the confidence threshold
+    pub should_review: bool,
+    /// Highest confidence of any paradox match, if one matched
+    pub paradox_confidence: Option<f64>,
+    /// Class of that strongest match, for routing by kind of paradox
+    pub paradox_class: Option<ParadoxClass>,
+    /// Reference-like values not in a recognized syntax
+    ///
+    /// Fails the verdict when `VerificationConfig::reject_unknown_refs` is set.
+    pub suspicious_references: Vec<SuspiciousReference>,
+    /// Decidability class the proposer declared in the AST, if any
+    pub declared_class: Option<DecidabilityClass>,
+    /// Advisory: the declared class conflicts with the findings
+    ///
+    /// Does not affect the verdict.
+    pub class_mismatch: Option<ClassMismatch>,
+    /// Banned phrase found in the text; fails the verdict
+    pub banned_content: Option<String>,
+    /// Node names of the first dependency cycle, each depending on the next
+    ///
+    /// Diagnostic only. `None` when no cycle was found, and also when the
+    /// graph was too large to analyze (which fails as a cycle).
+    pub cycle_path: Option<Vec<String>>,
+}
+
+impl DetailedVerdict {
+    /// Fail without running the checks (rejected up front or not canonicalizable)
+    fn hard_fail(failure_reason: FailureReason) -> Self {
+        Self {
+            failure_reason: Some(failure_reason),
+            verdict: ChannelAVerdict::fail(0, false, false),
+            should_review: false,
+            paradox_confidence: None,
+            paradox_class: None,
+            suspicious_references: Vec::new(),
+            declared_class: None,
+            class_mismatch: None,
+            banned_content: None,
+            cycle_path: None,
+        }
+    }
+}
+
+/// Verify a proposal with explicit pipeline settings
+///
+/// With `VerificationConfig::default()` the verdict is identical to
+/// `verify_proposal`.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{verify_proposal_with, VerificationConfig};
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "repeal"}"#.to_string(),
+///     "If this passes then the old fee schedule is rejected".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let config = VerificationConfig { paradox_confidence_threshold: 0.75, ..Default::default() };
+/// let detailed = verify_proposal_with(&proposal, &config);
+/// assert!(detailed.verdict.pass);
+/// assert!(detailed.should_review);
+/// ```
+pub fn verify_proposal_with(proposal: &Proposal, config: &VerificationConfig) -> DetailedVerdict {
+    if proposal.layer == GovernanceLayer::L0Immutable {
+        return DetailedVerdict::hard_fail(FailureReason::L0NotTargetable);
+    }
+    match canonicalize::parse_ast(&proposal.logic_ast) {
+        Ok(ast) => verify_value_with(&ast, &proposal.text, proposal.layer, config),
+        // Canonicalization failure is a hard fail
+        Err(_) => DetailedVerdict::hard_fail(FailureReason::CanonicalizationError),
+    }
+}
+
+fn verify_value_with(
+    ast: &Value,
+    text: &str,
+    layer: GovernanceLayer,
+    config: &VerificationConfig,
+) -> DetailedVerdict {
+    // Step 0: L0 is not directly targetable by proposals
+    if layer == GovernanceLayer::L0Immutable {
+        return DetailedVerdict::hard_fail(FailureReason::L0NotTargetable);
+    }
+
+    paranoid::checked("verification", || {
+        // Step 1: Canonicalize
+        let canonical = match canonicalize::canonicalize_versioned(
+            ast,
+            text,
+            &config.canonicalize,
+            config.canonical_form_version,
+        ) {
+            Ok(c) => c,
+            // Canonicalization failure is a hard fail
+            Err(_) => return DetailedVerdict::hard_fail(FailureReason::CanonicalizationError),
+        };
+
+        // Steps 2-6 run on the payload and the original (unstripped) AST
+        evaluate(
+            &canonical.bytes,
+            ast,
+            text,
+            canonical.normalized_text(),
+            layer,
+            config,
+        )
+    })
+}
+
+/// Verify an already-canonicalized payload
+///
+/// Splits the payload at its separator and runs the pipeline exactly as
+/// `verify_proposal` would: complexity on the whole payload, cycle
+/// detection on the AST half and paradox detection on the text half. This
+/// is the re-verification primitive for fraud proofs, which carry the
+/// payload rather than the original `Proposal`.
+///
+/// Cross-clause detection recovers clauses from the bare numbers that
+/// survive normalization, so it reaches the same result on the payload's
+/// text as on the submitted text. The payload does not record the
+/// governance layer, so this checks it as an L2 proposal under the default
+/// configuration; use `verify_canonical_with` to supply both. Malformed
+/// payloads hard-fail.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{canonicalize, verify_canonical};
+/// use constitution_dao_core::{verify_proposal, Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let payload = canonicalize(&proposal).unwrap();
+/// assert_eq!(verify_canonical(&payload.bytes), verify_proposal(&proposal));
+/// ```
+pub fn verify_canonical(payload_bytes: &[u8]) -> ChannelAVerdict {
+    verify_canonical_with(
+        payload_bytes,
+        GovernanceLayer::L2Operational,
+        &VerificationConfig::default(),
+    )
+    .verdict
+}
+
+/// Verify an already-canonicalized payload submitted under `layer`
+///
+/// Matches `verify_proposal_with` for the proposal the payload came from,
+/// given the same layer and configuration.
+pub fn verify_canonical_with(
+    payload_bytes: &[u8],
+    layer: GovernanceLayer,
+    config: &VerificationConfig,
+) -> DetailedVerdict {
+    if layer == GovernanceLayer::L0Immutable {
+        return DetailedVerdict::hard_fail(FailureReason::L0NotTargetable);
+    }
+    paranoid::checked(
+        "canonical verification",
+        || match canonicalize::split_payload(payload_bytes) {
+            Ok((ast, text)) => evaluate(payload_bytes, &ast, text, text, layer, config),
+            Err(_) => DetailedVerdict::hard_fail(FailureReason::CanonicalizationError),
+        },
+    )
+}
+
+/// Pipeline steps 2-6 over canonical payload bytes
+///
+/// `text` is the proposal text as submitted and `normalized` its
+/// normalized form from the payload.
+fn evaluate(
+    payload: &[u8],
+    ast: &Value,
+    text: &str,
+    normalized: &str,
+    layer: GovernanceLayer,
+    config: &VerificationConfig,
+) -> DetailedVerdict {
+    evaluate_timed(
+        payload,
+        ast,
+        text,
+        normalized,
+        layer,
+        config,
+        &mut StageTimings::default(),
+    )
+}
+
+/// `evaluate`, recording how long steps 2-4 take
+fn evaluate_timed(
+    payload: &[u8],
+    ast: &Value,
+    text: &str,
+    normalized: &str,
+    layer: GovernanceLayer,
+    config: &VerificationConfig,
+    timings: &mut StageTimings,
+) -> DetailedVerdict {
     // Step 2: Compute complexity
-    let complexity_score = compute_complexity(&canonical.bytes);
+    let complexity_score = timing::timed(&mut timings.complexity, || {
+        config
+            .complexity_algorithm
+            .score_within(payload, config.max_payload_bytes)
+    });
 
-    // Step 3: Detect paradoxes
-    let paradox_found = detect_paradox(&proposal.text);
+    // Step 3: Detect paradoxes (sentence-local and cross-clause)
+    // A cross-clause contradiction is structural, so it has full confidence;
+    // its odd-negation cycle is a liar paradox spread over several clauses.
+    // It normalizes its input, so the submitted and normalized text agree
+    let strongest = timing::timed(&mut timings.paradox, || {
+        if detect_clause_paradox(&fold_compatibility(text)) {
+            Some((1.0, ParadoxClass::Liar))
+        } else if let Some(rules) = &config.paradox_rules {
+            rules.strongest_match(normalized)
+        } else {
+            paradox::strongest_match(normalized)
+        }
+    });
+    let confidence = strongest.map(|(confidence, _)| confidence);
+    let paradox_found = confidence.is_some_and(|c| c >= config.paradox_confidence_threshold);
+    let should_review = confidence.is_some() && !paradox_found;
 
     // Step 4: Detect cycles
-    let cycle_found = detect_cycles(&proposal.logic_ast).unwrap_or(false);
+    // Fail-safe: a graph that cannot be analyzed (e.g. too large) counts as cyclic
+    let cycle_options = config.cycle_options();
+    let (cycle_found, cycle_path) = timing::timed(&mut timings.cycles, || {
+        match detect_cycles_value_with(ast, &cycle_options) {
+            Ok(true) => (
+                true,
+                cycles::first_cycle_path(ast, &cycle_options).ok().flatten(),
+            ),
+            Ok(false) => (false, None),
+            Err(_) => (true, None),
+        }
+    });
+
+    // References cycle detection could not see; fatal only in strict mode
+    let suspicious_references = lint_references(ast);
+    let refs_rejected = config.reject_unknown_refs && !suspicious_references.is_empty();
 
-    // Step 5-6: Determine pass/fail
-    let pass = complexity_score <= config::MAX_COMPLEXITY
-        && !paradox_found
-        && !cycle_found;
+    // Optional stage: governance-banned phrases
+    let banned_content = config
+        .banned_keywords
+        .as_ref()
+        .and_then(|list| check_banned_keywords(text, list));
 
-    if pass {
+    // Step 5-6: Determine pass/fail, reporting the first failing check
+    let failure_reason =
+        if !check_complexity(complexity_score, config.complexity_limits.limit_for(layer)) {
+            Some(FailureReason::ComplexityExceeded)
+        } else if paradox_found {
+            Some(FailureReason::ParadoxFound)
+        } else if cycle_found {
+            Some(FailureReason::CycleFound)
+        } else if refs_rejected {
+            Some(FailureReason::UnknownReferences)
+        } else if banned_content.is_some() {
+            Some(FailureReason::BannedContent)
+        } else {
+            None
+        };
+    let pass = failure_reason.is_none();
+
+    let verdict = if pass {
         ChannelAVerdict::pass(complexity_score)
     } else {
         ChannelAVerdict::fail(complexity_score, paradox_found, cycle_found)
+    };
+
+    // Advisory: compare the self-declared class with the findings
+    let declared = declared_class(ast, &config.declared_class_key);
+    let class_mismatch = declared.and_then(|class| check_declared_class(class, &verdict));
+
+    DetailedVerdict {
+        verdict,
+        failure_reason,
+        should_review,
+        paradox_confidence: confidence,
+        paradox_class: strongest.map(|(_, class)| class),
+        suspicious_references,
+        declared_class: declared,
+        class_mismatch,
+        banned_content,
+        cycle_path,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::GovernanceLayer;
 
     #[test]
     fn test_simple_proposal_passes() {
@@ -116,6 +659,186 @@ mod tests {
         assert!(verdict.paradox_found);
     }
 
+    #[test]
+    fn test_oversized_graph_fails() {
+        // Acyclic but dense: every node depends on all later nodes (~105k edges)
+        let names: Vec<String> = (0..460).map(|i| format!("\"n{}\"", i)).collect();
+        let nodes: Vec<String> = (0..460)
+            .map(|i| {
+                format!(
+                    r#""n{}": {{"depends_on": [{}]}}"#,
+                    i,
+                    names[i + 1..].join(",")
+                )
+            })
+            .collect();
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            format!("{{{}}}", nodes.join(",")),
+            "Dense dependencies".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+
+        let verdict = verify_proposal(&proposal);
+        assert!(!verdict.pass);
+        assert!(verdict.cycle_found);
+    }
+
+    #[test]
+    fn test_value_path_matches_string_path() {
+        let cases = [
+            (
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            (
+                r#"{"z": {"y": [1, 2.5, null]}, "a": "s"}"#,
+                "Nested, unordered keys",
+            ),
+            (
+                r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#,
+                "Cyclic logic",
+            ),
+            (r#"{}"#, "This proposal passes iff it fails."),
+            (r#"[{"x": 1e20}, {"y": -0.0}]"#, "Top-level array"),
+        ];
+
+        for (ast_json, text) in cases {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast_json.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let ast: Value = serde_json::from_str(ast_json).unwrap();
+
+            let by_string = canonicalize(&proposal).unwrap();
+            let by_value = canonicalize_value(&ast, text).unwrap();
+            assert_eq!(
+                by_string.bytes, by_value.bytes,
+                "payload differs for {}",
+                ast_json
+            );
+            assert_eq!(by_string.hash, by_value.hash);
+
+            assert_eq!(
+                verify_proposal(&proposal),
+                verify_value(&ast, text, proposal.layer)
+            );
+        }
+    }
+
+    #[test]
+    fn test_value_path_matches_string_path_on_generated_asts() {
+        // Hand-rendered JSON, so key order and number spelling vary in ways
+        // a serialized `Value` would normalize away
+        fn render(next: &mut impl FnMut() -> u32, depth: u32) -> String {
+            const KEYS: [&str; 6] = ["action", "amount", "to", "a", "b", r"\u00e9"];
+            const NUMBERS: [&str; 10] = [
+                "0",
+                "-0.0",
+                "100",
+                "2.5",
+                "1e20",
+                "-3.25E-7",
+                "0.1",
+                "1.0",
+                "18446744073709551615",
+                "-9223372036854775808",
+            ];
+            match next() % if depth == 0 { 4 } else { 6 } {
+                0 => NUMBERS[next() as usize % NUMBERS.len()].to_string(),
+                1 => format!("{}.{:03}", next() % 1000, next() % 1000),
+                2 => ["null", "true", r#""$ref:a""#, r#""x y""#][next() as usize % 4].to_string(),
+                3 => format!("{}e{}", next() % 100, next() % 40),
+                4 => {
+                    let items: Vec<_> = (0..next() % 4).map(|_| render(next, depth - 1)).collect();
+                    format!("[{}]", items.join(", "))
+                }
+                _ => {
+                    // Distinct keys in a random order; duplicates are a parse error
+                    let mut keys = KEYS.to_vec();
+                    let len = next() as usize % keys.len();
+                    let entries: Vec<_> = (0..len)
+                        .map(|_| {
+                            let key = keys.remove(next() as usize % keys.len());
+                            format!(r#""{}": {}"#, key, render(next, depth - 1))
+                        })
+                        .collect();
+                    format!("{{{}}}", entries.join(", "))
+                }
+            }
+        }
+
+        let texts = [
+            "Transfer 100 tokens",
+            "This proposal passes iff it fails.",
+            "  Mixed   CASE\ttext ",
+        ];
+        let mut state: u32 = 0x9E37_79B9;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for _ in 0..500 {
+            let ast_json = render(&mut next, 4);
+            let text = texts[next() as usize % texts.len()];
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast_json.clone(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let ast: Value = serde_json::from_str(&ast_json).unwrap();
+
+            let by_string = canonicalize(&proposal).unwrap();
+            let by_value = canonicalize_value(&ast, text).unwrap();
+            assert_eq!(
+                by_string.bytes, by_value.bytes,
+                "payload differs for {}",
+                ast_json
+            );
+            assert_eq!(by_string.hash, by_value.hash);
+
+            assert_eq!(
+                verify_proposal(&proposal),
+                verify_value(&ast, text, proposal.layer),
+                "verdict differs for {}",
+                ast_json
+            );
+        }
+    }
+
+    #[test]
+    fn test_warm_up_idempotent() {
+        warm_up();
+        warm_up();
+        assert!(detect_paradox("This proposal passes iff it fails"));
+
+        let handles: Vec<_> = (0..4).map(|_| std::thread::spawn(warm_up)).collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(!detect_paradox("Transfer 100 tokens to the community fund"));
+    }
+
+    #[test]
+    fn test_cross_clause_paradox_fails() {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "conditional"}"#.to_string(),
+            "1. This proposal passes. 2. Clause 1 holds iff clause 2 is false.".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+
+        let verdict = verify_proposal(&proposal);
+        assert!(!verdict.pass);
+        assert!(verdict.paradox_found);
+    }
+
     #[test]
     fn test_spec_test_vector_paradox() {
         // From Appendix A.3 test vector
@@ -129,4 +852,665 @@ mod tests {
         let verdict = verify_proposal(&proposal);
         assert!(verdict.paradox_found);
     }
+
+    #[test]
+    fn test_default_config_matches_verify_proposal() {
+        let texts = [
+            "Transfer 100 tokens to the community fund",
+            "This proposal passes iff it fails.",
+            "If this passes then the old fee schedule is rejected",
+            "1. This proposal passes. 2. Clause 1 holds iff clause 2 is false.",
+        ];
+        for text in texts {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                r#"{"action": "test"}"#.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let detailed = verify_proposal_with(&proposal, &VerificationConfig::default());
+            assert_eq!(detailed.verdict, verify_proposal(&proposal), "{}", text);
+            assert!(!detailed.should_review);
+        }
+    }
+
+    #[test]
+    fn test_low_confidence_paradox_routes_to_review() {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "repeal"}"#.to_string(),
+            "If this passes then the old fee schedule is rejected".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let config = VerificationConfig {
+            paradox_confidence_threshold: 0.75,
+            ..Default::default()
+        };
+
+        // Hard-fails by default
+        assert!(verify_proposal(&proposal).paradox_found);
+
+        let detailed = verify_proposal_with(&proposal, &config);
+        assert!(detailed.verdict.pass);
+        assert!(!detailed.verdict.paradox_found);
+        assert!(detailed.should_review);
+        assert_eq!(detailed.paradox_confidence, Some(0.6));
+        assert_eq!(
+            detailed.paradox_class,
+            Some(ParadoxClass::ConditionalSelfReference)
+        );
+    }
+
+    #[test]
+    fn test_verdict_surfaces_paradox_class() {
+        let config = VerificationConfig::default();
+        let cases = [
+            ("This statement is false", Some(ParadoxClass::Liar)),
+            (
+                "This passes only if it doesn't pass",
+                Some(ParadoxClass::NegationLoop),
+            ),
+            (
+                "The set of all rules that do not contain themselves",
+                Some(ParadoxClass::RussellVariant),
+            ),
+            (
+                "1. This proposal passes. 2. Clause 1 holds iff clause 2 is false.",
+                Some(ParadoxClass::Liar),
+            ),
+            ("Transfer 100 tokens to the community fund", None),
+        ];
+
+        for (text, class) in cases {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                "{}".to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let detailed = verify_proposal_with(&proposal, &config);
+            assert_eq!(detailed.paradox_class, class, "{}", text);
+            assert_eq!(detailed.verdict.paradox_found, class.is_some());
+        }
+    }
+
+    #[test]
+    fn test_cycle_path_reported() {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"fee": {"value": "$ref:cap"}, "cap": {"value": "$ref:total"}, "total": {"value": "$ref:fee"}, "memo": {"value": 1}}"#
+                .to_string(),
+            "Circular fees".to_string(),
+            GovernanceLayer::L3Execution,
+        );
+        let detailed = verify_proposal_with(&proposal, &VerificationConfig::default());
+        assert_eq!(detailed.verdict, verify_proposal(&proposal));
+        assert!(!detailed.verdict.pass && detailed.verdict.cycle_found);
+
+        let path = detailed.cycle_path.unwrap();
+        assert_eq!(path, vec!["cap", "total", "fee"]);
+        assert!(["fee", "cap", "total"]
+            .iter()
+            .all(|name| path.iter().any(|node| node == name)));
+
+        // No path without a cycle, nor for a graph too large to analyze
+        let acyclic = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"fee": {"value": 5}, "total": {"value": "$ref:fee"}}"#.to_string(),
+            "Fees".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        assert_eq!(
+            verify_proposal_with(&acyclic, &VerificationConfig::default()).cycle_path,
+            None
+        );
+        let capped = VerificationConfig {
+            max_graph_edges: 0,
+            ..Default::default()
+        };
+        let detailed = verify_proposal_with(&acyclic, &capped);
+        assert!(detailed.verdict.cycle_found && detailed.cycle_path.is_none());
+    }
+
+    #[test]
+    fn test_punctuation_laced_paradox_detected() {
+        let cases = [
+            "This proposal pass-es iff it fai.ls",
+            "This state\u{200B}ment is fal\u{200B}se",
+            "This passes only if it doesn't pass",
+            "Set x = not itself",
+        ];
+
+        for text in cases {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                "{}".to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let verdict = verify_proposal(&proposal);
+            assert!(verdict.paradox_found, "{}", text);
+
+            // Detection sees the same string the hash commits to
+            let payload = canonicalize(&proposal).unwrap();
+            assert!(detect_paradox(payload.normalized_text()), "{}", text);
+            assert_eq!(verify_canonical(&payload.bytes), verdict);
+        }
+
+        // The raw text evades the patterns
+        assert!(!detect_paradox(cases[0]));
+        assert!(!detect_paradox(cases[1]));
+    }
+
+    #[test]
+    fn test_high_confidence_paradox_still_fails_above_threshold() {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{}"#.to_string(),
+            "This statement is false".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let config = VerificationConfig {
+            paradox_confidence_threshold: 0.75,
+            ..Default::default()
+        };
+
+        let detailed = verify_proposal_with(&proposal, &config);
+        assert!(!detailed.verdict.pass);
+        assert!(detailed.verdict.paradox_found);
+        assert!(!detailed.should_review);
+    }
+
+    #[test]
+    fn test_metadata_stripping_ignores_complexity() {
+        let proposal = |ast: &str| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                "Transfer".to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        let bare = proposal(r#"{"action": "transfer"}"#);
+        let tagged = proposal(
+            r#"{"action": "transfer", "_meta": {"tool_version": "9.9.9", "ui": {"x": 1}}}"#,
+        );
+        let config = VerificationConfig {
+            canonicalize: CanonicalizeOptions::strip_metadata(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            verify_proposal_with(&bare, &config).verdict,
+            verify_proposal_with(&tagged, &config).verdict
+        );
+        assert_ne!(
+            verify_proposal(&bare).complexity_score,
+            verify_proposal(&tagged).complexity_score
+        );
+    }
+
+    #[test]
+    fn test_unknown_ref_strict_and_lenient() {
+        // `$refs:a` is a typo for `$ref:a`; the a <-> b cycle is invisible
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"a": {"value": "$ref:b"}, "b": {"value": "$refs:a"}}"#.to_string(),
+            "Two linked steps".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let strict = VerificationConfig {
+            reject_unknown_refs: true,
+            ..Default::default()
+        };
+
+        let lenient = verify_proposal_with(&proposal, &VerificationConfig::default());
+        assert!(lenient.verdict.pass);
+        assert_eq!(lenient.verdict, verify_proposal(&proposal));
+        assert_eq!(lenient.suspicious_references.len(), 1);
+
+        let detailed = verify_proposal_with(&proposal, &strict);
+        assert!(!detailed.verdict.pass);
+        assert_eq!(
+            detailed.failure_reason,
+            Some(FailureReason::UnknownReferences)
+        );
+        assert!(!detailed.verdict.cycle_found);
+        assert!(!detailed.verdict.paradox_found);
+        assert_eq!(
+            detailed.suspicious_references,
+            vec![SuspiciousReference {
+                path: "b.value".to_string(),
+                found: "$refs:a".to_string(),
+            }]
+        );
+
+        // Clean proposals are unaffected by strict mode
+        let clean = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "transfer", "amount": 100}"#.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        assert!(verify_proposal_with(&clean, &strict).verdict.pass);
+    }
+
+    #[test]
+    fn test_verify_canonical_matches_verify_proposal() {
+        let cases = [
+            (
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens to the community fund",
+            ),
+            (
+                r#"{"note": "a.b", "rate": 1.5}"#,
+                "Set the rate to 1.5 percent.",
+            ),
+            (
+                r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#,
+                "Cyclic logic",
+            ),
+            (r#"{}"#, "This proposal passes iff it fails."),
+            (r#"{}"#, "1. Clause 2 holds. 2. Clause 1 does not hold."),
+            (r#"[{"$ref": "[1]"}, {"$ref": "[0]"}]"#, "Indexed cycle"),
+            (r#"1.5"#, "A bare number"),
+        ];
+
+        for (ast_json, text) in cases {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast_json.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let payload = canonicalize(&proposal).unwrap();
+            assert_eq!(
+                verify_canonical(&payload.bytes),
+                verify_proposal(&proposal),
+                "{}",
+                ast_json
+            );
+        }
+
+        // Cross-clause contradictions are found from the payload alone
+        let clauses = Proposal::new(
+            "rTestAddress123".to_string(),
+            "{}".to_string(),
+            "1. Clause 2 holds. 2. Clause 1 does not hold.".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        assert!(verify_canonical(&canonicalize(&clauses).unwrap().bytes).paradox_found);
+    }
+
+    #[test]
+    fn test_verify_canonical_malformed_payload_fails() {
+        for payload in [&b""[..], b"no separator", b"{\"a\":1", b"{}.\xff"] {
+            let verdict = verify_canonical(payload);
+            assert!(!verdict.pass);
+            assert_eq!(verdict.complexity_score, 0);
+        }
+    }
+
+    #[test]
+    fn test_declared_class_advisory() {
+        let detailed = |ast: &str, text: &str| {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            verify_proposal_with(&proposal, &VerificationConfig::default())
+        };
+
+        // Correct self-declaration
+        let correct = detailed(
+            r#"{"action": "transfer", "decidability_class": "II"}"#,
+            "Transfer 100 tokens",
+        );
+        assert!(correct.verdict.pass);
+        assert_eq!(correct.declared_class, Some(DecidabilityClass::II));
+        assert_eq!(correct.class_mismatch, None);
+
+        // Missing declaration: defaults apply, nothing to compare
+        let missing = detailed(
+            r#"{"action": "conditional"}"#,
+            "This proposal passes iff it fails",
+        );
+        assert_eq!(missing.declared_class, None);
+        assert_eq!(missing.class_mismatch, None);
+
+        // Declared Class II, but a paradox implies Class III
+        let conflicting = detailed(
+            r#"{"action": "conditional", "decidability_class": "II"}"#,
+            "This proposal passes iff it fails",
+        );
+        assert!(conflicting.verdict.paradox_found);
+        assert_eq!(
+            conflicting.class_mismatch,
+            Some(ClassMismatch {
+                declared: DecidabilityClass::II,
+                detected: DecidabilityClass::III,
+            })
+        );
+    }
+
+    #[test]
+    fn test_banned_content_stage() {
+        let config = VerificationConfig {
+            banned_keywords: Some(BannedKeywordList::new(["drain treasury"])),
+            ..Default::default()
+        };
+        let proposal = |text: &str| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                r#"{"action": "transfer"}"#.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+
+        let banned = verify_proposal_with(&proposal("Then DRAIN, treasury!"), &config);
+        assert!(!banned.verdict.pass);
+        assert!(!banned.verdict.paradox_found && !banned.verdict.cycle_found);
+        assert_eq!(banned.banned_content, Some("drain treasury".to_string()));
+        assert_eq!(banned.failure_reason, Some(FailureReason::BannedContent));
+
+        let clean = verify_proposal_with(&proposal("Fund the treasury"), &config);
+        assert!(clean.verdict.pass);
+        assert_eq!(clean.banned_content, None);
+
+        // Not enforced unless configured
+        assert!(verify_proposal(&proposal("Then DRAIN, treasury!")).pass);
+    }
+
+    #[test]
+    fn test_complexity_algorithm_opt_in() {
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "transfer", "amount": 100}"#.to_string(),
+            "Transfer 100 tokens to the community fund".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let payload = canonicalize(&proposal).unwrap().bytes;
+        let config = VerificationConfig {
+            complexity_algorithm: ComplexityAlgorithm::ZlibDictionary,
+            ..Default::default()
+        };
+
+        let standard = verify_proposal(&proposal);
+        let dictionary = verify_proposal_with(&proposal, &config).verdict;
+        assert_eq!(standard.complexity_score, compute_complexity(&payload));
+        assert_eq!(
+            dictionary.complexity_score,
+            compute_complexity_with_dictionary(&payload, DEFAULT_COMPLEXITY_DICTIONARY)
+        );
+        assert!(dictionary.complexity_score < standard.complexity_score);
+    }
+
+    #[test]
+    fn test_l0_proposals_fail_before_checks() {
+        let proposal = |layer| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                r#"{"action": "transfer", "amount": 100}"#.to_string(),
+                "Transfer 100 tokens to the community fund".to_string(),
+                layer,
+            )
+        };
+
+        let l0 = proposal(GovernanceLayer::L0Immutable);
+        let verdict = verify_proposal(&l0);
+        assert!(!verdict.pass);
+        assert_eq!(verdict, ChannelAVerdict::fail(0, false, false));
+        let detailed = verify_proposal_with(&l0, &VerificationConfig::default());
+        assert_eq!(
+            detailed.failure_reason,
+            Some(FailureReason::L0NotTargetable)
+        );
+
+        // Rejected even when the AST would not parse
+        let mut broken = l0.clone();
+        broken.logic_ast = "{oops".to_string();
+        assert_eq!(
+            verify_proposal_with(&broken, &VerificationConfig::default()).failure_reason,
+            Some(FailureReason::L0NotTargetable)
+        );
+
+        let ast: Value = serde_json::from_str(&l0.logic_ast).unwrap();
+        assert!(!verify_value(&ast, &l0.text, GovernanceLayer::L0Immutable).pass);
+
+        for layer in [
+            GovernanceLayer::L1Constitutional,
+            GovernanceLayer::L2Operational,
+            GovernanceLayer::L3Execution,
+        ] {
+            let detailed = verify_proposal_with(&proposal(layer), &VerificationConfig::default());
+            assert!(detailed.verdict.pass, "{:?}", layer);
+            assert_eq!(detailed.failure_reason, None);
+        }
+    }
+
+    #[test]
+    fn test_malformed_ast_reports_canonicalization_error() {
+        for ast in ["{oops", r#"{"a": 1} {"b": 2}"#, r#"{"a": "\ud800"}"#, ""] {
+            let proposal = Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                "Transfer".to_string(),
+                GovernanceLayer::L2Operational,
+            );
+            let detailed = verify_proposal_with(&proposal, &VerificationConfig::default());
+            assert_eq!(
+                detailed.failure_reason,
+                Some(FailureReason::CanonicalizationError),
+                "{ast}"
+            );
+            // The on-chain verdict is unchanged
+            assert_eq!(detailed.verdict, ChannelAVerdict::fail(0, false, false));
+        }
+    }
+
+    #[test]
+    fn test_failure_reason_names_failing_check() {
+        let proposal = |ast: &str, text: &str| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        let reason = |ast: &str, text: &str| {
+            verify_proposal_with(&proposal(ast, text), &VerificationConfig::default())
+                .failure_reason
+        };
+
+        assert_eq!(
+            reason(r#"{"action": "transfer"}"#, "Transfer 100 tokens"),
+            None
+        );
+
+        // A tight limit fails the same proposal on complexity alone
+        let tight = VerificationConfig {
+            complexity_limits: ComplexityLimits::default()
+                .with_limit(GovernanceLayer::L2Operational, 1),
+            ..Default::default()
+        };
+        let transfer = proposal(r#"{"action": "transfer"}"#, "Transfer 100 tokens");
+        assert_eq!(
+            verify_proposal_with(&transfer, &tight).failure_reason,
+            Some(FailureReason::ComplexityExceeded)
+        );
+        assert_eq!(
+            reason("{}", "This proposal passes iff it fails"),
+            Some(FailureReason::ParadoxFound)
+        );
+        assert_eq!(
+            reason(r#"{"a": {"value": "$ref:a"}}"#, "Loop"),
+            Some(FailureReason::CycleFound)
+        );
+
+        // Paradox precedes cycles in pipeline order
+        assert_eq!(
+            reason(r#"{"a": {"value": "$ref:a"}}"#, "This statement is false"),
+            Some(FailureReason::ParadoxFound)
+        );
+    }
+
+    #[test]
+    fn test_complexity_limit_depends_on_layer() {
+        let proposal = |layer| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                r#"{"action": "transfer", "amount": 100, "to": "community_fund"}"#.to_string(),
+                "Transfer 100 tokens to the community fund".to_string(),
+                layer,
+            )
+        };
+        let score = verify_proposal(&proposal(GovernanceLayer::L2Operational)).complexity_score;
+        let config = VerificationConfig {
+            complexity_limits: ComplexityLimits::default()
+                .with_limit(GovernanceLayer::L1Constitutional, score - 1)
+                .with_limit(GovernanceLayer::L3Execution, score),
+            ..Default::default()
+        };
+
+        let l3 = verify_proposal_with(&proposal(GovernanceLayer::L3Execution), &config);
+        assert!(l3.verdict.pass);
+        assert_eq!(l3.verdict.complexity_score, score);
+
+        let l1 = verify_proposal_with(&proposal(GovernanceLayer::L1Constitutional), &config);
+        assert!(!l1.verdict.pass);
+        assert_eq!(l1.verdict, ChannelAVerdict::fail(score, false, false));
+        assert_eq!(l1.failure_reason, Some(FailureReason::ComplexityExceeded));
+
+        // The default limits match the spec for every layer
+        assert_eq!(
+            verify_proposal_with(&proposal(GovernanceLayer::L2Operational), &config).verdict,
+            verify_proposal(&proposal(GovernanceLayer::L2Operational))
+        );
+    }
+
+    #[test]
+    fn test_config_knobs_change_verdict() {
+        let proposal = |ast: &str, text: &str| {
+            Proposal::new(
+                "rTestAddress123".to_string(),
+                ast.to_string(),
+                text.to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        let chained = proposal(
+            r#"{"a": {"value": 1}, "b": {"value": "$ref:a"}, "c": {"depends_on": ["a", "b"]}}"#,
+            "Chain the fees",
+        );
+        let looping = proposal(
+            r#"{"action": "pay"}"#,
+            "The fund pays out only if it does not pay out",
+        );
+        let baseline = [verify_proposal(&chained), verify_proposal(&looping)];
+        assert!(baseline.iter().all(|verdict| verdict.pass));
+
+        // Graph size cap: three edges do not fit in two, which fails closed as a cycle
+        let capped = VerificationConfig {
+            max_graph_edges: 2,
+            ..Default::default()
+        };
+        let verdict = verify_proposal_with_config(&chained, &capped);
+        assert!(!verdict.pass && verdict.cycle_found);
+        assert!(
+            verify_proposal_with_config(
+                &chained,
+                &VerificationConfig {
+                    max_graph_edges: 3,
+                    ..Default::default()
+                }
+            )
+            .pass
+        );
+        for capped in [
+            VerificationConfig {
+                max_graph_nodes: 2,
+                ..Default::default()
+            },
+            VerificationConfig {
+                max_ast_depth: 2,
+                ..Default::default()
+            },
+        ] {
+            let verdict = verify_proposal_with_config(&chained, &capped);
+            assert!(!verdict.pass && verdict.cycle_found);
+        }
+        assert!(
+            verify_proposal_with_config(
+                &chained,
+                &VerificationConfig {
+                    max_ast_depth: 3,
+                    ..Default::default()
+                }
+            )
+            .pass
+        );
+
+        // Paradox rule set: an added domain rule fires, and the built-ins still apply
+        let mut rules = ParadoxRuleset::default();
+        rules.patterns.push(ParadoxRule {
+            id: "payout_loop".to_string(),
+            pattern: r"(?i)pays out only if it does not pay out".to_string(),
+        });
+        let extended = VerificationConfig {
+            paradox_rules: Some(rules.compile().unwrap()),
+            ..Default::default()
+        };
+        let detailed = verify_proposal_with(&looping, &extended);
+        assert!(detailed.verdict.paradox_found);
+        assert_eq!(detailed.paradox_class, Some(ParadoxClass::Custom));
+        assert_eq!(
+            verify_proposal_with(&proposal("{}", "This statement is false"), &extended)
+                .paradox_class,
+            Some(ParadoxClass::Liar)
+        );
+
+        // A configuration with an invalid rule does not load
+        let mut config = serde_json::to_value(&extended).unwrap();
+        config["paradox_rules"]["patterns"][0]["pattern"] = "(".into();
+        assert!(serde_json::from_value::<VerificationConfig>(config).is_err());
+
+        // The default rule set reproduces the built-in patterns
+        let explicit = VerificationConfig {
+            paradox_rules: Some(ParadoxRuleset::default().compile().unwrap()),
+            ..Default::default()
+        };
+        for text in [
+            "If this passes then the old fee schedule is rejected",
+            "This statement is false",
+            "Fund it",
+        ] {
+            let p = proposal("{}", text);
+            assert_eq!(
+                verify_proposal_with(&p, &explicit),
+                verify_proposal_with(&p, &VerificationConfig::default()),
+                "{text}"
+            );
+        }
+
+        // A rehearsed version bump still verifies
+        let next = VerificationConfig {
+            canonical_form_version: config::CANONICAL_FORM_VERSION + 1,
+            ..Default::default()
+        };
+        assert!(verify_proposal_with_config(&chained, &next).pass);
+
+        // None of this leaks into the default pipeline
+        assert_eq!(
+            [verify_proposal(&chained), verify_proposal(&looping)],
+            baseline
+        );
+        assert_eq!(
+            verify_proposal_with_config(&chained, &VerifyConfig::default()),
+            baseline[0]
+        );
+    }
 }
@@ -5,44 +5,213 @@
 //! - Engine: re2 regular expression matching on the normalized text
 //! - Patterns detect Gödelian self-referential paradoxes
 //!
+//! Channel A matches against the normalized text of the canonical payload
+//! (`CanonicalPayload::normalized_text`), not the raw text, so punctuation
+//! or zero-width characters spliced into words ("pass-es", "fai.ls")
+//! cannot break a match. Patterns must therefore also match the
+//! apostrophe-free forms normalization leaves ("doesnt", "dont"), and
+//! cannot rely on punctuation such as `=`.
+//!
+//! The built-in patterns are also compiled into one `RegexSet`, so
+//! detection is a single scan of the text however many patterns there
+//! are. Match positions are only searched for with the individual
+//! patterns the set reports as matching.
+//!
+//! A `ParadoxDetector` is such a set. The default holds the built-in
+//! patterns and backs `detect_paradox`; `ParadoxDetector::with_patterns`
+//! appends a DAO's own patterns (e.g. self-reference in financial rules)
+//! while keeping the single scan.
+//!
+//! # Pattern IDs
+//!
+//! Every built-in pattern has a stable string ID. Persisted references
+//! (records, logs, fraud proofs) should use the ID: the positional index
+//! is kept for convenience but changes if the list is ever reordered.
+//! IDs are never reused for a different pattern.
+//!
+//! # Paradox Classes
+//!
+//! Each pattern also has a `ParadoxClass`, so a pipeline can treat kinds
+//! of paradox differently: a liar paradox is genuinely undecidable, while
+//! a negation loop is often a drafting error the author can fix. Rules
+//! added through a `ParadoxRuleset` that are not built-in are `Custom`.
+//!
 //! # Test Vector
 //!
 //! - Input: "This proposal passes iff it fails."
 //! - Expected Output: paradox_found = true
 
 use lazy_static::lazy_static;
-use regex::Regex;
+use regex::{Regex, RegexSet};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Kind of paradox a pattern detects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ParadoxClass {
+    /// A statement asserting its own falsity, directly or via "iff"
+    Liar,
+    /// "If this is true then it is false"
+    ConditionalSelfReference,
+    /// Passing conditioned on not passing, or a definition negating itself
+    NegationLoop,
+    /// A set defined over the members that do not contain themselves
+    RussellVariant,
+    /// A rule that is not built-in
+    Custom,
+}
+
+impl ParadoxClass {
+    /// Class of the built-in pattern with this ID, or `Custom`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::ParadoxClass;
+    ///
+    /// assert_eq!(ParadoxClass::for_pattern_id("russell_set"), ParadoxClass::RussellVariant);
+    /// assert_eq!(ParadoxClass::for_pattern_id("financial_loop"), ParadoxClass::Custom);
+    /// ```
+    pub fn for_pattern_id(id: &str) -> Self {
+        PARADOX_PATTERNS
+            .iter()
+            .find(|p| p.id == id)
+            .map_or(ParadoxClass::Custom, |p| p.class)
+    }
+}
+
+/// A built-in paradox pattern and how reliably a match indicates a paradox
+struct ParadoxPattern {
+    /// Stable identifier, independent of position in the list
+    id: &'static str,
+    class: ParadoxClass,
+    regex: Regex,
+    /// Confidence in (0.0, 1.0] that a match is a genuine paradox
+    confidence: f64,
+}
+
+impl ParadoxPattern {
+    fn new(id: &'static str, class: ParadoxClass, pattern: &str, confidence: f64) -> Self {
+        Self {
+            id,
+            class,
+            regex: Regex::new(pattern).unwrap(),
+            confidence,
+        }
+    }
+}
 
 lazy_static! {
     /// Paradox detection patterns from the spec
     ///
     /// These patterns detect self-referential logical paradoxes that would
-    /// make a proposal undecidable or logically inconsistent.
-    static ref PARADOX_PATTERNS: Vec<Regex> = vec![
+    /// make a proposal undecidable or logically inconsistent. Broad patterns
+    /// that also match ordinary conditionals carry a lower confidence.
+    static ref PARADOX_PATTERNS: Vec<ParadoxPattern> = vec![
         // Pattern 1: "this proposal/motion passes/fails iff fails/passes"
         // Matches: "This proposal passes iff it fails"
-        Regex::new(r"(?i)(this proposal|the motion|this rule|this amendment).*(passes|fails|is true|is false|succeeds|is rejected)\s+(iff|if and only if)\s+.*(fails|passes|is false|is true|is rejected|succeeds)").unwrap(),
+        ParadoxPattern::new("iff_self_reference", ParadoxClass::Liar, r"(?i)(this proposal|the motion|this rule|this amendment).*(passes|fails|is true|is false|succeeds|is rejected)\s+(iff|if and only if)\s+.*(fails|passes|is false|is true|is rejected|succeeds)", 1.0),
 
         // Pattern 2: "this rule/statement is false"
         // Classic liar paradox
-        Regex::new(r"(?i)(this rule|this statement|the following statement|this proposal)\s+(is|are)\s+false").unwrap(),
+        ParadoxPattern::new("liar", ParadoxClass::Liar, r"(?i)(this rule|this statement|the following statement|this proposal)\s+(is|are)\s+false", 1.0),
 
         // Pattern 3: Conditional self-reference
         // "if this is true then it is false"
-        Regex::new(r"(?i)if\s+(this|it).*(true|passes|succeeds).*then.*(false|fails|is rejected)").unwrap(),
+        // Low confidence: also matches "if this passes then the old rule is rejected"
+        ParadoxPattern::new("conditional_self_reference", ParadoxClass::ConditionalSelfReference, r"(?i)if\s+(this|it).*(true|passes|succeeds).*then.*(false|fails|is rejected)", 0.6),
 
         // Pattern 4: Negation loops
         // "this passes only if it doesn't pass"
-        Regex::new(r"(?i)(this|it).*(passes|succeeds|is approved)\s+(only if|unless)\s+.*(doesn't|does not|doesn't|not)\s*(pass|succeed|approved)").unwrap(),
+        ParadoxPattern::new("negation_loop", ParadoxClass::NegationLoop, r"(?i)(this|it).*(passes|succeeds|is approved)\s+(only if|unless)\s+.*(doesn'?t|does not|not)\s*(pass|succeed|approved)", 0.8),
 
         // Pattern 5: Self-contradictory definitions
-        // "define X as not-X"
-        Regex::new(r"(?i)(define|let|set)\s+(\w+)\s+(as|to be|equal to|=)\s+(not|the opposite of|the negation of)\s+\2").unwrap(),
+        // "define X as the negation of itself"
+        // (re2 semantics: no backreferences, so the self-reference is spelled out)
+        ParadoxPattern::new("self_negating_definition", ParadoxClass::NegationLoop, r"(?i)(define|let|set)\s+(\w+)\s+((as|to be|equal to|=)\s+)?(not|the opposite of|the negation of)\s+(itself|its own value)", 0.9),
 
         // Pattern 6: Russell's paradox variants
         // "the set of all proposals that don't include themselves"
-        Regex::new(r"(?i)(set|collection|group)\s+of\s+(all)?\s*(proposals?|rules?|statements?)\s+that\s+(don't|do not|doesn't)\s+(include|contain|reference)\s+(themselves|itself)").unwrap(),
+        ParadoxPattern::new("russell_set", ParadoxClass::RussellVariant, r"(?i)(set|collection|group)\s+of\s+(all)?\s*(proposals?|rules?|statements?)\s+that\s+(don'?t|do not|doesn'?t)\s+(include|contain|reference)\s+(themselves|itself)", 0.9),
+
+        // Pattern 7: Curry's paradox
+        // "if this proposal is valid, then the treasury is drained"
+        // Only a conditional on the proposal's own truth or validity, so
+        // "if this proposal passes then ..." and "if the vote passes, ..." are not flagged
+        ParadoxPattern::new("curry", ParadoxClass::ConditionalSelfReference, r"(?i)\bif\s+(this|the present)\s+(proposal|rule|statement|sentence|amendment|motion)\s+is\s+(true|valid)\s*,?\s+then\s+\w", 0.9),
+
+        // Pattern 8: Fixed point of negation
+        // "this proposal is equivalent to its own rejection"
+        ParadoxPattern::new("equivalent_to_own_negation", ParadoxClass::Liar, r"(?i)(this proposal|this rule|this statement|this amendment|the motion|it)\s+(is equivalent to|is equal to|is identical to|amounts to|means)\s+(its own\s+(negation|rejection|denial|opposite|falsity)|the\s+(negation|rejection|denial|opposite)\s+of\s+itself)", 1.0),
+
+        // Pattern 9: Holding exactly when not holding
+        // "this rule holds exactly when it does not hold"
+        ParadoxPattern::new("holds_exactly_when_not", ParadoxClass::Liar, r"(?i)(this|it)\b.*\b(holds|applies|is true|is valid|is in force)\s+(exactly|precisely|just)\s+(when|if)\s+(it|this\s+\w+)\s+(doesn'?t|does not|is not|isn'?t)\s+(hold|apply|true|valid|in force)", 1.0),
     ];
+
+    /// `PARADOX_PATTERNS` as one set, indexed in the same order
+    static ref DEFAULT_DETECTOR: ParadoxDetector = ParadoxDetector {
+        set: RegexSet::new(PARADOX_PATTERNS.iter().map(|p| p.regex.as_str())).unwrap(),
+    };
+}
+
+/// Paradox patterns compiled into a single `RegexSet`
+///
+/// Pattern indices follow `get_paradox_patterns` for the built-ins, then
+/// the custom patterns in the order given. Build a detector once and reuse
+/// it: construction compiles every pattern.
+#[derive(Debug, Clone)]
+pub struct ParadoxDetector {
+    set: RegexSet,
+}
+
+impl Default for ParadoxDetector {
+    /// The built-in patterns only
+    fn default() -> Self {
+        DEFAULT_DETECTOR.clone()
+    }
+}
+
+impl ParadoxDetector {
+    /// The built-in patterns followed by `custom`
+    ///
+    /// Returns an error if any custom pattern is not a valid regex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::{get_paradox_patterns, ParadoxDetector};
+    ///
+    /// let detector = ParadoxDetector::with_patterns([r"(?i)pays out only if it does not pay out"]).unwrap();
+    /// assert!(detector.detect("The fund pays out only if it does not pay out"));
+    /// assert_eq!(detector.matches("The fund pays out only if it does not pay out"), [get_paradox_patterns().len()]);
+    /// assert!(detector.detect("This statement is false"));
+    ///
+    /// assert!(ParadoxDetector::with_patterns(["(unclosed"]).is_err());
+    /// ```
+    pub fn with_patterns<I, S>(custom: I) -> Result<Self, regex::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let builtin = PARADOX_PATTERNS
+            .iter()
+            .map(|p| p.regex.as_str().to_string());
+        let custom = custom.into_iter().map(|p| p.as_ref().to_string());
+        Ok(Self {
+            set: RegexSet::new(builtin.chain(custom))?,
+        })
+    }
+
+    /// Whether any pattern matches `text`
+    pub fn detect(&self, text: &str) -> bool {
+        self.set.is_match(text)
+    }
+
+    /// Indices of the patterns matching `text`, ascending
+    pub fn matches(&self, text: &str) -> Vec<usize> {
+        self.set.matches(text).into_iter().collect()
+    }
 }
 
 /// Detect if a proposal text contains logical paradoxes
@@ -60,28 +229,411 @@ lazy_static! {
 /// assert!(!detect_paradox("Transfer 100 tokens to the community fund"));
 /// ```
 pub fn detect_paradox(text: &str) -> bool {
-    PARADOX_PATTERNS.iter().any(|pattern| pattern.is_match(text))
+    DEFAULT_DETECTOR.detect(text)
+}
+
+/// Highest confidence among the built-in patterns matching `text`
+///
+/// `None` when no pattern matches, so `detect_paradox(text)` is equivalent
+/// to `paradox_confidence(text).is_some()`.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::paradox_confidence;
+///
+/// assert_eq!(paradox_confidence("This statement is false"), Some(1.0));
+/// assert_eq!(paradox_confidence("Transfer 100 tokens"), None);
+/// ```
+pub fn paradox_confidence(text: &str) -> Option<f64> {
+    strongest_match(text).map(|(confidence, _)| confidence)
+}
+
+/// Class of the highest-confidence built-in pattern matching `text`
+///
+/// Ties go to the pattern listed first. `None` when no pattern matches.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{paradox_class, ParadoxClass};
+///
+/// assert_eq!(paradox_class("This statement is false"), Some(ParadoxClass::Liar));
+/// assert_eq!(paradox_class("This passes only if it doesn't pass"), Some(ParadoxClass::NegationLoop));
+/// assert_eq!(paradox_class("Transfer 100 tokens"), None);
+/// ```
+pub fn paradox_class(text: &str) -> Option<ParadoxClass> {
+    strongest_match(text).map(|(_, class)| class)
+}
+
+/// Confidence and class of the strongest match, first pattern winning ties
+pub(crate) fn strongest_match(text: &str) -> Option<(f64, ParadoxClass)> {
+    matching_patterns(text).map(|(_, pattern)| pattern).fold(
+        None,
+        |best: Option<(f64, ParadoxClass)>, pattern| match best {
+            Some((confidence, _)) if confidence >= pattern.confidence => best,
+            _ => Some((pattern.confidence, pattern.class)),
+        },
+    )
+}
+
+/// Force compilation of the paradox patterns
+///
+/// `lazy_static` guarantees the initializer runs exactly once even when
+/// several threads race; calling this again is a no-op.
+pub(crate) fn warm_up() {
+    lazy_static::initialize(&PARADOX_PATTERNS);
+    lazy_static::initialize(&DEFAULT_DETECTOR);
+}
+
+/// Built-in patterns matching `text` with their indices, in list order
+fn matching_patterns(text: &str) -> impl Iterator<Item = (usize, &'static ParadoxPattern)> {
+    DEFAULT_DETECTOR
+        .set
+        .matches(text)
+        .into_iter()
+        .map(|i| (i, &PARADOX_PATTERNS[i]))
 }
 
 /// Get the list of paradox patterns for debugging/display
 pub fn get_paradox_patterns() -> Vec<String> {
-    PARADOX_PATTERNS.iter().map(|p| p.as_str().to_string()).collect()
+    PARADOX_PATTERNS
+        .iter()
+        .map(|p| p.regex.as_str().to_string())
+        .collect()
+}
+
+/// Stable IDs of the built-in patterns, in `get_paradox_patterns` order
+pub fn get_paradox_pattern_ids() -> Vec<&'static str> {
+    PARADOX_PATTERNS.iter().map(|p| p.id).collect()
+}
+
+/// A built-in paradox pattern that matched a text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParadoxMatch {
+    /// Stable ID of the pattern; use this in anything persisted
+    pub pattern_id: String,
+    /// Kind of paradox the pattern detects
+    pub class: ParadoxClass,
+    /// Position of the pattern in `get_paradox_patterns` (not stable across versions)
+    pub pattern_index: usize,
+    /// Text the pattern matched
+    pub matched: String,
 }
 
 /// Check which specific paradox pattern(s) matched
-pub fn find_paradox_matches(text: &str) -> Vec<(usize, String)> {
-    PARADOX_PATTERNS
-        .iter()
-        .enumerate()
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::find_paradox_matches;
+///
+/// let matches = find_paradox_matches("This statement is false");
+/// assert_eq!(matches[0].pattern_id, "liar");
+/// ```
+pub fn find_paradox_matches(text: &str) -> Vec<ParadoxMatch> {
+    matching_patterns(text)
         .filter_map(|(i, pattern)| {
-            pattern.find(text).map(|m| (i, m.as_str().to_string()))
+            pattern.regex.find(text).map(|m| ParadoxMatch {
+                pattern_id: pattern.id.to_string(),
+                class: pattern.class,
+                pattern_index: i,
+                matched: m.as_str().to_string(),
+            })
         })
         .collect()
 }
 
+/// A single named paradox rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParadoxRule {
+    /// Identifier used to reference the rule across amendments
+    pub id: String,
+    /// Regular expression (regex crate / re2 syntax)
+    pub pattern: String,
+}
+
+/// A governance-amendable set of paradox rules
+///
+/// Rulesets are plain data so they can be proposed, diffed and stored.
+/// Whitelist entries are phrases that exempt a match: a pattern match whose
+/// matched text contains a whitelisted phrase (case-insensitive) is ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParadoxRuleset {
+    /// Paradox patterns, keyed by rule ID
+    pub patterns: Vec<ParadoxRule>,
+    /// Phrases exempt from paradox detection
+    pub whitelist: Vec<String>,
+}
+
+impl Default for ParadoxRuleset {
+    /// The built-in patterns, under their stable IDs, with an empty whitelist
+    fn default() -> Self {
+        Self {
+            patterns: PARADOX_PATTERNS
+                .iter()
+                .map(|p| ParadoxRule {
+                    id: p.id.to_string(),
+                    pattern: p.regex.as_str().to_string(),
+                })
+                .collect(),
+            whitelist: Vec::new(),
+        }
+    }
+}
+
+impl ParadoxRuleset {
+    /// Compile every pattern once, for checking many texts
+    ///
+    /// Returns an error if any pattern is not a valid regex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::channel_a::ParadoxRuleset;
+    ///
+    /// let rules = ParadoxRuleset::default().compile().unwrap();
+    /// assert!(rules.detect("This proposal passes iff it fails"));
+    /// assert!(!rules.detect("Transfer 100 tokens"));
+    /// ```
+    pub fn compile(&self) -> Result<CompiledRuleset, regex::Error> {
+        Ok(CompiledRuleset {
+            set: RegexSet::new(self.patterns.iter().map(|rule| &rule.pattern))?,
+            regexes: self
+                .patterns
+                .iter()
+                .map(|rule| Regex::new(&rule.pattern))
+                .collect::<Result<_, _>>()?,
+            strengths: self
+                .patterns
+                .iter()
+                .map(
+                    |rule| match PARADOX_PATTERNS.iter().find(|p| p.id == rule.id) {
+                        Some(builtin) => (builtin.confidence, builtin.class),
+                        None => (1.0, ParadoxClass::Custom),
+                    },
+                )
+                .collect(),
+            whitelist: self.whitelist.iter().map(|w| w.to_lowercase()).collect(),
+            source: self.clone(),
+        })
+    }
+
+    /// Compute what changes going from `self` to `other`
+    ///
+    /// Rules are matched by ID: an ID present in both with a different
+    /// pattern is reported as changed. All lists are sorted.
+    pub fn diff(&self, other: &ParadoxRuleset) -> RulesetDiff {
+        let old: BTreeMap<&str, &str> = self
+            .patterns
+            .iter()
+            .map(|r| (r.id.as_str(), r.pattern.as_str()))
+            .collect();
+        let new: BTreeMap<&str, &str> = other
+            .patterns
+            .iter()
+            .map(|r| (r.id.as_str(), r.pattern.as_str()))
+            .collect();
+
+        let mut diff = RulesetDiff::default();
+        for (id, pattern) in &new {
+            match old.get(id) {
+                None => diff.added.push(id.to_string()),
+                Some(prev) if prev != pattern => diff.changed.push(id.to_string()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|id| !new.contains_key(*id))
+            .map(|id| id.to_string())
+            .collect();
+
+        diff.whitelist_added = other
+            .whitelist
+            .iter()
+            .filter(|w| !self.whitelist.contains(w))
+            .cloned()
+            .collect();
+        diff.whitelist_removed = self
+            .whitelist
+            .iter()
+            .filter(|w| !other.whitelist.contains(w))
+            .cloned()
+            .collect();
+        diff.whitelist_added.sort();
+        diff.whitelist_removed.sort();
+        diff
+    }
+}
+
+/// A ruleset with its patterns compiled
+///
+/// Build one with `ParadoxRuleset::compile` and reuse it: compiling is far
+/// slower than matching. Like `ParadoxDetector`, all patterns are scanned
+/// for at once.
+///
+/// Serializes as its `ParadoxRuleset`; deserializing a ruleset with an
+/// invalid pattern is an error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "ParadoxRuleset", into = "ParadoxRuleset")]
+pub struct CompiledRuleset {
+    /// Every pattern, in rule order
+    set: RegexSet,
+    /// The same patterns individually, to find where a match lies
+    regexes: Vec<Regex>,
+    /// Confidence and class of each rule
+    strengths: Vec<(f64, ParadoxClass)>,
+    /// Whitelist phrases, lowercased
+    whitelist: Vec<String>,
+    /// The ruleset as configured
+    source: ParadoxRuleset,
+}
+
+impl CompiledRuleset {
+    /// The ruleset this was compiled from
+    pub fn ruleset(&self) -> &ParadoxRuleset {
+        &self.source
+    }
+
+    /// Whether any rule matches `text` outside the whitelist
+    pub fn detect(&self, text: &str) -> bool {
+        self.matching_rules(text).next().is_some()
+    }
+
+    /// Confidence and class of the strongest unexempted match, first rule winning ties
+    ///
+    /// A rule sharing an ID with a built-in pattern takes that pattern's
+    /// confidence and class; any other rule has confidence 1.0 and class
+    /// `Custom`.
+    pub(crate) fn strongest_match(&self, text: &str) -> Option<(f64, ParadoxClass)> {
+        self.matching_rules(text).map(|i| self.strengths[i]).fold(
+            None,
+            |best: Option<(f64, ParadoxClass)>, (confidence, class)| match best {
+                Some((best_confidence, _)) if best_confidence >= confidence => best,
+                _ => Some((confidence, class)),
+            },
+        )
+    }
+
+    /// Indices of the rules with an unexempted match in `text`, ascending
+    fn matching_rules<'a>(&'a self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.set
+            .matches(text)
+            .into_iter()
+            .filter(move |&i| self.is_unexempted_match(&self.regexes[i], text))
+    }
+
+    /// Whether `regex` matches `text` somewhere no whitelist phrase covers
+    fn is_unexempted_match(&self, regex: &Regex, text: &str) -> bool {
+        regex.find_iter(text).any(|m| {
+            let matched = m.as_str().to_lowercase();
+            !self.whitelist.iter().any(|w| matched.contains(w.as_str()))
+        })
+    }
+}
+
+impl PartialEq for CompiledRuleset {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl TryFrom<ParadoxRuleset> for CompiledRuleset {
+    type Error = regex::Error;
+
+    fn try_from(ruleset: ParadoxRuleset) -> Result<Self, Self::Error> {
+        ruleset.compile()
+    }
+}
+
+impl From<CompiledRuleset> for ParadoxRuleset {
+    fn from(compiled: CompiledRuleset) -> Self {
+        compiled.source
+    }
+}
+
+/// Texts whose paradox verdict changes between two rulesets
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImpactReport {
+    /// Texts the new ruleset flags and the old one does not (corpus order)
+    pub newly_flagged: Vec<String>,
+    /// Texts the old ruleset flags and the new one does not (corpus order)
+    pub newly_unflagged: Vec<String>,
+}
+
+/// Measure how a ruleset amendment changes verdicts over a corpus
+///
+/// Run against historical proposal texts, `newly_flagged` estimates the
+/// false-positive risk of a new or broadened pattern and `newly_unflagged`
+/// shows what a narrowed pattern or whitelist entry stops catching.
+/// Returns an error if either ruleset has an invalid pattern.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{ruleset_impact, ParadoxRule, ParadoxRuleset};
+///
+/// let old = ParadoxRuleset::default();
+/// let mut new = old.clone();
+/// new.patterns.push(ParadoxRule {
+///     id: "financial_loop".to_string(),
+///     pattern: r"(?i)pays out only if it does not pay out".to_string(),
+/// });
+///
+/// let report = ruleset_impact(&new, &old, &["The fund pays out only if it does not pay out"]).unwrap();
+/// assert_eq!(report.newly_flagged.len(), 1);
+/// assert!(report.newly_unflagged.is_empty());
+/// ```
+pub fn ruleset_impact(
+    new_ruleset: &ParadoxRuleset,
+    old_ruleset: &ParadoxRuleset,
+    corpus: &[&str],
+) -> Result<ImpactReport, regex::Error> {
+    let new = new_ruleset.compile()?;
+    let old = old_ruleset.compile()?;
+
+    let mut report = ImpactReport::default();
+    for text in corpus {
+        match (old.detect(text), new.detect(text)) {
+            (false, true) => report.newly_flagged.push(text.to_string()),
+            (true, false) => report.newly_unflagged.push(text.to_string()),
+            _ => {}
+        }
+    }
+    Ok(report)
+}
+
+/// Structured difference between two paradox rulesets
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RulesetDiff {
+    /// Rule IDs present only in the new ruleset
+    pub added: Vec<String>,
+    /// Rule IDs present only in the old ruleset
+    pub removed: Vec<String>,
+    /// Rule IDs whose pattern changed
+    pub changed: Vec<String>,
+    /// Whitelist phrases added
+    pub whitelist_added: Vec<String>,
+    /// Whitelist phrases removed
+    pub whitelist_removed: Vec<String>,
+}
+
+impl RulesetDiff {
+    /// Whether the two rulesets are identical
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.whitelist_added.is_empty()
+            && self.whitelist_removed.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::channel_a::normalize_text;
 
     #[test]
     fn test_spec_test_vector() {
@@ -127,11 +679,280 @@ mod tests {
     fn test_find_matches() {
         let matches = find_paradox_matches("This proposal passes iff it fails");
         assert!(!matches.is_empty());
+        assert_eq!(matches[0].pattern_id, "iff_self_reference");
+        assert_eq!(matches[0].pattern_index, 0);
 
         let matches = find_paradox_matches("Normal proposal text");
         assert!(matches.is_empty());
     }
 
+    #[test]
+    fn test_pattern_ids_are_stable() {
+        // Persisted references depend on these; never change or reuse an ID
+        let expected = [
+            (
+                "iff_self_reference",
+                "(?i)(this proposal|the motion|this rule|this amendment)",
+            ),
+            (
+                "liar",
+                "(?i)(this rule|this statement|the following statement|this proposal)",
+            ),
+            ("conditional_self_reference", r"(?i)if\s+(this|it)"),
+            (
+                "negation_loop",
+                "(?i)(this|it).*(passes|succeeds|is approved)",
+            ),
+            ("self_negating_definition", r"(?i)(define|let|set)\s+"),
+            ("russell_set", r"(?i)(set|collection|group)\s+of"),
+            ("curry", r"(?i)\bif\s+(this|the present)"),
+            (
+                "equivalent_to_own_negation",
+                "(?i)(this proposal|this rule|this statement|this amendment|the motion|it)",
+            ),
+            ("holds_exactly_when_not", r"(?i)(this|it)\b.*\b(holds"),
+        ];
+
+        let patterns = get_paradox_patterns();
+        assert_eq!(get_paradox_pattern_ids(), expected.map(|(id, _)| id));
+        for (pattern, (id, prefix)) in patterns.iter().zip(expected) {
+            assert!(
+                pattern.starts_with(prefix),
+                "{} no longer names {}",
+                id,
+                pattern
+            );
+        }
+
+        let ruleset = ParadoxRuleset::default();
+        assert!(ruleset
+            .patterns
+            .iter()
+            .map(|r| r.id.as_str())
+            .eq(expected.map(|(id, _)| id)));
+    }
+
+    #[test]
+    fn test_pattern_classes() {
+        let expected = [
+            ("iff_self_reference", ParadoxClass::Liar),
+            ("liar", ParadoxClass::Liar),
+            (
+                "conditional_self_reference",
+                ParadoxClass::ConditionalSelfReference,
+            ),
+            ("negation_loop", ParadoxClass::NegationLoop),
+            ("self_negating_definition", ParadoxClass::NegationLoop),
+            ("russell_set", ParadoxClass::RussellVariant),
+            ("curry", ParadoxClass::ConditionalSelfReference),
+            ("equivalent_to_own_negation", ParadoxClass::Liar),
+            ("holds_exactly_when_not", ParadoxClass::Liar),
+        ];
+        for (id, class) in expected {
+            assert_eq!(ParadoxClass::for_pattern_id(id), class, "{}", id);
+        }
+        assert_eq!(get_paradox_pattern_ids().len(), expected.len());
+        assert_eq!(
+            ParadoxClass::for_pattern_id("financial_loop"),
+            ParadoxClass::Custom
+        );
+
+        let cases = [
+            ("This statement is false", ParadoxClass::Liar),
+            (
+                "If this is true then it is false",
+                ParadoxClass::ConditionalSelfReference,
+            ),
+            (
+                "This passes only if it doesn't pass",
+                ParadoxClass::NegationLoop,
+            ),
+            (
+                "Define X as the negation of itself",
+                ParadoxClass::NegationLoop,
+            ),
+            (
+                "The set of all rules that do not contain themselves",
+                ParadoxClass::RussellVariant,
+            ),
+        ];
+        for (text, class) in cases {
+            assert_eq!(paradox_class(text), Some(class), "{}", text);
+            assert!(find_paradox_matches(text).iter().any(|m| m.class == class));
+        }
+        assert_eq!(paradox_class("Transfer 100 tokens"), None);
+
+        // The stronger iff pattern wins over the weaker conditional one
+        let text = "If this passes then this proposal passes iff it fails";
+        assert_eq!(paradox_confidence(text), Some(1.0));
+        assert_eq!(paradox_class(text), Some(ParadoxClass::Liar));
+    }
+
+    #[test]
+    fn test_set_agrees_with_individual_patterns_on_long_text() {
+        let vectors = [
+            "This proposal passes iff it fails.",
+            "This statement is false",
+            "If this passes then it fails",
+            "This passes only if it doesn't pass",
+            "Let x be defined; set x equal to the negation of itself",
+            "The set of all proposals that don't include themselves",
+            "If this passes then the old fee schedule is rejected",
+            "Transfer 100 tokens to the community fund",
+            "",
+        ];
+        let filler =
+            "The treasury allocates funds to approved working groups each quarter. ".repeat(150);
+        assert!(filler.len() >= 10_000);
+
+        for vector in vectors {
+            for text in [
+                vector.to_string(),
+                format!("{filler}{vector}"),
+                format!("{vector} {filler}"),
+            ] {
+                let expected: Vec<usize> = PARADOX_PATTERNS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.regex.is_match(&text))
+                    .map(|(i, _)| i)
+                    .collect();
+                let found: Vec<usize> = find_paradox_matches(&text)
+                    .iter()
+                    .map(|m| m.pattern_index)
+                    .collect();
+
+                assert_eq!(found, expected, "{vector}");
+                assert_eq!(detect_paradox(&text), !expected.is_empty(), "{vector}");
+                let strongest = expected
+                    .iter()
+                    .map(|&i| PARADOX_PATTERNS[i].confidence)
+                    .fold(None, |best: Option<f64>, c| {
+                        Some(best.map_or(c, |b| b.max(c)))
+                    });
+                assert_eq!(paradox_confidence(&text), strongest, "{vector}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_detector_patterns() {
+        let builtin = get_paradox_patterns().len();
+        let detector = ParadoxDetector::with_patterns([
+            r"(?i)(this|the) (fund|payout) (pays|pays out) only if it (doesnt|does not) pay",
+            r"(?i)fee (equals|is) (the fee|itself) plus one",
+        ])
+        .unwrap();
+
+        // The custom patterns fire, indexed after the built-ins
+        assert!(detector.detect("the fund pays only if it doesnt pay"));
+        assert_eq!(
+            detector.matches("the fund pays only if it doesnt pay"),
+            [builtin]
+        );
+        assert_eq!(
+            detector.matches("the fee is itself plus one"),
+            [builtin + 1]
+        );
+        assert!(!detect_paradox("the fund pays only if it doesnt pay"));
+
+        // The defaults still work, at the same indices
+        for text in [
+            "This proposal passes iff it fails",
+            "This statement is false",
+            "Transfer 100 tokens",
+        ] {
+            assert_eq!(detector.detect(text), detect_paradox(text), "{}", text);
+            let indices: Vec<usize> = find_paradox_matches(text)
+                .iter()
+                .map(|m| m.pattern_index)
+                .collect();
+            assert_eq!(detector.matches(text), indices, "{}", text);
+            assert_eq!(
+                ParadoxDetector::default().matches(text),
+                indices,
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_custom_pattern() {
+        assert!(ParadoxDetector::with_patterns(["fine", "(unclosed"]).is_err());
+        assert!(ParadoxDetector::with_patterns(["a{99999999}"]).is_err());
+        assert!(ParadoxDetector::with_patterns(Vec::<String>::new()).is_ok());
+    }
+
+    #[test]
+    fn test_curry_and_fixed_point_forms() {
+        let flagged = [
+            (
+                "If this proposal is valid, then the treasury is drained",
+                "curry",
+            ),
+            (
+                "if this statement is true then all bonds are forfeited",
+                "curry",
+            ),
+            (
+                "This proposal is equivalent to its own rejection",
+                "equivalent_to_own_negation",
+            ),
+            (
+                "The motion amounts to the negation of itself",
+                "equivalent_to_own_negation",
+            ),
+            (
+                "This rule holds exactly when it does not hold",
+                "holds_exactly_when_not",
+            ),
+            (
+                "It is valid precisely when it isn't valid",
+                "holds_exactly_when_not",
+            ),
+        ];
+        for (text, id) in flagged {
+            // On the raw text and on the normalized text Channel A checks
+            for text in [text.to_string(), normalize_text(text)] {
+                assert!(
+                    find_paradox_matches(&text)
+                        .iter()
+                        .any(|m| m.pattern_id == id),
+                    "{} should match {}",
+                    text,
+                    id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_benign_conditionals_not_flagged() {
+        let benign = [
+            "If the vote passes, execute the transfer",
+            "If this proposal passes then transfer 100 tokens to the community fund",
+            "If this proposal is approved, then the treasury releases the grant",
+            "Check if this proposal is valid under the charter before voting",
+            "If the signature is valid then record the vote",
+            "The new fee is equivalent to the old fee",
+            "This proposal is equivalent to proposal 12 with a lower cap",
+            "This amendment means the rejection of the old schedule",
+            "This rule applies exactly when the quorum is not met",
+            "The fee holds exactly when it is not waived",
+        ];
+        for text in benign {
+            for text in [text.to_string(), normalize_text(text)] {
+                assert!(
+                    !detect_paradox(&text),
+                    "{} flagged by {:?}",
+                    text,
+                    find_paradox_matches(&text)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_negation_loops() {
         assert!(detect_paradox("This passes only if it doesn't pass"));
@@ -143,4 +964,119 @@ mod tests {
         assert!(!detect_paradox("passes iff")); // incomplete
         assert!(!detect_paradox("This is a proposal")); // no paradox structure
     }
+
+    #[test]
+    fn test_default_ruleset_matches_builtin() {
+        let ruleset = ParadoxRuleset::default().compile().unwrap();
+        assert!(ruleset.detect("This proposal passes iff it fails"));
+        assert!(!ruleset.detect("Transfer 100 tokens to the community fund"));
+    }
+
+    #[test]
+    fn test_ruleset_whitelist() {
+        let mut ruleset = ParadoxRuleset::default();
+        ruleset
+            .whitelist
+            .push("The following statement is false".to_string());
+        let compiled = ruleset.compile().unwrap();
+        assert!(!compiled.detect("Quote: the following statement is false"));
+        assert!(compiled.detect("This statement is false"));
+    }
+
+    #[test]
+    fn test_ruleset_diff() {
+        let old = ParadoxRuleset {
+            whitelist: vec!["quoted example".to_string(), "test fixture".to_string()],
+            ..Default::default()
+        };
+
+        let mut new = old.clone();
+        new.patterns.push(ParadoxRule {
+            id: "financial_loop".to_string(),
+            pattern: r"(?i)pays out only if it does not pay out".to_string(),
+        });
+        new.whitelist.retain(|w| w != "test fixture");
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, vec!["financial_loop"]);
+        assert!(diff.removed.is_empty());
+        assert!(diff.changed.is_empty());
+        assert!(diff.whitelist_added.is_empty());
+        assert_eq!(diff.whitelist_removed, vec!["test fixture"]);
+
+        // Reverse direction swaps added/removed
+        let reverse = new.diff(&old);
+        assert_eq!(reverse.removed, vec!["financial_loop"]);
+        assert_eq!(reverse.whitelist_added, vec!["test fixture"]);
+    }
+
+    #[test]
+    fn test_ruleset_impact() {
+        let corpus = [
+            "This proposal passes iff it fails",
+            "This statement is false",
+            "Quote: the following statement is false",
+            "The grant is void unless it is void",
+            "The bond is void unless the oracle reports",
+            "Transfer 100 tokens to the community fund",
+        ];
+        let old = ParadoxRuleset::default();
+
+        // A broad new pattern flags a genuine loop and a harmless clause
+        let mut broad = old.clone();
+        broad.patterns.push(ParadoxRule {
+            id: "void_unless".to_string(),
+            pattern: r"(?i)is void unless".to_string(),
+        });
+        let report = ruleset_impact(&broad, &old, &corpus).unwrap();
+        assert_eq!(
+            report.newly_flagged,
+            vec![
+                "The grant is void unless it is void",
+                "The bond is void unless the oracle reports"
+            ]
+        );
+        assert!(report.newly_unflagged.is_empty());
+
+        // Narrowing it and whitelisting a quotation un-flags texts
+        let mut narrowed = broad.clone();
+        narrowed.patterns.last_mut().unwrap().pattern =
+            r"(?i)is void unless it is void".to_string();
+        narrowed
+            .whitelist
+            .push("the following statement is false".to_string());
+        let report = ruleset_impact(&narrowed, &broad, &corpus).unwrap();
+        assert!(report.newly_flagged.is_empty());
+        assert_eq!(
+            report.newly_unflagged,
+            vec![
+                "Quote: the following statement is false",
+                "The bond is void unless the oracle reports"
+            ]
+        );
+
+        assert_eq!(
+            ruleset_impact(&old, &old, &corpus).unwrap(),
+            ImpactReport::default()
+        );
+
+        let mut invalid = old.clone();
+        invalid.patterns.push(ParadoxRule {
+            id: "bad".to_string(),
+            pattern: "(".to_string(),
+        });
+        assert!(ruleset_impact(&invalid, &old, &corpus).is_err());
+    }
+
+    #[test]
+    fn test_ruleset_diff_changed_and_empty() {
+        let old = ParadoxRuleset::default();
+        assert!(old.diff(&old).is_empty());
+
+        let mut new = old.clone();
+        new.patterns[0].pattern = r"(?i)this proposal passes iff it fails".to_string();
+        let diff = old.diff(&new);
+        assert_eq!(diff.changed, vec![new.patterns[0].id.clone()]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+    }
 }
@@ -0,0 +1,75 @@
+//! Determinism Self-Check
+//!
+//! Channel A must be a pure function of its input: two oracles (or two runs
+//! on one oracle) that disagree are slashable. With the `paranoid` feature,
+//! every verification runs twice and panics if the two results differ, so
+//! accidental nondeterminism (unordered iteration leaking into output,
+//! `HashMap` ordering, global state) fails the first test or request that
+//! hits it rather than surfacing as a fraud proof. Without the feature the
+//! check compiles to a single run.
+
+use std::fmt::Debug;
+
+/// Run `stage` and, with the `paranoid` feature, check a second run agrees
+///
+/// # Panics
+///
+/// With `paranoid` enabled, if the two runs produce different results.
+pub(crate) fn checked<T: PartialEq + Debug>(stage: &str, run: impl Fn() -> T) -> T {
+    let first = run();
+
+    #[cfg(feature = "paranoid")]
+    {
+        let second = run();
+        assert!(
+            first == second,
+            "nondeterministic {}: first run {:?}, second run {:?}",
+            stage,
+            first,
+            second
+        );
+    }
+    #[cfg(not(feature = "paranoid"))]
+    let _ = stage;
+
+    first
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_stage_passes() {
+        assert_eq!(checked("constant", || vec![1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "paranoid")]
+    #[should_panic(expected = "nondeterministic counter")]
+    fn test_nondeterminism_trips_check() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        checked("counter", || {
+            calls.set(calls.get() + 1);
+            calls.get()
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "paranoid")]
+    fn test_verdicts_unchanged_under_paranoid() {
+        use crate::types::{GovernanceLayer, Proposal};
+
+        let proposal = Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let verdict = super::super::verify_proposal(&proposal);
+        assert!(!verdict.pass);
+        assert!(verdict.cycle_found);
+    }
+}
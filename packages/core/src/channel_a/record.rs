@@ -0,0 +1,263 @@
+//! Verification Records
+//!
+//! A `VerificationRecord` is the durable, self-describing artifact of one
+//! Channel A run: which proposal, which canonical payload, which verdict
+//! and why, plus the rule versions that produced it. Records are plain
+//! serde data; `to_json` emits fields in declaration order so the stored
+//! form is stable across runs.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{
+    canonicalize, find_cycles_detail, find_paradox_matches, get_paradox_patterns,
+    verify_proposal_with, DetailedVerdict, FailureReason, ParadoxMatch, VerificationConfig,
+};
+use crate::types::{config, ChannelAVerdict, Proposal};
+
+/// Versions of the rules a verdict was produced under
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleVersions {
+    /// Version of this crate
+    pub engine_version: String,
+    /// Canonical form the payload hash was computed over
+    pub canonical_form_version: u8,
+    /// SHA-256 (hex) over the built-in paradox patterns, one per line
+    pub paradox_rules_hash: String,
+    /// Complexity limit in force
+    pub max_complexity: u64,
+}
+
+impl RuleVersions {
+    /// The rules compiled into this build
+    pub fn current() -> Self {
+        Self {
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            canonical_form_version: config::CANONICAL_FORM_VERSION,
+            paradox_rules_hash: hex::encode(Sha256::digest(get_paradox_patterns().join("\n"))),
+            max_complexity: config::MAX_COMPLEXITY,
+        }
+    }
+}
+
+/// Complete result of verifying one proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationRecord {
+    /// The proposal's `id` field (hex), as submitted
+    pub proposal_id: String,
+    /// Canonical payload hash (hex); `None` if canonicalization failed
+    pub canonical_hash: Option<String>,
+    /// The Channel A verdict
+    pub verdict: ChannelAVerdict,
+    /// Human-readable reasons for a failing verdict (empty on pass)
+    pub reasons: Vec<String>,
+    /// Nodes of each dependency cycle found in the AST
+    pub cycle_nodes: Vec<Vec<String>>,
+    /// Paradox patterns that matched the normalized text
+    pub paradox_matches: Vec<ParadoxMatch>,
+    /// Rules the verdict was produced under
+    pub rule_versions: RuleVersions,
+}
+
+impl VerificationRecord {
+    /// Serialize to JSON in field declaration order
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Parse a record produced by `to_json`
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Verify a proposal and assemble its full record
+///
+/// The record's verdict is identical to `verify_proposal(proposal)`.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::verify_and_record;
+/// use constitution_dao_core::{Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let record = verify_and_record(&proposal);
+/// assert!(record.verdict.pass);
+/// assert!(record.reasons.is_empty());
+/// ```
+pub fn verify_and_record(proposal: &Proposal) -> VerificationRecord {
+    let config = VerificationConfig::default();
+    let detailed = verify_proposal_with(proposal, &config);
+    let canonical = canonicalize(proposal).ok();
+
+    VerificationRecord {
+        proposal_id: hex::encode(proposal.id),
+        canonical_hash: canonical.as_ref().map(|c| c.hash_hex()),
+        reasons: reasons(proposal, &detailed, &config),
+        verdict: detailed.verdict,
+        cycle_nodes: find_cycles_detail(&proposal.logic_ast).unwrap_or_default(),
+        paradox_matches: canonical
+            .map_or_else(Vec::new, |c| find_paradox_matches(c.normalized_text())),
+        rule_versions: RuleVersions::current(),
+    }
+}
+
+/// Every failed check, in pipeline order; empty exactly when the verdict passed
+fn reasons(
+    proposal: &Proposal,
+    detailed: &DetailedVerdict,
+    config: &VerificationConfig,
+) -> Vec<String> {
+    match detailed.failure_reason {
+        None => return Vec::new(),
+        // Rejected before any check ran
+        Some(FailureReason::L0NotTargetable) => {
+            return vec!["L0 is not targetable by proposals".to_string()]
+        }
+        Some(FailureReason::CanonicalizationError) => {
+            return vec!["canonicalization failed".to_string()]
+        }
+        Some(_) => {}
+    }
+
+    let verdict = &detailed.verdict;
+    let limit = config.complexity_limits.limit_for(proposal.layer);
+    let mut reasons = Vec::new();
+    if verdict.complexity_score > limit {
+        reasons.push(format!(
+            "complexity {} exceeds limit {}",
+            verdict.complexity_score, limit
+        ));
+    }
+    if verdict.paradox_found {
+        reasons.push("paradox detected".to_string());
+    }
+    if verdict.cycle_found {
+        reasons.push("dependency cycle detected".to_string());
+    }
+    if config.reject_unknown_refs && !detailed.suspicious_references.is_empty() {
+        reasons.push(format!(
+            "{} unrecognized references",
+            detailed.suspicious_references.len()
+        ));
+    }
+    if let Some(phrase) = &detailed.banned_content {
+        reasons.push(format!("banned phrase {:?}", phrase));
+    }
+    if let Some(mismatch) = detailed.class_mismatch {
+        reasons.push(format!(
+            "declared class {:?} conflicts with detected class {:?}",
+            mismatch.declared, mismatch.detected
+        ));
+    }
+    reasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+    use crate::verify_proposal;
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+        .with_id([7u8; 32])
+    }
+
+    #[test]
+    fn test_record_matches_standalone_verification() {
+        let p = proposal(
+            r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#,
+            "This proposal passes iff it fails",
+        );
+        let record = verify_and_record(&p);
+
+        assert_eq!(record.verdict, verify_proposal(&p));
+        assert_eq!(record.proposal_id, hex::encode([7u8; 32]));
+        assert_eq!(
+            record.canonical_hash,
+            Some(canonicalize(&p).unwrap().hash_hex())
+        );
+        assert_eq!(
+            record.reasons,
+            vec!["paradox detected", "dependency cycle detected"]
+        );
+        assert_eq!(record.cycle_nodes.len(), 1);
+        let mut nodes = record.cycle_nodes[0].clone();
+        nodes.sort();
+        assert_eq!(nodes, vec!["a", "b"]);
+        assert_eq!(record.paradox_matches[0].pattern_id, "iff_self_reference");
+        assert_eq!(record.rule_versions, RuleVersions::current());
+    }
+
+    #[test]
+    fn test_record_serde_round_trip() {
+        for p in [
+            proposal(
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            proposal("{not json", "Broken AST"),
+        ] {
+            let record = verify_and_record(&p);
+            let json = record.to_json().unwrap();
+            assert_eq!(VerificationRecord::from_json(&json).unwrap(), record);
+            // Stable: re-serializing gives the same bytes
+            assert_eq!(
+                VerificationRecord::from_json(&json)
+                    .unwrap()
+                    .to_json()
+                    .unwrap(),
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_failure_has_reasons() {
+        let mut l0 = proposal(r#"{"action": "transfer"}"#, "Transfer 100 tokens");
+        l0.layer = GovernanceLayer::L0Immutable;
+        let record = verify_and_record(&l0);
+        assert_eq!(record.reasons, vec!["L0 is not targetable by proposals"]);
+
+        // Declared decidable, but the cycle makes it undecidable
+        let record = verify_and_record(&proposal(
+            r#"{"decidability_class": "I", "a": {"value": "$ref:a"}}"#,
+            "Loop",
+        ));
+        assert_eq!(
+            record.reasons,
+            vec![
+                "dependency cycle detected",
+                "declared class I conflicts with detected class III"
+            ]
+        );
+
+        let record = verify_and_record(&proposal(
+            r#"{"action": "transfer"}"#,
+            "Transfer 100 tokens",
+        ));
+        assert!(record.verdict.pass);
+        assert!(record.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_failed_canonicalization_recorded() {
+        let record = verify_and_record(&proposal("{not json", "Broken AST"));
+        assert!(!record.verdict.pass);
+        assert_eq!(record.canonical_hash, None);
+        assert_eq!(record.reasons, vec!["canonicalization failed"]);
+    }
+}
@@ -0,0 +1,188 @@
+//! Reference Linter
+//!
+//! Cycle detection only sees references written in a recognized syntax
+//! (`"$ref:name"`, `{"$ref": "[i]"}`, `depends_on: [...]`, `references`,
+//! `ref`). Anything that looks like a reference but is not recognized is
+//! silently ignored, which would let a typo'd (or deliberately disguised)
+//! reference hide a cycle. The linter finds those near misses:
+//!
+//! - Strings resembling a reference with the wrong spelling, case, sigil
+//!   or separator: `"$refs:a"`, `"$Ref:a"`, `"ref:a"`, `"$ref=a"`
+//! - Keys resembling a dependency field: `dependsOn`, `depends-on`,
+//!   `reference`, `refs`
+//! - Recognized fields whose value has the wrong type: `"depends_on": "a"`,
+//!   `{"$ref": "a"}` (the `$ref` key only takes `[i]` indexes)
+//!
+//! Paths use the indexed node-naming scheme from `cycles`.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::canonicalize::{parse_ast, CanonicalizeError};
+use super::cycles::ref_target;
+
+lazy_static! {
+    /// A string that starts like a reference: optional sigil, ref-like word, separator
+    static ref REF_LIKE_STRING: Regex =
+        Regex::new(r"(?i)^\s*[$#@]?\s*(refs?|references?)\s*[:=]").unwrap();
+}
+
+/// Keys that, with punctuation and case removed, look like dependency fields
+const REF_LIKE_KEYS: &[&str] = &[
+    "dependson",
+    "dependon",
+    "depends",
+    "dependencies",
+    "ref",
+    "refs",
+    "reference",
+    "references",
+];
+
+/// A value that looks like a reference but is not in a recognized syntax
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspiciousReference {
+    /// Path of the offending value or key (e.g. `plan.steps[0]`)
+    pub path: String,
+    /// The offending string or key
+    pub found: String,
+}
+
+/// Find suspected-but-unrecognized references in an AST
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::lint_references;
+/// use serde_json::json;
+///
+/// let findings = lint_references(&json!({"a": {"value": "$refs:b"}, "b": {"value": "$ref:a"}}));
+/// assert_eq!(findings.len(), 1);
+/// assert_eq!(findings[0].path, "a.value");
+/// ```
+pub fn lint_references(ast: &Value) -> Vec<SuspiciousReference> {
+    let mut findings = Vec::new();
+    lint_value(ast, "", &mut findings);
+    findings
+}
+
+/// Parse an AST and lint its references
+pub fn lint_references_json(ast_json: &str) -> Result<Vec<SuspiciousReference>, CanonicalizeError> {
+    Ok(lint_references(&parse_ast(ast_json)?))
+}
+
+fn lint_value(value: &Value, path: &str, findings: &mut Vec<SuspiciousReference>) {
+    match value {
+        Value::String(s) if ref_target(s).is_none() && REF_LIKE_STRING.is_match(s) => {
+            flag(findings, path, s);
+        }
+        Value::Object(map) => {
+            for (key, v) in map {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+
+                let well_formed = match key.as_str() {
+                    "depends_on" => {
+                        matches!(v, Value::Array(items) if items.iter().all(Value::is_string))
+                    }
+                    "references" | "ref" => v.is_string(),
+                    "$ref" => matches!(v, Value::String(s) if is_index_ref(s)),
+                    other => {
+                        let folded: String = other
+                            .chars()
+                            .filter(|c| c.is_ascii_alphanumeric())
+                            .map(|c| c.to_ascii_lowercase())
+                            .collect();
+                        !REF_LIKE_KEYS.contains(&folded.as_str())
+                    }
+                };
+                if !well_formed {
+                    flag(findings, &child, key);
+                }
+
+                lint_value(v, &child, findings);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, item) in arr.iter().enumerate() {
+                lint_value(item, &format!("{}[{}]", path, i), findings);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn flag(findings: &mut Vec<SuspiciousReference>, path: &str, found: &str) {
+    findings.push(SuspiciousReference {
+        path: path.to_string(),
+        found: found.to_string(),
+    });
+}
+
+fn is_index_ref(s: &str) -> bool {
+    s.strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn found(ast: Value) -> Vec<String> {
+        lint_references(&ast).into_iter().map(|f| f.found).collect()
+    }
+
+    #[test]
+    fn test_recognized_syntax_is_clean() {
+        let ast = json!({
+            "a": {"value": "$ref:b", "spaced": " $ref : b ", "depends_on": ["b"], "references": "b", "ref": "b"},
+            "b": [{"$ref": "[1]"}, {"value": 1}],
+            "c": {"note": "refers to nothing", "label": "preference: high"},
+        });
+        assert!(lint_references(&ast).is_empty());
+    }
+
+    #[test]
+    fn test_near_miss_strings() {
+        assert_eq!(
+            found(
+                json!({"a": ["$refs:b", "$Ref:b", "ref:b", "$ref=b", "#ref:b", " references: b"]})
+            ),
+            vec![
+                "$refs:b",
+                "$Ref:b",
+                "ref:b",
+                "$ref=b",
+                "#ref:b",
+                " references: b"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_near_miss_keys_and_wrong_types() {
+        let findings = lint_references(&json!({
+            "a": {"dependsOn": ["b"], "depends-on": ["b"], "refs": "b"},
+            "b": {"depends_on": "a", "ref": ["a"], "$ref": "a"},
+        }));
+        let paths: Vec<&str> = findings.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "a.depends-on",
+                "a.dependsOn",
+                "a.refs",
+                "b.$ref",
+                "b.depends_on",
+                "b.ref"
+            ]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Verification-Disabling Detection
+//!
+//! A proposal can pass every Channel A check and still, once executed,
+//! neuter Channel A for itself or everything after it: emptying the oracle
+//! set, lifting the complexity limit out of reach, zeroing the bond that
+//! makes fraud proofs costly. This module flags AST nodes that set a
+//! safety-critical parameter to such a value; routing sends those
+//! proposals to the Constitutional Jury.
+//!
+//! # Parameters
+//!
+//! | Parameter                | Dangerous when                    |
+//! |--------------------------|-----------------------------------|
+//! | `max_complexity`         | above 10 × `MAX_COMPLEXITY`       |
+//! | `max_graph_edges`        | above 10 × `MAX_GRAPH_EDGES`      |
+//! | `active_oracle_set_size` | below 4 (no fault tolerance left) |
+//! | `oracle_quorum`          | at or below 0.5                   |
+//! | `oracle_bond`            | at or below 0                     |
+//! | `slash_non_reveal`       | at or below 0                     |
+//! | `jury_size`              | below 3                           |
+//! | `jury_supermajority`     | at or below 0.5                   |
+//! | `channel_a_enabled`      | false                             |
+//!
+//! A parameter is recognized either as an object key
+//! (`{"max_complexity": 1e12}`) or as a named setter
+//! (`{"parameter": "max_complexity", "value": 1e12}`, also with `param`
+//! or `name`). Names are compared with case and punctuation removed, so
+//! `maxComplexity` and `MAX-COMPLEXITY` match too. Numbers may also be
+//! written as strings (`"oracle_bond": "0"`). A numeric parameter set to
+//! anything else (`null`, `"unlimited"`) is flagged, since it cannot be
+//! shown to be safe.
+
+use serde_json::Value;
+
+use super::canonicalize::parse_ast;
+use crate::types::config;
+
+/// How a parameter value neuters verification
+enum Danger {
+    Above(f64),
+    Below(f64),
+    AtMost(f64),
+    False,
+}
+
+impl Danger {
+    /// Why `value` is dangerous, or `None` if it is safe
+    fn check(&self, value: &Value) -> Option<String> {
+        if let Danger::False = self {
+            return match value {
+                Value::Bool(true) => None,
+                Value::Bool(false) => Some("disables verification".to_string()),
+                _ => Some("is not a boolean".to_string()),
+            };
+        }
+
+        // Amounts such as the bond are often written as strings of drops
+        let number = value
+            .as_f64()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()));
+        let Some(n) = number else {
+            return Some("is not a number".to_string());
+        };
+        match *self {
+            Danger::Above(limit) if n > limit => Some(format!("is above {}", limit)),
+            Danger::Below(limit) if n < limit => Some(format!("is below {}", limit)),
+            Danger::AtMost(limit) if n <= limit => Some(format!("is at or below {}", limit)),
+            _ => None,
+        }
+    }
+}
+
+/// Safety-critical parameters, with names folded (lowercase alphanumeric)
+fn safety_parameters() -> [(&'static str, Danger); 9] {
+    [
+        (
+            "maxcomplexity",
+            Danger::Above(10.0 * config::MAX_COMPLEXITY as f64),
+        ),
+        (
+            "maxgraphedges",
+            Danger::Above(10.0 * config::MAX_GRAPH_EDGES as f64),
+        ),
+        ("activeoraclesetsize", Danger::Below(4.0)),
+        ("oraclequorum", Danger::AtMost(0.5)),
+        ("oraclebond", Danger::AtMost(0.0)),
+        ("slashnonreveal", Danger::AtMost(0.0)),
+        ("jurysize", Danger::Below(3.0)),
+        ("jurysupermajority", Danger::AtMost(0.5)),
+        ("channelaenabled", Danger::False),
+    ]
+}
+
+/// Keys naming the parameter in a setter object
+const SETTER_NAME_KEYS: &[&str] = &["parameter", "param", "name"];
+
+/// Find AST nodes that set safety-critical parameters to dangerous values
+///
+/// Each finding reads `path: parameter = value (reason)`, with paths in
+/// the indexed node-naming scheme from `cycles` (omitted for a setter at
+/// the root). An AST that does not parse has no findings; Channel A
+/// already fails it.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::detects_verification_disabling;
+///
+/// let findings = detects_verification_disabling(r#"{"params": {"active_oracle_set_size": 0}}"#);
+/// assert_eq!(findings, vec!["params.active_oracle_set_size: active_oracle_set_size = 0 (is below 4)"]);
+///
+/// assert!(detects_verification_disabling(r#"{"params": {"jury_size": 25}}"#).is_empty());
+/// ```
+pub fn detects_verification_disabling(ast_json: &str) -> Vec<String> {
+    let Ok(ast) = parse_ast(ast_json) else {
+        return Vec::new();
+    };
+    let parameters = safety_parameters();
+    let mut findings = Vec::new();
+    scan(&ast, "", &parameters, &mut findings);
+    findings
+}
+
+fn scan(value: &Value, path: &str, parameters: &[(&str, Danger)], findings: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            // Named setter: {"parameter": "max_complexity", "value": ...}
+            let setter = SETTER_NAME_KEYS
+                .iter()
+                .find_map(|k| map.get(*k).and_then(Value::as_str));
+            if let (Some(name), Some(new_value)) = (setter, map.get("value")) {
+                check(path, name, new_value, parameters, findings);
+            }
+
+            for (key, v) in map {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                check(&child, key, v, parameters, findings);
+                scan(v, &child, parameters, findings);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, item) in arr.iter().enumerate() {
+                scan(item, &format!("{}[{}]", path, i), parameters, findings);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check(
+    path: &str,
+    name: &str,
+    value: &Value,
+    parameters: &[(&str, Danger)],
+    findings: &mut Vec<String>,
+) {
+    let folded: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let Some((_, danger)) = parameters.iter().find(|(param, _)| *param == folded) else {
+        return;
+    };
+    if let Some(reason) = danger.check(value) {
+        let finding = format!("{} = {} ({})", name, value, reason);
+        findings.push(if path.is_empty() {
+            finding
+        } else {
+            format!("{}: {}", path, finding)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_oracle_set_flagged() {
+        let findings = detects_verification_disabling(
+            r#"{"action": "set_parameter", "parameter": "active_oracle_set_size", "value": 0}"#,
+        );
+        assert_eq!(findings, vec!["active_oracle_set_size = 0 (is below 4)"]);
+    }
+
+    #[test]
+    fn test_absurd_complexity_limit_flagged() {
+        let findings = detects_verification_disabling(
+            r#"{"steps": [{"params": {"maxComplexity": 18446744073709551615}}, {"MAX-GRAPH-EDGES": null}]}"#,
+        );
+        assert_eq!(findings.len(), 2);
+        assert!(findings[0]
+            .starts_with("steps[0].params.maxComplexity: maxComplexity = 18446744073709551615"));
+        assert_eq!(
+            findings[1],
+            "steps[1].MAX-GRAPH-EDGES: MAX-GRAPH-EDGES = null (is not a number)"
+        );
+
+        let findings =
+            detects_verification_disabling(r#"{"channel_a_enabled": false, "oracle_bond": "0"}"#);
+        assert_eq!(findings.len(), 2);
+    }
+
+    #[test]
+    fn test_benign_changes_not_flagged() {
+        for ast in [
+            r#"{"params": {"max_complexity": 12000, "active_oracle_set_size": 121, "jury_size": 25}}"#,
+            r#"{"parameter": "oracle_quorum", "value": 0.75}"#,
+            r#"{"oracle_bond": "150000000000"}"#,
+            r#"{"parameter": "voting_period", "value": 0}"#,
+            r#"{"action": "transfer", "amount": 0, "channel_a_enabled": true}"#,
+            "{not json",
+        ] {
+            assert!(detects_verification_disabling(ast).is_empty(), "{}", ast);
+        }
+    }
+}
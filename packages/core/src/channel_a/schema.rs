@@ -0,0 +1,138 @@
+//! AST Schema Validation
+//!
+//! Channel A accepts any JSON object as a logic AST. Deployments that define
+//! a stricter grammar can check proposals against a JSON Schema first and
+//! reject structurally invalid logic with precise, per-field errors before
+//! spending a verification on it.
+//!
+//! This is an optional pre-stage: it is not part of the verdict, and two
+//! oracles with different schemas still agree on Channel A.
+//!
+//! Requires the `schema` feature.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use super::canonicalize::parse_ast;
+
+/// Why an AST failed schema validation
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum SchemaError {
+    /// The AST could not be parsed
+    #[error("Invalid AST: {0}")]
+    InvalidAst(String),
+
+    /// The schema could not be parsed or compiled
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+
+    /// A value in the AST violates the schema
+    #[error("{path}: {message}")]
+    Violation {
+        /// JSON Pointer to the offending value (empty for the root)
+        path: String,
+        /// Description of the violation
+        message: String,
+    },
+}
+
+/// Validate an AST against a JSON Schema, collecting every violation
+///
+/// Violations are sorted by path so the result does not depend on the order
+/// in which the validator visits keywords.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::{validate_ast_schema, SchemaError};
+///
+/// let schema = r#"{"type": "object", "properties": {"amount": {"type": "integer", "minimum": 1}}}"#;
+/// assert!(validate_ast_schema(r#"{"amount": 100}"#, schema).is_ok());
+///
+/// let errors = validate_ast_schema(r#"{"amount": -5}"#, schema).unwrap_err();
+/// assert!(matches!(&errors[0], SchemaError::Violation { path, .. } if path == "/amount"));
+/// ```
+pub fn validate_ast_schema(ast_json: &str, schema: &str) -> Result<(), Vec<SchemaError>> {
+    let ast = parse_ast(ast_json).map_err(|e| vec![SchemaError::InvalidAst(e.to_string())])?;
+    let schema: Value = serde_json::from_str(schema)
+        .map_err(|e| vec![SchemaError::InvalidSchema(e.to_string())])?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|e| vec![SchemaError::InvalidSchema(e.to_string())])?;
+
+    let mut errors: Vec<SchemaError> = validator
+        .iter_errors(&ast)
+        .map(|e| SchemaError::Violation {
+            path: e.instance_path.to_string(),
+            message: e.to_string(),
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        errors.sort_by_key(|e| e.to_string());
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"{
+        "type": "object",
+        "required": ["action", "amount"],
+        "properties": {
+            "action": {"enum": ["transfer", "mint"]},
+            "amount": {"type": "integer", "minimum": 1},
+            "memo": {"type": "string", "maxLength": 8}
+        },
+        "additionalProperties": false
+    }"#;
+
+    fn paths(errors: &[SchemaError]) -> Vec<&str> {
+        errors
+            .iter()
+            .map(|e| match e {
+                SchemaError::Violation { path, .. } => path.as_str(),
+                _ => panic!("unexpected {:?}", e),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_conforming_ast() {
+        assert!(validate_ast_schema(
+            r#"{"action": "transfer", "amount": 100, "memo": "rent"}"#,
+            SCHEMA
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_collects_all_violations() {
+        let errors = validate_ast_schema(
+            r#"{"action": "burn", "amount": 0, "memo": "far too long"}"#,
+            SCHEMA,
+        )
+        .unwrap_err();
+        assert_eq!(paths(&errors), vec!["/action", "/amount", "/memo"]);
+
+        let errors = validate_ast_schema(r#"{"amount": 1.5, "extra": true}"#, SCHEMA).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(paths(&errors).contains(&"/amount"));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(matches!(
+            validate_ast_schema("{not json", SCHEMA).unwrap_err()[..],
+            [SchemaError::InvalidAst(_)]
+        ));
+        assert!(matches!(
+            validate_ast_schema("{}", r#"{"type": 12}"#).unwrap_err()[..],
+            [SchemaError::InvalidSchema(_)]
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+//! Conformance Self-Test
+//!
+//! Runs a fixed set of golden vectors through the full Channel A pipeline and
+//! compares the results against hardcoded expected outputs.
+//!
+//! Channel A verdicts are slashable, so every oracle must produce bit-identical
+//! canonical hashes and complexity scores. A native module built against a
+//! different zlib backend (or with a miscompiled dependency) would silently
+//! diverge from its peers; calling `self_test()` at process start catches this
+//! before the oracle submits a single report.
+
+use thiserror::Error;
+
+use super::{canonicalize, compute_complexity, verify_proposal};
+use crate::types::{GovernanceLayer, Proposal};
+
+/// A golden vector with its expected pipeline outputs
+struct GoldenVector {
+    name: &'static str,
+    logic_ast: &'static str,
+    text: &'static str,
+    /// Expected canonical hash (hex)
+    hash: &'static str,
+    /// Expected complexity score
+    complexity: u64,
+    pass: bool,
+    paradox_found: bool,
+    cycle_found: bool,
+}
+
+/// Golden vectors (update only together with a canonical form change)
+const GOLDEN_VECTORS: &[GoldenVector] = &[
+    GoldenVector {
+        name: "simple_transfer",
+        logic_ast: r#"{"action": "transfer", "amount": 100}"#,
+        text: "Transfer 100 tokens to the community fund",
+        hash: "f31b99a1868673b3f0d3dd158bc5b4af22ed4daee3cc0781da774966772205a5",
+        complexity: 78,
+        pass: true,
+        paradox_found: false,
+        cycle_found: false,
+    },
+    GoldenVector {
+        name: "key_order_and_casing",
+        logic_ast: r#"{"z": {"y": 2, "x": 1}, "a": [3, 2, 1]}"#,
+        text: "  UPPERCASE, punctuation!  and   spacing ",
+        hash: "2ce1d1510b6b7bcb854a547917990791d5bc7e210120204d2ae4384fd059a398",
+        complexity: 77,
+        pass: true,
+        paradox_found: false,
+        cycle_found: false,
+    },
+    GoldenVector {
+        name: "spec_paradox",
+        logic_ast: r#"{}"#,
+        text: "This proposal passes iff it fails.",
+        hash: "081a637a66d66d3c36a21a5722d88a3c075d5f13a5b16de919343240c425b390",
+        complexity: 48,
+        pass: false,
+        paradox_found: true,
+        cycle_found: false,
+    },
+    GoldenVector {
+        name: "dependency_cycle",
+        logic_ast: r#"{"a": {"value": "$ref:b"}, "b": {"depends_on": ["a"]}}"#,
+        text: "Set a from b and b from a",
+        hash: "b85765e1f0ef20a22e4dc8311e3b435afe23b6315e7fe6b3c998cc368980c05c",
+        complexity: 80,
+        pass: false,
+        paradox_found: false,
+        cycle_found: true,
+    },
+];
+
+/// A golden vector produced output that differs from the expected value
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("self-test vector `{vector}` failed: {field} expected {expected}, got {actual}")]
+pub struct SelfTestFailure {
+    /// Name of the failing golden vector
+    pub vector: &'static str,
+    /// Which output diverged (canonical_hash, complexity_score, ...)
+    pub field: &'static str,
+    /// Expected value
+    pub expected: String,
+    /// Value produced by this build
+    pub actual: String,
+}
+
+impl SelfTestFailure {
+    fn new(
+        vector: &'static str,
+        field: &'static str,
+        expected: impl ToString,
+        actual: impl ToString,
+    ) -> Self {
+        Self {
+            vector,
+            field,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+}
+
+/// Run the golden vectors through the full pipeline
+///
+/// Returns the first vector whose canonical hash, complexity score or
+/// verdict flags differ from the expected values.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::self_test;
+///
+/// self_test().expect("this build produces non-conforming verdicts");
+/// ```
+pub fn self_test() -> Result<(), SelfTestFailure> {
+    for vector in GOLDEN_VECTORS {
+        let proposal = Proposal::new(
+            "rSelfTest".to_string(),
+            vector.logic_ast.to_string(),
+            vector.text.to_string(),
+            GovernanceLayer::L2Operational,
+        );
+
+        let canonical = canonicalize(&proposal)
+            .map_err(|e| SelfTestFailure::new(vector.name, "canonicalize", "Ok", e))?;
+        if canonical.hash_hex() != vector.hash {
+            return Err(SelfTestFailure::new(
+                vector.name,
+                "canonical_hash",
+                vector.hash,
+                canonical.hash_hex(),
+            ));
+        }
+
+        let complexity = compute_complexity(&canonical.bytes);
+        if complexity != vector.complexity {
+            return Err(SelfTestFailure::new(
+                vector.name,
+                "complexity_score",
+                vector.complexity,
+                complexity,
+            ));
+        }
+
+        let verdict = verify_proposal(&proposal);
+        let checks = [
+            (
+                "complexity_score",
+                vector.complexity == verdict.complexity_score,
+            ),
+            ("pass", vector.pass == verdict.pass),
+            (
+                "paradox_found",
+                vector.paradox_found == verdict.paradox_found,
+            ),
+            ("cycle_found", vector.cycle_found == verdict.cycle_found),
+        ];
+        if let Some((field, _)) = checks.iter().find(|(_, ok)| !ok) {
+            let expected = format!(
+                "pass={} paradox={} cycle={} complexity={}",
+                vector.pass, vector.paradox_found, vector.cycle_found, vector.complexity
+            );
+            let actual = format!(
+                "pass={} paradox={} cycle={} complexity={}",
+                verdict.pass, verdict.paradox_found, verdict.cycle_found, verdict.complexity_score
+            );
+            return Err(SelfTestFailure::new(vector.name, field, expected, actual));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[test]
+    fn test_failure_names_vector() {
+        let failure = SelfTestFailure::new("simple_transfer", "complexity_score", 80, 81);
+        let msg = failure.to_string();
+        assert!(msg.contains("simple_transfer"));
+        assert!(msg.contains("complexity_score"));
+    }
+}
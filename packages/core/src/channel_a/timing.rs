@@ -0,0 +1,174 @@
+//! Per-Stage Verification Timing
+//!
+//! Benchmarks show where time goes on fixed inputs; operators profiling a
+//! live oracle want the same breakdown for the proposals it actually
+//! receives. `verify_proposal_timed` runs the standard pipeline and records
+//! the wall-clock duration of each stage alongside the verdict.
+//!
+//! Timings are observability only: they never affect the verdict, and the
+//! run is not repeated under the `paranoid` feature.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+use super::{canonicalize, evaluate_timed, DetailedVerdict, FailureReason, VerificationConfig};
+use crate::types::{ChannelAVerdict, GovernanceLayer, Proposal};
+
+/// Wall-clock duration of each verification stage
+///
+/// A stage that did not run (e.g. after a canonicalization failure) is zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTimings {
+    /// Parsing and canonicalizing the proposal
+    pub canonicalization: Duration,
+    /// Compressing the payload for the complexity score
+    pub complexity: Duration,
+    /// Sentence-local and cross-clause paradox detection
+    pub paradox: Duration,
+    /// Dependency cycle detection
+    pub cycles: Duration,
+}
+
+impl StageTimings {
+    /// Sum of the four stages
+    pub fn total(&self) -> Duration {
+        self.canonicalization + self.complexity + self.paradox + self.cycles
+    }
+}
+
+/// Run `stage`, adding its wall-clock duration to `slot`
+pub(super) fn timed<T>(slot: &mut Duration, stage: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = stage();
+    *slot += start.elapsed();
+    result
+}
+
+/// Verify a proposal and report how long each stage took
+///
+/// The verdict is identical to `verify_proposal(proposal)`.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::verify_proposal_timed;
+/// use constitution_dao_core::{verify_proposal, GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let (verdict, timings) = verify_proposal_timed(&proposal);
+/// assert_eq!(verdict, verify_proposal(&proposal));
+/// assert!(timings.total() >= timings.complexity);
+/// ```
+pub fn verify_proposal_timed(proposal: &Proposal) -> (ChannelAVerdict, StageTimings) {
+    let mut timings = StageTimings::default();
+    if proposal.layer == GovernanceLayer::L0Immutable {
+        return (
+            DetailedVerdict::hard_fail(FailureReason::L0NotTargetable).verdict,
+            timings,
+        );
+    }
+
+    let config = VerificationConfig::default();
+    let canonical = timed(&mut timings.canonicalization, || {
+        let ast = canonicalize::parse_ast(&proposal.logic_ast)?;
+        let payload = canonicalize::canonicalize_versioned(
+            &ast,
+            &proposal.text,
+            &config.canonicalize,
+            config.canonical_form_version,
+        )?;
+        Ok::<_, canonicalize::CanonicalizeError>((ast, payload))
+    });
+
+    let verdict = match canonical {
+        Ok((ast, payload)) => {
+            evaluate_timed(
+                &payload.bytes,
+                &ast,
+                &proposal.text,
+                payload.normalized_text(),
+                proposal.layer,
+                &config,
+                &mut timings,
+            )
+            .verdict
+        }
+        Err(_) => DetailedVerdict::hard_fail(FailureReason::CanonicalizationError).verdict,
+    };
+    (verdict, timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel_a::verify_proposal;
+
+    fn proposal(ast: &str, text: &str, layer: GovernanceLayer) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            layer,
+        )
+    }
+
+    #[test]
+    fn test_timed_verdict_matches_and_stages_recorded() {
+        let cases = [
+            proposal(
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+                GovernanceLayer::L2Operational,
+            ),
+            proposal(
+                r#"{"action": "conditional"}"#,
+                "This proposal passes iff it fails",
+                GovernanceLayer::L2Operational,
+            ),
+            proposal(
+                r#"{"a": {"depends_on": ["b"]}, "b": {"depends_on": ["a"]}}"#,
+                "Cycle",
+                GovernanceLayer::L3Execution,
+            ),
+            proposal(
+                r#"{"action": "repeal"}"#,
+                "1. The fee is 5. 2. The fee is not 5.",
+                GovernanceLayer::L2Operational,
+            ),
+        ];
+
+        for p in &cases {
+            let (verdict, timings) = verify_proposal_timed(p);
+            assert_eq!(verdict, verify_proposal(p), "{}", p.text);
+
+            // Every stage is reported, and the total covers each of them
+            for stage in [
+                timings.canonicalization,
+                timings.complexity,
+                timings.paradox,
+                timings.cycles,
+            ] {
+                assert!(stage <= timings.total());
+            }
+        }
+    }
+
+    #[test]
+    fn test_timed_hard_fails() {
+        let broken = proposal("{not json", "Broken", GovernanceLayer::L2Operational);
+        let (verdict, timings) = verify_proposal_timed(&broken);
+        assert_eq!(verdict, verify_proposal(&broken));
+        assert_eq!(timings.complexity, Duration::ZERO);
+
+        let l0 = proposal("{}", "Change the axioms", GovernanceLayer::L0Immutable);
+        let (verdict, timings) = verify_proposal_timed(&l0);
+        assert_eq!(verdict, verify_proposal(&l0));
+        assert_eq!(timings, StageTimings::default());
+    }
+}
@@ -0,0 +1,225 @@
+//! Computation Traces
+//!
+//! A fraud proof challenger has to show exactly what an honest oracle
+//! computed, step by step. `verify_proposal_traced` runs the standard
+//! pipeline and returns a `FraudProofWitness` holding the hex canonical
+//! payload and an ordered, human-readable trace of each step: the
+//! canonical hash, the compressed byte count, the paradox patterns that
+//! matched and the strongly connected components examined.
+//!
+//! Trace lines are for people and are not a stable format; the verdict is
+//! identical to `verify_proposal`.
+
+use super::canonicalize::{fold_compatibility, parse_ast};
+use super::cycles::strongly_connected_components;
+use super::{
+    canonicalize, detect_clause_paradox, find_paradox_matches, verify_proposal_with, FailureReason,
+    VerificationConfig,
+};
+use crate::types::{ChannelAVerdict, FraudProofWitness, GovernanceLayer, Proposal};
+
+/// Verify a proposal, recording each pipeline step as a fraud proof witness
+///
+/// If canonicalization fails the witness payload is empty and the trace
+/// ends at the failing step.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::channel_a::verify_proposal_traced;
+/// use constitution_dao_core::{verify_proposal, GovernanceLayer, Proposal};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+///     "Transfer 100 tokens".to_string(),
+///     GovernanceLayer::L2Operational,
+/// );
+///
+/// let (verdict, witness) = verify_proposal_traced(&proposal);
+/// assert_eq!(verdict, verify_proposal(&proposal));
+/// assert!(witness.computation_trace.iter().any(|line| line.starts_with("complexity:")));
+/// ```
+pub fn verify_proposal_traced(proposal: &Proposal) -> (ChannelAVerdict, FraudProofWitness) {
+    let config = VerificationConfig::default();
+    let detailed = verify_proposal_with(proposal, &config);
+    let mut witness = FraudProofWitness {
+        canonical_payload: String::new(),
+        computation_trace: vec![format!("layer: {:?}", proposal.layer)],
+    };
+    let trace = &mut witness.computation_trace;
+
+    // Step 0
+    if proposal.layer == GovernanceLayer::L0Immutable {
+        trace.push("rejected: L0 is not targetable by proposals".to_string());
+        trace.push(verdict_line(&detailed.verdict, detailed.failure_reason));
+        return (detailed.verdict, witness);
+    }
+
+    // Step 1
+    let canonical = parse_ast(&proposal.logic_ast)
+        .and_then(|ast| canonicalize(proposal).map(|payload| (ast, payload)));
+    let (ast, payload) = match canonical {
+        Ok(canonical) => canonical,
+        Err(e) => {
+            trace.push(format!("canonicalization failed: {}", e));
+            trace.push(verdict_line(&detailed.verdict, detailed.failure_reason));
+            return (detailed.verdict, witness);
+        }
+    };
+    witness.canonical_payload = hex::encode(&payload.bytes);
+    trace.push(format!("canonical hash: {}", payload.hash_hex()));
+    trace.push(format!(
+        "payload: {} bytes, form version {}",
+        payload.bytes.len(),
+        payload.version
+    ));
+
+    // Step 2: the score is the compressed size of the payload
+    trace.push(format!(
+        "complexity: {} compressed bytes (limit {})",
+        detailed.verdict.complexity_score,
+        config.complexity_limits.limit_for(proposal.layer)
+    ));
+
+    // Step 3
+    if detect_clause_paradox(&fold_compatibility(&proposal.text)) {
+        trace.push("paradox: contradiction across numbered clauses".to_string());
+    }
+    let matches = find_paradox_matches(payload.normalized_text());
+    if matches.is_empty() {
+        trace.push("paradox: no pattern matched".to_string());
+    }
+    for m in matches {
+        trace.push(format!(
+            "paradox: pattern {} ({}) matched {:?}",
+            m.pattern_index, m.pattern_id, m.matched
+        ));
+    }
+
+    // Step 4
+    match strongly_connected_components(&ast, &config.cycle_options()) {
+        Ok(sccs) => {
+            trace.push(format!(
+                "cycles: {} strongly connected components",
+                sccs.len()
+            ));
+            for (nodes, cyclic) in sccs {
+                let kind = if cyclic { "cyclic" } else { "acyclic" };
+                trace.push(format!("scc: [{}] {}", nodes.join(", "), kind));
+            }
+        }
+        Err(e) => trace.push(format!("cycles: {}; counted as cyclic", e)),
+    }
+    if let Some(path) = &detailed.cycle_path {
+        trace.push(format!("cycle path: {} -> {}", path.join(" -> "), path[0]));
+    }
+
+    trace.push(verdict_line(&detailed.verdict, detailed.failure_reason));
+    (detailed.verdict, witness)
+}
+
+fn verdict_line(verdict: &ChannelAVerdict, reason: Option<FailureReason>) -> String {
+    match reason {
+        None => "verdict: pass".to_string(),
+        Some(reason) => format!(
+            "verdict: fail ({:?}; paradox_found={}, cycle_found={})",
+            reason, verdict.paradox_found, verdict.cycle_found
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel_a::{get_paradox_pattern_ids, verify_proposal};
+
+    fn proposal(ast: &str, text: &str, layer: GovernanceLayer) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            layer,
+        )
+    }
+
+    #[test]
+    fn test_trace_records_complexity_and_pattern() {
+        let p = proposal(
+            r#"{"action": "conditional"}"#,
+            "This proposal passes iff it fails",
+            GovernanceLayer::L2Operational,
+        );
+        let (verdict, witness) = verify_proposal_traced(&p);
+        assert_eq!(verdict, verify_proposal(&p));
+        assert!(verdict.paradox_found);
+
+        let trace = &witness.computation_trace;
+        let payload = canonicalize(&p).unwrap();
+        assert_eq!(witness.canonical_payload, hex::encode(&payload.bytes));
+        assert!(trace.contains(&format!("canonical hash: {}", payload.hash_hex())));
+        assert!(trace
+            .iter()
+            .any(|line| line.starts_with(&format!("complexity: {} ", verdict.complexity_score))));
+
+        let index = get_paradox_pattern_ids()
+            .iter()
+            .position(|&id| id == "iff_self_reference")
+            .unwrap();
+        assert!(trace
+            .iter()
+            .any(|line| line
+                .starts_with(&format!("paradox: pattern {} (iff_self_reference)", index))));
+        assert_eq!(
+            trace.last().unwrap(),
+            "verdict: fail (ParadoxFound; paradox_found=true, cycle_found=false)"
+        );
+    }
+
+    #[test]
+    fn test_trace_lists_sccs_in_order() {
+        let p = proposal(
+            r#"{"c": {"value": 1}, "a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#,
+            "Circular budget",
+            GovernanceLayer::L3Execution,
+        );
+        let (verdict, witness) = verify_proposal_traced(&p);
+        assert!(verdict.cycle_found);
+
+        let trace = &witness.computation_trace;
+        let start = trace
+            .iter()
+            .position(|line| line == "cycles: 2 strongly connected components")
+            .unwrap();
+        assert_eq!(
+            trace[start + 1..start + 4],
+            [
+                "scc: [a, b] cyclic",
+                "scc: [c] acyclic",
+                "cycle path: a -> b -> a"
+            ]
+        );
+        assert!(trace.contains(&"paradox: no pattern matched".to_string()));
+    }
+
+    #[test]
+    fn test_trace_stops_at_hard_fails() {
+        let broken = proposal("{not json", "Broken", GovernanceLayer::L2Operational);
+        let (verdict, witness) = verify_proposal_traced(&broken);
+        assert_eq!(verdict, verify_proposal(&broken));
+        assert!(witness.canonical_payload.is_empty());
+        assert!(witness.computation_trace[1].starts_with("canonicalization failed"));
+        assert_eq!(witness.computation_trace.len(), 3);
+
+        let l0 = proposal("{}", "Change the axioms", GovernanceLayer::L0Immutable);
+        let (verdict, witness) = verify_proposal_traced(&l0);
+        assert_eq!(verdict, verify_proposal(&l0));
+        assert_eq!(
+            witness.computation_trace[..2],
+            [
+                "layer: L0Immutable",
+                "rejected: L0 is not targetable by proposals"
+            ]
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Oracle Verdict Commitments
+//!
+//! Standardizes what an oracle signs when it reports a Channel A verdict,
+//! so signatures are comparable network-wide:
+//!
+//! ```text
+//! commitment = sha256(canonical_hash || verdict.to_canonical_bytes())
+//! ```
+//!
+//! For the commit-reveal report flow (spec v5.0 §4.2), the commitment is
+//! sealed with a secret salt during the commit phase and opened by
+//! revealing the verdict and salt.
+//!
+//! An oracle attesting to a whole batch (e.g. an epoch's work) commits to
+//! its canonical hashes in sorted order:
+//!
+//! ```text
+//! batch_commitment = sha256(sorted(canonical_hash_1, ..., canonical_hash_n))
+//! ```
+
+use sha2::{Digest, Sha256};
+
+use crate::channel_a::{canonicalize, CanonicalizeError};
+use crate::types::{ChannelAVerdict, Proposal};
+
+/// Compute the commitment an oracle signs for a verdict on a proposal
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::commitment::{proposal_commitment, verify_commitment};
+/// use constitution_dao_core::ChannelAVerdict;
+///
+/// let hash = [7u8; 32];
+/// let verdict = ChannelAVerdict::pass(120);
+/// let commitment = proposal_commitment(hash, &verdict);
+/// assert!(verify_commitment(&commitment, hash, &verdict));
+/// ```
+pub fn proposal_commitment(canonical_hash: [u8; 32], verdict: &ChannelAVerdict) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(canonical_hash);
+    hasher.update(verdict.to_canonical_bytes());
+    hasher.finalize().into()
+}
+
+/// Check that a commitment matches a proposal hash and verdict
+pub fn verify_commitment(
+    commitment: &[u8; 32],
+    canonical_hash: [u8; 32],
+    verdict: &ChannelAVerdict,
+) -> bool {
+    proposal_commitment(canonical_hash, verdict) == *commitment
+}
+
+/// Seal a commitment with a secret salt for the commit phase
+///
+/// `sealed = sha256(commitment || salt)`
+pub fn seal_commitment(commitment: &[u8; 32], salt: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(commitment);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Check a reveal (hash, verdict, salt) against a sealed commitment
+pub fn verify_reveal(
+    sealed: &[u8; 32],
+    canonical_hash: [u8; 32],
+    verdict: &ChannelAVerdict,
+    salt: &[u8; 32],
+) -> bool {
+    seal_commitment(&proposal_commitment(canonical_hash, verdict), salt) == *sealed
+}
+
+/// Compute a single commitment over a batch of proposals
+///
+/// Each proposal is canonicalized and the hashes are sorted, so the
+/// commitment does not depend on input order. A proposal appearing twice
+/// contributes its hash twice. Fails if any proposal cannot be
+/// canonicalized.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::commitment::batch_commitment;
+/// use constitution_dao_core::{GovernanceLayer, Proposal};
+///
+/// let proposal = |text: &str| {
+///     Proposal::new("rAddr".to_string(), "{}".to_string(), text.to_string(), GovernanceLayer::L2Operational)
+/// };
+/// let (a, b) = (proposal("Raise the quorum"), proposal("Lower the fee"));
+///
+/// let forward = batch_commitment(&[a.clone(), b.clone()]).unwrap();
+/// assert_eq!(forward, batch_commitment(&[b, a]).unwrap());
+/// ```
+pub fn batch_commitment(proposals: &[Proposal]) -> Result<[u8; 32], CanonicalizeError> {
+    let mut hashes = proposals
+        .iter()
+        .map(|proposal| canonicalize(proposal).map(|payload| payload.hash))
+        .collect::<Result<Vec<_>, _>>()?;
+    hashes.sort_unstable();
+
+    let mut hasher = Sha256::new();
+    for hash in &hashes {
+        hasher.update(hash);
+    }
+    Ok(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::GovernanceLayer;
+
+    const HASH: [u8; 32] = [0xAB; 32];
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_matching_commitment() {
+        let verdict = ChannelAVerdict::fail(500, true, false);
+        let commitment = proposal_commitment(HASH, &verdict);
+
+        assert!(verify_commitment(&commitment, HASH, &verdict));
+        assert_eq!(commitment, proposal_commitment(HASH, &verdict.clone()));
+    }
+
+    #[test]
+    fn test_altered_verdict_mismatches() {
+        let verdict = ChannelAVerdict::fail(500, true, false);
+        let commitment = proposal_commitment(HASH, &verdict);
+
+        assert!(!verify_commitment(
+            &commitment,
+            HASH,
+            &ChannelAVerdict::pass(500)
+        ));
+        assert!(!verify_commitment(
+            &commitment,
+            HASH,
+            &ChannelAVerdict::fail(501, true, false)
+        ));
+        assert!(!verify_commitment(
+            &commitment,
+            HASH,
+            &ChannelAVerdict::fail(500, true, true)
+        ));
+    }
+
+    #[test]
+    fn test_altered_hash_mismatches() {
+        let verdict = ChannelAVerdict::pass(120);
+        let commitment = proposal_commitment(HASH, &verdict);
+
+        let mut other = HASH;
+        other[31] ^= 1;
+        assert!(!verify_commitment(&commitment, other, &verdict));
+    }
+
+    #[test]
+    fn test_commit_reveal_round_trip() {
+        let verdict = ChannelAVerdict::pass(120);
+        let salt = [0x5A; 32];
+        let sealed = seal_commitment(&proposal_commitment(HASH, &verdict), &salt);
+
+        assert!(verify_reveal(&sealed, HASH, &verdict, &salt));
+        assert!(!verify_reveal(&sealed, HASH, &verdict, &[0u8; 32]));
+        assert!(!verify_reveal(
+            &sealed,
+            HASH,
+            &ChannelAVerdict::fail(120, false, true),
+            &salt
+        ));
+    }
+
+    #[test]
+    fn test_batch_commitment_ignores_order() {
+        let batch = [
+            proposal(
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            proposal(
+                r#"{"action": "set_quorum", "value": 0.15}"#,
+                "Raise the quorum",
+            ),
+            proposal("{}", "Signal support"),
+        ];
+        let commitment = batch_commitment(&batch).unwrap();
+
+        let mut reversed = batch.clone();
+        reversed.reverse();
+        assert_eq!(batch_commitment(&reversed).unwrap(), commitment);
+
+        // Semantically identical proposals canonicalize to the same hash
+        let mut reformatted = batch.clone();
+        reformatted[0] = proposal(
+            r#"{"amount":100,"action":"transfer"}"#,
+            "transfer  100 tokens",
+        );
+        assert_eq!(batch_commitment(&reformatted).unwrap(), commitment);
+    }
+
+    #[test]
+    fn test_batch_commitment_changes_with_contents() {
+        let batch = vec![
+            proposal(
+                r#"{"action": "transfer", "amount": 100}"#,
+                "Transfer 100 tokens",
+            ),
+            proposal("{}", "Signal support"),
+        ];
+        let commitment = batch_commitment(&batch).unwrap();
+
+        let mut extended = batch.clone();
+        extended.push(proposal(r#"{"action": "mint"}"#, "Mint"));
+        assert_ne!(batch_commitment(&extended).unwrap(), commitment);
+
+        // A repeated proposal is counted again
+        let mut repeated = batch.clone();
+        repeated.push(batch[0].clone());
+        assert_ne!(batch_commitment(&repeated).unwrap(), commitment);
+        assert_ne!(batch_commitment(&batch[..1]).unwrap(), commitment);
+
+        let mut broken = batch;
+        broken.push(proposal("{not json", "Broken"));
+        assert!(batch_commitment(&broken).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+//! Multi-Oracle Consensus
+//!
+//! Oracles report Channel A verdicts independently. `aggregate_verdicts`
+//! combines the reports for one proposal:
+//!
+//! - The consensus verdict is the exact verdict reported by a strict
+//!   plurality of oracles; a tie for first place has no consensus
+//! - Each field also gets its own strict-plurality value, so a split on
+//!   complexity alone still shows agreement on `pass`
+//! - Quorum is met when the oracles backing the consensus verdict make up
+//!   at least `ORACLE_QUORUM` of the active oracle set, so low turnout
+//!   fails quorum even when every reporter agrees
+//! - Oracles whose verdict differs from the consensus verdict are
+//!   dissenters and candidates for fraud-proof follow-up
+//!
+//! Only the first report from each oracle counts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::types::{config, ChannelAVerdict};
+
+/// Aggregated outcome of the oracle reports for one proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusResult {
+    /// Verdict reported by a strict plurality; `None` on a tie or no reports
+    pub verdict: Option<ChannelAVerdict>,
+    /// Plurality value of `pass`
+    pub pass: Option<bool>,
+    /// Plurality value of `complexity_score`
+    pub complexity_score: Option<u64>,
+    /// Plurality value of `paradox_found`
+    pub paradox_found: Option<bool>,
+    /// Plurality value of `cycle_found`
+    pub cycle_found: Option<bool>,
+    /// Oracles that reported (after dropping duplicates)
+    pub participants: usize,
+    /// Oracles that reported the consensus verdict
+    pub support: usize,
+    /// Support reached `ORACLE_QUORUM` of the active set
+    pub quorum_met: bool,
+    /// Oracles whose verdict differs from the consensus verdict, in report order
+    pub dissenters: Vec<String>,
+}
+
+/// Aggregate verdicts against the configured active oracle set size
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::consensus::aggregate_verdicts;
+/// use constitution_dao_core::ChannelAVerdict;
+///
+/// let reports = vec![
+///     ("oracle-1".to_string(), ChannelAVerdict::pass(80)),
+///     ("oracle-2".to_string(), ChannelAVerdict::pass(80)),
+///     ("oracle-3".to_string(), ChannelAVerdict::fail(80, true, false)),
+/// ];
+///
+/// let result = aggregate_verdicts(&reports);
+/// assert_eq!(result.verdict, Some(ChannelAVerdict::pass(80)));
+/// assert_eq!(result.dissenters, vec!["oracle-3"]);
+/// assert!(!result.quorum_met); // 2 of 101 active oracles
+/// ```
+pub fn aggregate_verdicts(verdicts: &[(String, ChannelAVerdict)]) -> ConsensusResult {
+    aggregate_verdicts_with(verdicts, config::ACTIVE_ORACLE_SET_SIZE)
+}
+
+/// Aggregate verdicts against an explicit active oracle set size
+pub fn aggregate_verdicts_with(
+    verdicts: &[(String, ChannelAVerdict)],
+    active_set_size: usize,
+) -> ConsensusResult {
+    let mut seen = HashSet::new();
+    let reports: Vec<&(String, ChannelAVerdict)> = verdicts
+        .iter()
+        .filter(|(oracle, _)| seen.insert(oracle.as_str()))
+        .collect();
+
+    // Verdicts are keyed by their canonical encoding, which is ordered
+    let consensus = plurality(reports.iter().map(|(_, v)| v.to_canonical_bytes()));
+    let verdict = consensus.and_then(|key| {
+        reports
+            .iter()
+            .map(|(_, v)| v)
+            .find(|v| v.to_canonical_bytes() == key)
+            .cloned()
+    });
+
+    let support = verdict
+        .as_ref()
+        .map_or(0, |c| reports.iter().filter(|(_, v)| v == c).count());
+    let dissenters = match &verdict {
+        Some(c) => reports
+            .iter()
+            .filter(|(_, v)| v != c)
+            .map(|(oracle, _)| oracle.clone())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    ConsensusResult {
+        pass: plurality(reports.iter().map(|(_, v)| v.pass)),
+        complexity_score: plurality(reports.iter().map(|(_, v)| v.complexity_score)),
+        paradox_found: plurality(reports.iter().map(|(_, v)| v.paradox_found)),
+        cycle_found: plurality(reports.iter().map(|(_, v)| v.cycle_found)),
+        participants: reports.len(),
+        support,
+        quorum_met: support > 0 && support as f64 >= config::ORACLE_QUORUM * active_set_size as f64,
+        dissenters,
+        verdict,
+    }
+}
+
+/// Value with strictly the highest count; `None` on a tie or no values
+fn plurality<T: Ord>(values: impl Iterator<Item = T>) -> Option<T> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0usize) += 1;
+    }
+
+    let max = *counts.values().max()?;
+    let mut leaders = counts.into_iter().filter(|&(_, count)| count == max);
+    match (leaders.next(), leaders.next()) {
+        (Some((value, _)), None) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports(verdicts: &[ChannelAVerdict]) -> Vec<(String, ChannelAVerdict)> {
+        verdicts
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("oracle-{}", i), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_unanimous_agreement() {
+        let result = aggregate_verdicts_with(&reports(&vec![ChannelAVerdict::pass(80); 3]), 3);
+        assert_eq!(result.verdict, Some(ChannelAVerdict::pass(80)));
+        assert_eq!(result.pass, Some(true));
+        assert_eq!(result.support, 3);
+        assert!(result.quorum_met);
+        assert!(result.dissenters.is_empty());
+    }
+
+    #[test]
+    fn test_single_dissenter() {
+        let mut verdicts = vec![ChannelAVerdict::pass(80); 4];
+        verdicts.insert(2, ChannelAVerdict::pass(81));
+        let result = aggregate_verdicts_with(&reports(&verdicts), 5);
+
+        assert_eq!(result.verdict, Some(ChannelAVerdict::pass(80)));
+        assert_eq!(result.dissenters, vec!["oracle-2"]);
+        assert_eq!(result.support, 4);
+        assert!(result.quorum_met);
+        // The dissent is on complexity only
+        assert_eq!(result.pass, Some(true));
+        assert_eq!(result.complexity_score, Some(80));
+    }
+
+    #[test]
+    fn test_tie() {
+        let result = aggregate_verdicts_with(
+            &reports(&[
+                ChannelAVerdict::pass(80),
+                ChannelAVerdict::fail(80, true, false),
+            ]),
+            2,
+        );
+        assert_eq!(result.verdict, None);
+        assert_eq!(result.pass, None);
+        assert_eq!(result.paradox_found, None);
+        // Fields everyone agrees on still have a value
+        assert_eq!(result.complexity_score, Some(80));
+        assert_eq!(result.cycle_found, Some(false));
+        assert_eq!(result.support, 0);
+        assert!(!result.quorum_met);
+        assert!(result.dissenters.is_empty());
+    }
+
+    #[test]
+    fn test_sub_quorum_turnout() {
+        // 67 of 101 agree: just below 2/3
+        let result = aggregate_verdicts(&reports(&vec![ChannelAVerdict::pass(80); 67]));
+        assert_eq!(result.verdict, Some(ChannelAVerdict::pass(80)));
+        assert!(!result.quorum_met);
+
+        let result = aggregate_verdicts(&reports(&vec![ChannelAVerdict::pass(80); 68]));
+        assert!(result.quorum_met);
+
+        let empty = aggregate_verdicts(&[]);
+        assert_eq!(empty.verdict, None);
+        assert_eq!(empty.participants, 0);
+        assert!(!empty.quorum_met);
+    }
+
+    #[test]
+    fn test_duplicate_reports_ignored() {
+        let reports = vec![
+            ("oracle-a".to_string(), ChannelAVerdict::pass(80)),
+            (
+                "oracle-b".to_string(),
+                ChannelAVerdict::fail(80, true, false),
+            ),
+            (
+                "oracle-b".to_string(),
+                ChannelAVerdict::fail(80, true, false),
+            ),
+            ("oracle-c".to_string(), ChannelAVerdict::pass(80)),
+        ];
+        let result = aggregate_verdicts_with(&reports, 3);
+        assert_eq!(result.participants, 3);
+        assert_eq!(result.verdict, Some(ChannelAVerdict::pass(80)));
+        assert_eq!(result.dissenters, vec!["oracle-b"]);
+    }
+}
@@ -0,0 +1,118 @@
+//! Recent Proposal Deduplication
+//!
+//! Resubmitting an identical proposal costs the proposer a transaction but
+//! costs every oracle a full verification. `RecentProposals` remembers the
+//! canonical hashes (proposal IDs) seen over a sliding window of epochs so
+//! replays and spam can be rejected before verification.
+//!
+//! A hash first seen in epoch `e` is a duplicate through epoch
+//! `e + window`; from epoch `e + window + 1` it is evicted and accepted
+//! again. Resubmissions do not refresh the recorded epoch, so repeated
+//! spam cannot keep a proposal blocked past its window.
+
+use std::collections::HashMap;
+
+/// Canonical hashes seen within a sliding window of epochs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecentProposals {
+    window: u64,
+    seen: HashMap<[u8; 32], u64>,
+}
+
+impl RecentProposals {
+    /// Track hashes for `window` epochs after the epoch they were first seen
+    pub fn new(window: u64) -> Self {
+        Self {
+            window,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Number of epochs a hash is remembered after first being seen
+    pub fn window(&self) -> u64 {
+        self.window
+    }
+
+    /// Whether `hash` was already seen within the window
+    ///
+    /// Evicts expired entries first. A hash that is not a duplicate is
+    /// recorded as seen in `current_epoch`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::dedup::RecentProposals;
+    ///
+    /// let mut recent = RecentProposals::new(2);
+    /// assert!(!recent.is_duplicate([7u8; 32], 10));
+    /// assert!(recent.is_duplicate([7u8; 32], 12));
+    /// assert!(!recent.is_duplicate([7u8; 32], 13));
+    /// ```
+    pub fn is_duplicate(&mut self, hash: [u8; 32], current_epoch: u64) -> bool {
+        self.evict(current_epoch);
+        if self.seen.contains_key(&hash) {
+            return true;
+        }
+        self.seen.insert(hash, current_epoch);
+        false
+    }
+
+    /// Drop entries first seen more than `window` epochs before `current_epoch`
+    pub fn evict(&mut self, current_epoch: u64) {
+        let window = self.window;
+        self.seen
+            .retain(|_, epoch| current_epoch.saturating_sub(*epoch) <= window);
+    }
+
+    /// Number of hashes currently remembered
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Whether no hashes are remembered
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fresh_proposal_not_duplicate() {
+        let mut recent = RecentProposals::new(3);
+        assert!(!recent.is_duplicate([1u8; 32], 5));
+        assert!(!recent.is_duplicate([2u8; 32], 5));
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[test]
+    fn test_immediate_resubmission_is_duplicate() {
+        let mut recent = RecentProposals::new(3);
+        assert!(!recent.is_duplicate([1u8; 32], 5));
+        assert!(recent.is_duplicate([1u8; 32], 5));
+        assert!(recent.is_duplicate([1u8; 32], 8));
+
+        // A zero window still rejects resubmission within the same epoch
+        let mut recent = RecentProposals::new(0);
+        assert!(!recent.is_duplicate([1u8; 32], 5));
+        assert!(recent.is_duplicate([1u8; 32], 5));
+        assert!(!recent.is_duplicate([1u8; 32], 6));
+    }
+
+    #[test]
+    fn test_resubmission_after_window_not_duplicate() {
+        let mut recent = RecentProposals::new(3);
+        assert!(!recent.is_duplicate([1u8; 32], 5));
+        assert!(!recent.is_duplicate([2u8; 32], 7));
+
+        // Duplicates do not refresh the recorded epoch
+        assert!(recent.is_duplicate([1u8; 32], 7));
+        assert!(!recent.is_duplicate([1u8; 32], 9));
+        assert!(recent.is_duplicate([2u8; 32], 9));
+
+        recent.evict(u64::MAX);
+        assert!(recent.is_empty());
+    }
+}
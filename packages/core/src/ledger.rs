@@ -0,0 +1,213 @@
+//! XRPL Ledger Ingest
+//!
+//! An oracle decoding proposals straight from ledger transactions receives
+//! the fields as separate transaction components. `ProposalFromLedger`
+//! collects them as they are decoded, validates them and produces the
+//! `Proposal` together with its canonical payload:
+//!
+//! - `Account`: the proposer's classic XRPL address
+//! - `MemoData`: the hex-decoded memo bytes, a JSON object with string
+//!   fields `logic_ast` and `text` (other fields, such as `id`, are ignored)
+//! - a layer annotation supplied by the caller
+//!
+//! The resulting proposal's `id` is its canonical hash.
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::channel_a::{canonicalize_and_id, CanonicalPayload, CanonicalizeError};
+use crate::types::{is_valid_xrpl_address, GovernanceLayer, Proposal};
+
+/// Errors from assembling a proposal out of ledger components
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[error("Transaction has no Account")]
+    MissingAccount,
+    #[error("Account {0:?} is not a valid classic XRPL address")]
+    InvalidAccount(String),
+    #[error("Transaction has no proposal memo")]
+    MissingMemo,
+    #[error("Malformed proposal memo: {0}")]
+    MalformedMemo(String),
+    #[error("No governance layer annotation")]
+    MissingLayer,
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
+
+/// Proposal fields carried in the memo
+#[derive(Deserialize)]
+struct ProposalMemo {
+    logic_ast: String,
+    text: String,
+}
+
+/// A proposal assembled from a ledger transaction
+#[derive(Debug, Clone)]
+pub struct LedgerProposal {
+    /// The proposal, with `id` set to the canonical hash
+    pub proposal: Proposal,
+    /// Its canonical payload
+    pub payload: CanonicalPayload,
+}
+
+/// Builder assembling a proposal from decoded transaction components
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::ledger::ProposalFromLedger;
+/// use constitution_dao_core::GovernanceLayer;
+///
+/// let memo = br#"{"logic_ast": "{\"action\": \"transfer\"}", "text": "Transfer tokens"}"#;
+/// let assembled = ProposalFromLedger::new()
+///     .account("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+///     .memo_data(memo)
+///     .layer(GovernanceLayer::L2Operational)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(assembled.proposal.id, assembled.payload.hash);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProposalFromLedger {
+    account: Option<String>,
+    memo_data: Option<Vec<u8>>,
+    layer: Option<GovernanceLayer>,
+}
+
+impl ProposalFromLedger {
+    /// Start with no components
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the transaction's `Account` (the proposer)
+    pub fn account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Set the hex-decoded `MemoData` bytes
+    pub fn memo_data(mut self, memo_data: &[u8]) -> Self {
+        self.memo_data = Some(memo_data.to_vec());
+        self
+    }
+
+    /// Set the governance layer the proposal targets
+    pub fn layer(mut self, layer: GovernanceLayer) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Validate the components and canonicalize the proposal
+    pub fn build(self) -> Result<LedgerProposal, LedgerError> {
+        let account = self.account.ok_or(LedgerError::MissingAccount)?;
+        if !is_valid_xrpl_address(&account) {
+            return Err(LedgerError::InvalidAccount(account));
+        }
+
+        let memo_data = self.memo_data.ok_or(LedgerError::MissingMemo)?;
+        let memo: ProposalMemo = serde_json::from_slice(&memo_data)
+            .map_err(|e| LedgerError::MalformedMemo(e.to_string()))?;
+        let layer = self.layer.ok_or(LedgerError::MissingLayer)?;
+
+        let (proposal, payload) =
+            canonicalize_and_id(&Proposal::new(account, memo.logic_ast, memo.text, layer))?;
+
+        Ok(LedgerProposal { proposal, payload })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel_a::canonicalize;
+
+    const ACCOUNT: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    const MEMO: &[u8] = br#"{"id": "ignored", "logic_ast": "{\"amount\": 100, \"action\": \"transfer\"}", "text": "Transfer 100 tokens"}"#;
+
+    #[test]
+    fn test_well_formed_transaction() {
+        let assembled = ProposalFromLedger::new()
+            .memo_data(MEMO)
+            .layer(GovernanceLayer::L3Execution)
+            .account(ACCOUNT)
+            .build()
+            .unwrap();
+
+        let expected = Proposal::new(
+            ACCOUNT.to_string(),
+            r#"{"amount": 100, "action": "transfer"}"#.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L3Execution,
+        );
+        assert_eq!(assembled.proposal.proposer, ACCOUNT);
+        assert_eq!(assembled.proposal.layer, GovernanceLayer::L3Execution);
+        assert_eq!(
+            assembled.payload.bytes,
+            canonicalize(&expected).unwrap().bytes
+        );
+        assert_eq!(assembled.proposal.id, assembled.payload.hash);
+    }
+
+    #[test]
+    fn test_missing_memo() {
+        let result = ProposalFromLedger::new()
+            .account(ACCOUNT)
+            .layer(GovernanceLayer::L2Operational)
+            .build();
+        assert!(matches!(result, Err(LedgerError::MissingMemo)));
+    }
+
+    #[test]
+    fn test_malformed_memo() {
+        for memo in [
+            &b"not json"[..],
+            br#"{"logic_ast": "{}"}"#,
+            br#"{"logic_ast": {}, "text": "t"}"#,
+        ] {
+            let result = ProposalFromLedger::new()
+                .account(ACCOUNT)
+                .memo_data(memo)
+                .layer(GovernanceLayer::L2Operational)
+                .build();
+            assert!(matches!(result, Err(LedgerError::MalformedMemo(_))));
+        }
+
+        // Memo structure is fine but the AST inside is not
+        let result = ProposalFromLedger::new()
+            .account(ACCOUNT)
+            .memo_data(br#"{"logic_ast": "{oops", "text": "t"}"#)
+            .layer(GovernanceLayer::L2Operational)
+            .build();
+        assert!(matches!(result, Err(LedgerError::Canonicalize(_))));
+    }
+
+    #[test]
+    fn test_malformed_account() {
+        // Bad checksum (last character changed), wrong alphabet, missing
+        for account in [
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi",
+            "0xHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "",
+        ] {
+            let result = ProposalFromLedger::new()
+                .account(account)
+                .memo_data(MEMO)
+                .layer(GovernanceLayer::L2Operational)
+                .build();
+            assert!(
+                matches!(result, Err(LedgerError::InvalidAccount(_))),
+                "{}",
+                account
+            );
+        }
+
+        let result = ProposalFromLedger::new()
+            .memo_data(MEMO)
+            .layer(GovernanceLayer::L2Operational)
+            .build();
+        assert!(matches!(result, Err(LedgerError::MissingAccount)));
+    }
+}
@@ -11,6 +11,17 @@
 //! - **Paradox Detection**: Regex-based detection of self-referential paradoxes
 //! - **Cycle Detection**: Tarjan's SCC algorithm for dependency cycle detection
 //!
+//! The `routing` module combines Channel A and Channel B verdicts into the
+//! next lifecycle step, `commitment` defines what oracles sign, `consensus`
+//! aggregates the verdicts reported by many oracles, and `audit`
+//! keeps a hash-chained history of the verdicts an oracle produced. `auth`
+//! optionally binds a verdict to a signature from the claimed proposer.
+//! `schedule` holds the block arithmetic for oracle epochs and report windows,
+//! `ledger` assembles proposals from decoded XRPL transactions, `review`
+//! estimates jury review effort for scheduling, `wire` holds the
+//! compact binary proposal encoding used for gossip, and `dedup` rejects
+//! proposals resubmitted within a window of recent epochs.
+//!
 //! # NAPI Bindings
 //!
 //! When compiled with the `napi` feature, this crate provides native Node.js bindings
@@ -27,14 +38,26 @@
 //! );
 //! ```
 
-pub mod types;
+pub mod audit;
+pub mod auth;
 pub mod channel_a;
+pub mod commitment;
+pub mod consensus;
+pub mod dedup;
+pub mod ledger;
+pub mod review;
+pub mod routing;
+pub mod schedule;
+pub mod types;
+pub mod wire;
 
 #[cfg(feature = "napi")]
 pub mod napi;
 
+pub use channel_a::{
+    self_test, verify_proposal, verify_proposal_with_config, verify_value, warm_up,
+};
 pub use types::*;
-pub use channel_a::verify_proposal;
 
 // Re-export NAPI bindings when feature is enabled
 #[cfg(feature = "napi")]
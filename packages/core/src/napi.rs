@@ -8,12 +8,26 @@ use napi::bindgen_prelude::*;
 #[cfg(feature = "napi")]
 use napi_derive::napi;
 
-use crate::channel_a::{canonicalize, compute_complexity, detect_cycles, detect_paradox};
+use crate::channel_a::{
+    canonicalize, compute_complexity, detect_cycles, detect_paradox, AstMetrics as RustAstMetrics,
+    BatchSummary as RustBatchSummary, ComplexityBreakdown as RustComplexityBreakdown,
+    ComplexityHeadroom as RustComplexityHeadroom, ComplexityUnit as RustComplexityUnit,
+    Containment as RustContainment, ContainmentResult as RustContainmentResult,
+    PassReport as RustPassReport, StageTimings as RustStageTimings, COMPLEXITY_FAILURE,
+};
+use crate::review::ReviewEffort as RustReviewEffort;
 use crate::types::{
-    ChannelAVerdict as RustChannelAVerdict, FrictionParams as RustFrictionParams,
-    GovernanceLayer as RustGovernanceLayer, Proposal as RustProposal,
+    ChannelAVerdict as RustChannelAVerdict, FrictionDelta as RustFrictionDelta,
+    FrictionParams as RustFrictionParams, GovernanceLayer as RustGovernanceLayer,
+    Proposal as RustProposal,
 };
 
+/// Convert a u64 to i64, saturating at i64::MAX instead of wrapping negative
+#[cfg(feature = "napi")]
+fn saturating_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
 /// JavaScript-compatible Channel A verdict
 #[cfg(feature = "napi")]
 #[napi(object)]
@@ -33,17 +47,59 @@ impl From<RustChannelAVerdict> for ChannelAVerdict {
     fn from(v: RustChannelAVerdict) -> Self {
         Self {
             pass: v.pass,
-            complexity_score: v.complexity_score as i64,
+            // COMPLEXITY_FAILURE (u64::MAX) must not wrap to -1 in JavaScript
+            complexity_score: saturating_i64(v.complexity_score),
             paradox_found: v.paradox_found,
             cycle_found: v.cycle_found,
         }
     }
 }
 
+/// JavaScript-compatible per-stage verification timings, in milliseconds
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct StageTimings {
+    /// Parsing and canonicalizing the proposal
+    pub canonicalization_ms: f64,
+    /// Compressing the payload for the complexity score
+    pub complexity_ms: f64,
+    /// Paradox detection
+    pub paradox_ms: f64,
+    /// Dependency cycle detection
+    pub cycles_ms: f64,
+    /// Sum of the four stages
+    pub total_ms: f64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustStageTimings> for StageTimings {
+    fn from(t: RustStageTimings) -> Self {
+        Self {
+            canonicalization_ms: t.canonicalization.as_secs_f64() * 1000.0,
+            complexity_ms: t.complexity.as_secs_f64() * 1000.0,
+            paradox_ms: t.paradox.as_secs_f64() * 1000.0,
+            cycles_ms: t.cycles.as_secs_f64() * 1000.0,
+            total_ms: t.total().as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// JavaScript-compatible verdict with per-stage timings
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct TimedVerification {
+    /// Channel A verdict, identical to `verifyProposal`
+    pub verdict: ChannelAVerdict,
+    /// Wall-clock duration of each stage
+    pub timings: StageTimings,
+}
+
 /// JavaScript-compatible canonical payload result
 #[cfg(feature = "napi")]
 #[napi(object)]
 pub struct CanonicalResult {
+    /// Canonical form version (the payload's first byte)
+    pub version: u32,
     /// Canonical payload as hex-encoded string
     pub payload_hex: String,
     /// SHA-256 hash as hex-encoded string (proposal ID)
@@ -52,6 +108,20 @@ pub struct CanonicalResult {
     pub length: i64,
 }
 
+/// Canonical payload result with raw bytes instead of hex
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct CanonicalBufferResult {
+    /// Canonical form version (the payload's first byte)
+    pub version: u32,
+    /// Canonical payload bytes
+    pub payload: Buffer,
+    /// SHA-256 hash (proposal ID), 32 bytes
+    pub hash: Buffer,
+    /// Payload length in bytes
+    pub length: i64,
+}
+
 /// JavaScript-compatible friction parameters
 #[cfg(feature = "napi")]
 #[napi(object)]
@@ -81,6 +151,271 @@ impl From<RustFrictionParams> for FrictionParams {
     }
 }
 
+/// JavaScript-compatible change in friction between two alignment scores
+#[cfg(feature = "napi")]
+#[napi(object, js_name = "FrictionDelta")]
+pub struct FrictionDeltaJs {
+    /// Change in required quorum (fraction of voting power)
+    pub quorum_delta: f64,
+    /// Change in required quorum in basis points
+    pub quorum_delta_bps: i64,
+    /// Change in timelock duration in seconds
+    pub timelock_delta_secs: i64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustFrictionDelta> for FrictionDeltaJs {
+    fn from(d: RustFrictionDelta) -> Self {
+        Self {
+            quorum_delta: d.quorum_delta,
+            quorum_delta_bps: d.quorum_delta_bps,
+            timelock_delta_secs: d.timelock_delta_secs,
+        }
+    }
+}
+
+/// JavaScript-compatible AST structural metrics
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct AstMetrics {
+    /// Number of values below the root
+    pub node_count: i64,
+    /// Deepest nesting level
+    pub max_depth: i64,
+    /// Number of dependency references
+    pub edge_count: i64,
+    /// Nodes with no children
+    pub leaf_count: i64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustAstMetrics> for AstMetrics {
+    fn from(m: RustAstMetrics) -> Self {
+        Self {
+            node_count: m.node_count as i64,
+            max_depth: m.max_depth as i64,
+            edge_count: m.edge_count as i64,
+            leaf_count: m.leaf_count as i64,
+        }
+    }
+}
+
+/// JavaScript-compatible complexity headroom
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct ComplexityHeadroom {
+    /// Bytes left below MAX_COMPLEXITY (0 at or above it)
+    pub headroom: i64,
+    /// Headroom as a percentage of MAX_COMPLEXITY
+    pub headroom_percent: f64,
+    /// Score is at or above the near-limit threshold
+    pub near_limit: bool,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustComplexityHeadroom> for ComplexityHeadroom {
+    fn from(h: RustComplexityHeadroom) -> Self {
+        Self {
+            headroom: saturating_i64(h.headroom),
+            headroom_percent: h.headroom_percent,
+            near_limit: h.near_limit,
+        }
+    }
+}
+
+/// JavaScript-compatible complexity split between AST and text
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct ComplexityBreakdown {
+    /// Score of the whole canonical payload
+    pub total: i64,
+    /// Score of the canonical AST JSON alone
+    pub ast_score: i64,
+    /// Score of "." + normalized text alone
+    pub text_score: i64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustComplexityBreakdown> for ComplexityBreakdown {
+    fn from(b: RustComplexityBreakdown) -> Self {
+        Self {
+            total: saturating_i64(b.total),
+            ast_score: saturating_i64(b.ast_score),
+            text_score: saturating_i64(b.text_score),
+        }
+    }
+}
+
+/// JavaScript-compatible paradox pattern match
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct ParadoxMatch {
+    /// Position of the pattern in the built-in list (not stable across versions)
+    pub index: i64,
+    /// Stable ID of the pattern; use this in anything persisted
+    pub pattern_id: String,
+    /// Text the pattern matched
+    pub matched_text: String,
+}
+
+#[cfg(feature = "napi")]
+impl From<crate::channel_a::ParadoxMatch> for ParadoxMatch {
+    fn from(m: crate::channel_a::ParadoxMatch) -> Self {
+        Self {
+            index: m.pattern_index as i64,
+            pattern_id: m.pattern_id,
+            matched_text: m.matched,
+        }
+    }
+}
+
+/// JavaScript-compatible explanation of a passing verdict
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct PassReport {
+    /// Complexity score of the canonical payload
+    pub complexity_score: i64,
+    /// Complexity limit the score was checked against
+    pub max_complexity: i64,
+    /// Margin below the complexity limit
+    pub headroom: ComplexityHeadroom,
+    /// Number of built-in paradox patterns checked
+    pub paradox_patterns_checked: u32,
+    /// IDs of paradox patterns that matched (always empty for a pass)
+    pub paradox_matches: Vec<String>,
+    /// Nodes in the dependency graph
+    pub graph_nodes: i64,
+    /// Edges in the dependency graph, none of which form a cycle
+    pub graph_edges: i64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustPassReport> for PassReport {
+    fn from(r: RustPassReport) -> Self {
+        Self {
+            complexity_score: saturating_i64(r.complexity_score),
+            max_complexity: saturating_i64(r.max_complexity),
+            headroom: r.headroom.into(),
+            paradox_patterns_checked: r.paradox_patterns_checked as u32,
+            paradox_matches: r
+                .paradox_matches
+                .into_iter()
+                .map(|m| m.pattern_id)
+                .collect(),
+            graph_nodes: saturating_i64(r.graph_nodes as u64),
+            graph_edges: saturating_i64(r.graph_edges as u64),
+        }
+    }
+}
+
+/// JavaScript-compatible batch verification summary
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct BatchSummary {
+    /// Number of proposals verified
+    pub total: i64,
+    /// Proposals that passed
+    pub passed: i64,
+    /// Proposals that failed for any reason
+    pub failed: i64,
+    /// Failures targeting L0
+    pub failed_l0: i64,
+    /// Failures to canonicalize the logic AST
+    pub failed_canonicalization: i64,
+    /// Failures on complexity above the layer's limit
+    pub failed_complexity: i64,
+    /// Failures on a paradox
+    pub failed_paradox: i64,
+    /// Failures on a dependency cycle
+    pub failed_cycle: i64,
+    /// Failures on unrecognized references
+    pub failed_references: i64,
+    /// Failures on banned content
+    pub failed_banned: i64,
+    /// Lowest complexity score among scored proposals
+    pub min_complexity: Option<i64>,
+    /// Highest complexity score among scored proposals
+    pub max_complexity: Option<i64>,
+    /// Lower median complexity score among scored proposals
+    pub median_complexity: Option<i64>,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustBatchSummary> for BatchSummary {
+    fn from(s: RustBatchSummary) -> Self {
+        Self {
+            total: s.total as i64,
+            passed: s.passed as i64,
+            failed: s.failed as i64,
+            failed_l0: s.failed_l0 as i64,
+            failed_canonicalization: s.failed_canonicalization as i64,
+            failed_complexity: s.failed_complexity as i64,
+            failed_paradox: s.failed_paradox as i64,
+            failed_cycle: s.failed_cycle as i64,
+            failed_references: s.failed_references as i64,
+            failed_banned: s.failed_banned as i64,
+            min_complexity: s.min_complexity.map(saturating_i64),
+            max_complexity: s.max_complexity.map(saturating_i64),
+            median_complexity: s.median_complexity.map(saturating_i64),
+        }
+    }
+}
+
+/// JavaScript-compatible text containment result
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct ContainmentResult {
+    /// How the shorter text appears in the longer
+    pub containment: Containment,
+    /// Fraction of the longer text covered (0.0 to 1.0)
+    pub strength: f64,
+    /// Tokens of the shorter text found in the longer
+    pub shared_tokens: i64,
+    /// Whether the texts look like a padded resubmission
+    pub flagged: bool,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustContainmentResult> for ContainmentResult {
+    fn from(r: RustContainmentResult) -> Self {
+        Self {
+            containment: r.containment.into(),
+            strength: r.strength,
+            shared_tokens: r.shared_tokens as i64,
+            flagged: r.is_flagged(),
+        }
+    }
+}
+
+/// JavaScript-compatible jury review effort estimate
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct ReviewEffort {
+    /// Words in the normalized text
+    pub text_tokens: i64,
+    /// Values in the logic AST
+    pub ast_nodes: i64,
+    /// Channel A complexity score of the canonical payload
+    pub complexity_score: i64,
+    /// Combined effort score
+    pub effort_score: i64,
+    /// Suggested review duration in seconds, at most the jury voting period
+    pub suggested_duration_secs: i64,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustReviewEffort> for ReviewEffort {
+    fn from(e: RustReviewEffort) -> Self {
+        Self {
+            text_tokens: saturating_i64(e.text_tokens),
+            ast_nodes: saturating_i64(e.ast_nodes),
+            complexity_score: saturating_i64(e.complexity_score),
+            effort_score: saturating_i64(e.effort_score),
+            suggested_duration_secs: saturating_i64(e.suggested_duration_secs),
+        }
+    }
+}
+
 /// Governance layer enum for JavaScript
 #[cfg(feature = "napi")]
 #[napi(string_enum)]
@@ -103,6 +438,48 @@ impl From<GovernanceLayer> for RustGovernanceLayer {
     }
 }
 
+/// Complexity presentation unit for JavaScript
+#[cfg(feature = "napi")]
+#[napi(string_enum)]
+pub enum ComplexityUnit {
+    Bytes,
+    Bits,
+    NormalizedPercent,
+}
+
+#[cfg(feature = "napi")]
+impl From<ComplexityUnit> for RustComplexityUnit {
+    fn from(unit: ComplexityUnit) -> Self {
+        match unit {
+            ComplexityUnit::Bytes => RustComplexityUnit::Bytes,
+            ComplexityUnit::Bits => RustComplexityUnit::Bits,
+            ComplexityUnit::NormalizedPercent => RustComplexityUnit::NormalizedPercent,
+        }
+    }
+}
+
+/// Text containment relation for JavaScript
+#[cfg(feature = "napi")]
+#[napi(string_enum)]
+pub enum Containment {
+    Unrelated,
+    Subsequence,
+    Contiguous,
+    Identical,
+}
+
+#[cfg(feature = "napi")]
+impl From<RustContainment> for Containment {
+    fn from(containment: RustContainment) -> Self {
+        match containment {
+            RustContainment::Unrelated => Containment::Unrelated,
+            RustContainment::Subsequence => Containment::Subsequence,
+            RustContainment::Contiguous => Containment::Contiguous,
+            RustContainment::Identical => Containment::Identical,
+        }
+    }
+}
+
 /// Verify a proposal through the full Channel A pipeline
 ///
 /// This is the main entry point for Channel A verification from Node.js.
@@ -111,6 +488,7 @@ impl From<GovernanceLayer> for RustGovernanceLayer {
 /// @param logic_ast - JSON AST of the proposal logic
 /// @param text - Natural language description
 /// @param layer - Governance layer (L0Immutable, L1Constitutional, L2Operational, L3Execution)
+/// @param complexity_bucket_size - If set, report the complexity score rounded to this bucket size
 /// @returns Channel A verdict with pass/fail and details
 #[cfg(feature = "napi")]
 #[napi]
@@ -119,10 +497,168 @@ pub fn verify_proposal(
     logic_ast: String,
     text: String,
     layer: GovernanceLayer,
+    complexity_bucket_size: Option<i64>,
 ) -> Result<ChannelAVerdict> {
     let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
-    let verdict = crate::channel_a::verify_proposal(&proposal);
-    Ok(verdict.into())
+    Ok(verify_bucketed(&proposal, complexity_bucket_size).into())
+}
+
+/// Verify a proposal, optionally reporting a bucketed complexity score
+#[cfg(feature = "napi")]
+fn verify_bucketed(
+    proposal: &RustProposal,
+    complexity_bucket_size: Option<i64>,
+) -> RustChannelAVerdict {
+    let mut verdict = crate::channel_a::verify_proposal(proposal);
+    // The pass/fail decision above used the exact score; only the display changes
+    if let Some(size) = complexity_bucket_size {
+        verdict.complexity_score =
+            crate::channel_a::complexity_bucket(verdict.complexity_score, size.max(0) as u64);
+    }
+    verdict
+}
+
+/// Background task behind `verify_proposal_async`
+#[cfg(feature = "napi")]
+pub struct VerifyProposalTask {
+    proposal: RustProposal,
+    complexity_bucket_size: Option<i64>,
+}
+
+#[cfg(feature = "napi")]
+impl Task for VerifyProposalTask {
+    type Output = RustChannelAVerdict;
+    type JsValue = ChannelAVerdict;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(verify_bucketed(&self.proposal, self.complexity_bucket_size))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output.into())
+    }
+}
+
+/// Verify a proposal on the libuv thread pool
+///
+/// Same inputs and verdict as `verifyProposal`, but the pipeline runs off
+/// the JavaScript thread and the result arrives as a Promise. The verdict
+/// is deterministic, so it does not depend on which thread computed it.
+///
+/// @param proposer - XRPL address of the proposer
+/// @param logic_ast - JSON AST of the proposal logic
+/// @param text - Natural language description
+/// @param layer - Governance layer
+/// @param complexity_bucket_size - If set, report the complexity score rounded to this bucket size
+/// @returns Promise of the Channel A verdict
+#[cfg(feature = "napi")]
+#[napi]
+pub fn verify_proposal_async(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+    complexity_bucket_size: Option<i64>,
+) -> AsyncTask<VerifyProposalTask> {
+    AsyncTask::new(VerifyProposalTask {
+        proposal: RustProposal::new(proposer, logic_ast, text, layer.into()),
+        complexity_bucket_size,
+    })
+}
+
+/// Background task behind `verify_proposals_batch_async`
+#[cfg(feature = "napi")]
+pub struct VerifyBatchTask {
+    proposals_json: String,
+}
+
+#[cfg(feature = "napi")]
+impl Task for VerifyBatchTask {
+    type Output = Vec<RustChannelAVerdict>;
+    type JsValue = Vec<ChannelAVerdict>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(crate::channel_a::verify_batch(&parse_proposals(
+            &self.proposals_json,
+        )?))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Verify a JSON array of proposals on the libuv thread pool
+///
+/// Parsing and verification both run off the JavaScript thread. Verdicts
+/// are returned in input order and are identical to calling
+/// `verifyProposalJson` on each element in turn.
+///
+/// @param proposals_json - JSON array of proposals
+/// @returns Promise of one Channel A verdict per proposal; rejects like `verifyProposalsJson`
+#[cfg(feature = "napi")]
+#[napi]
+pub fn verify_proposals_batch_async(proposals_json: String) -> AsyncTask<VerifyBatchTask> {
+    AsyncTask::new(VerifyBatchTask { proposals_json })
+}
+
+/// Parse a JSON array of proposals, naming the first malformed element
+#[cfg(feature = "napi")]
+fn parse_proposals(proposals_json: &str) -> Result<Vec<RustProposal>> {
+    let items: Vec<serde_json::Value> = serde_json::from_str(proposals_json).map_err(|e| {
+        Error::from_reason(format!("Input is not a JSON array of proposals: {}", e))
+    })?;
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            serde_json::from_value(item).map_err(|e| {
+                Error::from_reason(format!("Invalid proposal at index {}: {}", index, e))
+            })
+        })
+        .collect()
+}
+
+/// Verify a JSON array of proposals in one call
+///
+/// Saves a boundary crossing per proposal when verifying many at once.
+/// Verdicts are returned in input order and are identical to calling
+/// `verifyProposalJson` on each element in turn.
+///
+/// @param proposals_json - JSON array of proposals
+/// @returns One Channel A verdict per proposal
+/// @throws Error if the input is not a JSON array, or naming the index of the first element that is not a valid proposal
+#[cfg(feature = "napi")]
+#[napi]
+pub fn verify_proposals_json(proposals_json: String) -> Result<Vec<ChannelAVerdict>> {
+    let proposals = parse_proposals(&proposals_json)?;
+    Ok(crate::channel_a::verify_batch(&proposals)
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Verify a proposal and report how long each stage took
+///
+/// @param proposer - XRPL address of the proposer
+/// @param logic_ast - JSON AST of the proposal logic
+/// @param text - Natural language description
+/// @param layer - Governance layer
+/// @returns Channel A verdict and per-stage timings in milliseconds
+#[cfg(feature = "napi")]
+#[napi]
+pub fn verify_proposal_timed(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> TimedVerification {
+    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
+    let (verdict, timings) = crate::channel_a::verify_proposal_timed(&proposal);
+    TimedVerification {
+        verdict: verdict.into(),
+        timings: timings.into(),
+    }
 }
 
 /// Verify a proposal from JSON input
@@ -138,6 +674,18 @@ pub fn verify_proposal_json(proposal_json: String) -> Result<ChannelAVerdict> {
     Ok(verdict.into())
 }
 
+/// Verify a JSON array of proposals and summarize the outcomes
+///
+/// @param proposals_json - JSON array of proposal objects
+/// @returns Pass/fail counts by failure mode and complexity distribution
+#[cfg(feature = "napi")]
+#[napi]
+pub fn summarize_batch(proposals_json: String) -> Result<BatchSummary> {
+    let proposals: Vec<RustProposal> =
+        serde_json::from_str(&proposals_json).map_err(|e| Error::from_reason(e.to_string()))?;
+    Ok(crate::channel_a::summarize_batch(&proposals).into())
+}
+
 /// Canonicalize a proposal and return the canonical payload
 ///
 /// @param proposer - XRPL address
@@ -153,30 +701,177 @@ pub fn canonicalize_proposal(
     text: String,
     layer: GovernanceLayer,
 ) -> Result<CanonicalResult> {
-    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
-
-    let canonical =
-        canonicalize(&proposal).map_err(|e| Error::from_reason(format!("Canonicalization failed: {}", e)))?;
+    let canonical = canonical_payload(proposer, logic_ast, text, layer)?;
 
     Ok(CanonicalResult {
+        version: canonical.version.into(),
         payload_hex: hex::encode(&canonical.bytes),
         hash: hex::encode(canonical.hash),
         length: canonical.bytes.len() as i64,
     })
 }
 
+/// Canonicalize a proposal, returning the payload and hash as Buffers
+///
+/// Same payload as `canonicalizeProposal` without the hex round-trip,
+/// for callers that hash or store the bytes directly.
+///
+/// @param proposer - XRPL address
+/// @param logic_ast - JSON AST
+/// @param text - Natural language description
+/// @param layer - Governance layer
+/// @returns Canonical payload and hash bytes
+#[cfg(feature = "napi")]
+#[napi]
+pub fn canonicalize_proposal_buffer(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> Result<CanonicalBufferResult> {
+    let canonical = canonical_payload(proposer, logic_ast, text, layer)?;
+
+    Ok(CanonicalBufferResult {
+        version: canonical.version.into(),
+        length: canonical.bytes.len() as i64,
+        hash: canonical.hash.to_vec().into(),
+        payload: canonical.bytes.into(),
+    })
+}
+
+/// Canonicalize a proposal built from NAPI arguments
+#[cfg(feature = "napi")]
+fn canonical_payload(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> Result<crate::channel_a::CanonicalPayload> {
+    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
+    canonicalize(&proposal)
+        .map_err(|e| Error::from_reason(format!("Canonicalization failed: {}", e)))
+}
+
+/// Check that a string is a classic XRPL account address
+///
+/// Advisory; Channel A verification does not check the proposer.
+///
+/// @param address - Candidate address
+/// @returns true for a checksummed classic address starting with `r`
+#[cfg(feature = "napi")]
+#[napi(js_name = "isValidXrplAddress")]
+pub fn is_valid_xrpl_address_js(address: String) -> bool {
+    crate::types::is_valid_xrpl_address(&address)
+}
+
 /// Compute complexity score for a payload
 ///
 /// Uses zlib compression level 9 to measure Kolmogorov complexity proxy.
 ///
 /// @param payload_hex - Hex-encoded payload bytes
 /// @returns Complexity score (compressed size in bytes)
+/// @throws Error if compression failed
 #[cfg(feature = "napi")]
 #[napi]
 pub fn compute_complexity_score(payload_hex: String) -> Result<i64> {
     let bytes = hex::decode(&payload_hex).map_err(|e| Error::from_reason(e.to_string()))?;
     let score = compute_complexity(&bytes);
-    Ok(score as i64)
+    if score == COMPLEXITY_FAILURE {
+        return Err(Error::from_reason("Complexity computation failed"));
+    }
+    Ok(saturating_i64(score))
+}
+
+/// Compute complexity for a payload in a display unit
+///
+/// The consensus gate always uses bytes; this is a presentation helper.
+///
+/// @param payload_hex - Hex-encoded payload bytes
+/// @param unit - Bytes, Bits, or NormalizedPercent (percent of MAX_COMPLEXITY)
+/// @returns Complexity in the requested unit
+/// @throws Error if compression failed
+#[cfg(feature = "napi")]
+#[napi]
+pub fn compute_complexity_in(payload_hex: String, unit: ComplexityUnit) -> Result<f64> {
+    let bytes = hex::decode(&payload_hex).map_err(|e| Error::from_reason(e.to_string()))?;
+    let score = compute_complexity(&bytes);
+    if score == COMPLEXITY_FAILURE {
+        return Err(Error::from_reason("Complexity computation failed"));
+    }
+    Ok(crate::channel_a::convert_complexity(score, unit.into()))
+}
+
+/// Compute the complexity contributed by proposal text alone
+///
+/// Diagnostic only; see `computeAstComplexity` for the AST portion.
+///
+/// @param text - Natural language proposal text (normalized before compressing)
+/// @returns Compressed size of "." + normalized text
+/// @throws Error if compression failed
+#[cfg(feature = "napi")]
+#[napi]
+pub fn compute_text_complexity(text: String) -> Result<i64> {
+    let score = crate::channel_a::text_only_complexity(&text);
+    if score == COMPLEXITY_FAILURE {
+        return Err(Error::from_reason("Complexity computation failed"));
+    }
+    Ok(saturating_i64(score))
+}
+
+/// Compute the complexity contributed by the logic AST alone
+///
+/// Diagnostic only; see `computeTextComplexity` for the text portion.
+///
+/// @param logic_ast - JSON string of the proposal logic
+/// @returns Compressed size of the canonical AST JSON
+/// @throws Error if the AST is not valid JSON or compression failed
+#[cfg(feature = "napi")]
+#[napi]
+pub fn compute_ast_complexity(logic_ast: String) -> Result<i64> {
+    let score = crate::channel_a::ast_only_complexity(&logic_ast)
+        .map_err(|e| Error::from_reason(e.to_string()))?;
+    if score == COMPLEXITY_FAILURE {
+        return Err(Error::from_reason("Complexity computation failed"));
+    }
+    Ok(saturating_i64(score))
+}
+
+/// Split a proposal's complexity score into AST and text contributions
+///
+/// The two parts are compressed separately and do not sum to the total;
+/// the larger one shows which half to trim.
+///
+/// @param proposer - XRPL address of proposer
+/// @param logic_ast - JSON string of the proposal logic
+/// @param text - Natural language proposal text
+/// @param layer - Governance layer
+/// @returns Total, AST and text scores
+/// @throws Error if canonicalization fails
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_complexity_breakdown(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> Result<ComplexityBreakdown> {
+    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
+    let canonical = canonicalize(&proposal)
+        .map_err(|e| Error::from_reason(format!("Canonicalization failed: {}", e)))?;
+    Ok(crate::channel_a::compute_complexity_breakdown(&canonical).into())
+}
+
+/// Report how close a complexity score is to MAX_COMPLEXITY
+///
+/// @param score - Complexity score (negative values are treated as 0)
+/// @param near_limit_percent - Share of the limit (0-100) that counts as near it; defaults to 90
+/// @returns Remaining headroom and a near-limit flag
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_complexity_headroom(score: i64, near_limit_percent: Option<f64>) -> ComplexityHeadroom {
+    let score = u64::try_from(score).unwrap_or(0);
+    let threshold = near_limit_percent.unwrap_or(crate::channel_a::NEAR_LIMIT_PERCENT);
+    crate::channel_a::complexity_headroom_with(score, threshold).into()
 }
 
 /// Detect paradoxes in proposal text
@@ -193,6 +888,22 @@ pub fn detect_paradox_in_text(text: String) -> bool {
     detect_paradox(&text)
 }
 
+/// List the paradox patterns that match proposal text
+///
+/// Explains a `detectParadoxInText` result: one entry per matching
+/// pattern, in pattern order, with the substring it matched.
+///
+/// @param text - Natural language proposal text
+/// @returns Matching patterns (empty if no paradox is detected)
+#[cfg(feature = "napi")]
+#[napi]
+pub fn find_paradox_matches_in_text(text: String) -> Vec<ParadoxMatch> {
+    crate::channel_a::find_paradox_matches(&text)
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
 /// Detect cycles in proposal logic AST
 ///
 /// Uses Tarjan's strongly connected components algorithm.
@@ -205,12 +916,81 @@ pub fn detect_cycles_in_ast(logic_ast: String) -> Result<bool> {
     detect_cycles(&logic_ast).map_err(|e| Error::from_reason(e.to_string()))
 }
 
+/// Compute structural metrics of a logic AST
+///
+/// @param logic_ast - JSON AST of proposal logic
+/// @returns Node count, max depth, reference edge count and leaf count
+#[cfg(feature = "napi")]
+#[napi]
+pub fn get_ast_metrics(logic_ast: String) -> Result<AstMetrics> {
+    crate::channel_a::ast_metrics(&logic_ast)
+        .map(Into::into)
+        .map_err(|e| Error::from_reason(e.to_string()))
+}
+
+/// Compare two proposal texts for padded resubmission
+///
+/// Advisory only: reports whether one normalized text is a contiguous run
+/// or an in-order token subsequence of the other.
+///
+/// @param a - First proposal text
+/// @param b - Second proposal text
+/// @returns Containment relation, strength and whether it is flagged
+#[cfg(feature = "napi")]
+#[napi]
+pub fn text_containment(a: String, b: String) -> ContainmentResult {
+    crate::channel_a::text_containment(&a, &b).into()
+}
+
+/// Explain why a proposal passes Channel A
+///
+/// @param proposer - XRPL address of the proposer
+/// @param logic_ast - JSON AST of the proposal logic
+/// @param text - Natural language description
+/// @param layer - Governance layer
+/// @returns Complexity headroom, paradox patterns checked and dependency graph size, or null if the proposal fails
+#[cfg(feature = "napi")]
+#[napi]
+pub fn explain_pass(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> Option<PassReport> {
+    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
+    crate::channel_a::explain_pass(&proposal).map(Into::into)
+}
+
+/// Estimate jury review effort for a proposal
+///
+/// Advisory scheduling metadata for Class III proposals; never affects a verdict.
+///
+/// @param proposer - XRPL address of the proposer
+/// @param logic_ast - JSON AST of the proposal logic
+/// @param text - Natural language description
+/// @param layer - Governance layer
+/// @returns Effort score and suggested review duration in seconds
+#[cfg(feature = "napi")]
+#[napi]
+pub fn estimate_review_effort(
+    proposer: String,
+    logic_ast: String,
+    text: String,
+    layer: GovernanceLayer,
+) -> ReviewEffort {
+    let proposal = RustProposal::new(proposer, logic_ast, text, layer.into());
+    crate::review::estimate_review_effort(&proposal).into()
+}
+
 /// Calculate friction parameters from alignment score
 ///
 /// From spec v5.0:
 /// - Quorum Multiplier: 1.0 + (1.0 - alignment_score) * 0.5
 /// - Timelock Multiplier: 1.0 + (1.0 - alignment_score) * 2.0
 ///
+/// Non-finite input (`NaN`, `Infinity`) is treated as 0.0 alignment,
+/// giving maximum friction.
+///
 /// @param alignment_score - Semantic alignment score from Channel B (0.0 to 1.0)
 /// @returns Friction parameters
 #[cfg(feature = "napi")]
@@ -219,6 +999,42 @@ pub fn calculate_friction(alignment_score: f64) -> FrictionParams {
     RustFrictionParams::from_alignment_score(alignment_score).into()
 }
 
+/// Calculate the change in friction between two alignment scores
+///
+/// Each field is `to - from`; positive values mean more friction.
+/// Swapping the arguments negates every field.
+///
+/// @param from - Starting alignment score (0.0 to 1.0)
+/// @param to - Ending alignment score (0.0 to 1.0)
+/// @returns Change in quorum and timelock
+#[cfg(feature = "napi")]
+#[napi(js_name = "frictionDelta")]
+pub fn friction_delta_js(from: f64, to: f64) -> FrictionDeltaJs {
+    crate::types::friction_delta(from, to).into()
+}
+
+/// Run the golden-vector conformance self-test
+///
+/// Call once at server start to fail fast on a mis-built native module
+/// (e.g. one linked against a different zlib backend).
+///
+/// @throws Error naming the golden vector and output that diverged
+#[cfg(feature = "napi")]
+#[napi]
+pub fn self_test() -> Result<()> {
+    crate::channel_a::self_test().map_err(|e| Error::from_reason(e.to_string()))
+}
+
+/// Compile all lazily-initialized detectors now
+///
+/// Call once at server boot so the first verification is not slowed by
+/// regex compilation. Safe to call more than once.
+#[cfg(feature = "napi")]
+#[napi]
+pub fn warm_up() {
+    crate::channel_a::warm_up();
+}
+
 /// Get the maximum allowed complexity score
 ///
 /// @returns MAX_COMPLEXITY constant (10,000)
@@ -276,6 +1092,7 @@ mod tests {
             r#"{"action": "test"}"#.to_string(),
             "A simple test proposal".to_string(),
             GovernanceLayer::L2Operational,
+            None,
         );
 
         assert!(result.is_ok());
@@ -283,6 +1100,178 @@ mod tests {
         assert!(verdict.pass);
     }
 
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_find_paradox_matches_in_text() {
+        use super::*;
+
+        let matches =
+            find_paradox_matches_in_text("This proposal passes iff it fails.".to_string());
+        assert!(!matches.is_empty());
+        let iff = matches
+            .iter()
+            .find(|m| m.pattern_id == "iff_self_reference")
+            .unwrap();
+        assert_eq!(iff.index, 0);
+        assert!(!iff.matched_text.is_empty());
+        assert!("This proposal passes iff it fails.".contains(&iff.matched_text));
+
+        assert!(find_paradox_matches_in_text("Transfer 100 tokens".to_string()).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_async_tasks_match_sync_verdicts() {
+        use super::*;
+
+        // `resolve` only applies the `From` conversions checked here
+        let proposals = [
+            RustProposal::new(
+                "rAddr".to_string(),
+                r#"{"action": "transfer"}"#.to_string(),
+                "Transfer 100 tokens".to_string(),
+                RustGovernanceLayer::L2Operational,
+            ),
+            RustProposal::new(
+                "rAddr".to_string(),
+                "{}".to_string(),
+                "This statement is false".to_string(),
+                RustGovernanceLayer::L2Operational,
+            ),
+            RustProposal::new(
+                "rAddr".to_string(),
+                r#"{"a": {"value": "$ref:a"}}"#.to_string(),
+                "Loop".to_string(),
+                RustGovernanceLayer::L3Execution,
+            ),
+        ];
+
+        let mut task = VerifyProposalTask {
+            proposal: proposals[1].clone(),
+            complexity_bucket_size: None,
+        };
+        let output = task.compute().unwrap();
+        assert_eq!(output, crate::channel_a::verify_proposal(&proposals[1]));
+        let verdict = ChannelAVerdict::from(output);
+        assert!(!verdict.pass && verdict.paradox_found);
+
+        let mut task = VerifyBatchTask {
+            proposals_json: serde_json::to_string(&proposals).unwrap(),
+        };
+        let output = task.compute().unwrap();
+        let expected: Vec<_> = proposals
+            .iter()
+            .map(crate::channel_a::verify_proposal)
+            .collect();
+        assert_eq!(output, expected);
+        let verdicts: Vec<ChannelAVerdict> = output.into_iter().map(Into::into).collect();
+        let flags: Vec<_> = verdicts
+            .iter()
+            .map(|v| (v.pass, v.paradox_found, v.cycle_found))
+            .collect();
+        assert_eq!(
+            flags,
+            [
+                (true, false, false),
+                (false, true, false),
+                (false, false, true)
+            ]
+        );
+
+        let mut bad = VerifyBatchTask {
+            proposals_json: "not json".to_string(),
+        };
+        assert!(bad.compute().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_verify_proposals_json() {
+        use super::*;
+
+        let proposal = |text: &str| {
+            serde_json::to_value(RustProposal::new(
+                "rAddr".to_string(),
+                r#"{"action": "transfer"}"#.to_string(),
+                text.to_string(),
+                RustGovernanceLayer::L2Operational,
+            ))
+            .unwrap()
+        };
+        let batch = serde_json::json!([
+            proposal("Transfer 100 tokens"),
+            proposal("This proposal passes iff it fails"),
+            proposal("Fund the audit"),
+        ]);
+
+        let verdicts = verify_proposals_json(batch.to_string()).unwrap();
+        let flags: Vec<_> = verdicts.iter().map(|v| (v.pass, v.paradox_found)).collect();
+        assert_eq!(flags, [(true, false), (false, true), (true, false)]);
+
+        // A malformed element is reported by position
+        let mut malformed = batch.clone();
+        malformed[2] = serde_json::json!({"proposer": "rAddr"});
+        let err = verify_proposals_json(malformed.to_string()).err().unwrap();
+        assert!(err.reason.contains("index 2"), "{}", err.reason);
+        assert!(verify_proposals_json("{}".to_string()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_canonicalize_proposal_buffer_matches_hex() {
+        use super::*;
+
+        let args = || {
+            (
+                "rAddr".to_string(),
+                r#"{"note": "v1.2", "amount": 100}"#.to_string(),
+                "Pay 100 tokens for release 1.2".to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        let (proposer, ast, text, layer) = args();
+        let hex_result = canonicalize_proposal(proposer, ast, text, layer).unwrap();
+        // The bytes `canonicalizeProposalBuffer` wraps; a `Buffer` itself
+        // needs the Node runtime, so it is not built here
+        let (proposer, ast, text, layer) = args();
+        let canonical = canonical_payload(proposer, ast, text, layer).unwrap();
+
+        assert_eq!(hex::encode(&canonical.bytes), hex_result.payload_hex);
+        assert_eq!(hex::encode(canonical.hash), hex_result.hash);
+        assert_eq!(canonical.bytes.len() as i64, hex_result.length);
+        assert_eq!(u32::from(canonical.version), hex_result.version);
+
+        let (proposer, _, text, layer) = args();
+        assert!(canonical_payload(proposer, "{not json".to_string(), text, layer).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_verify_proposal_bucketed_score() {
+        use super::*;
+
+        let verify = |ast: &str, bucket: Option<i64>| {
+            verify_proposal(
+                "rAddr".to_string(),
+                ast.to_string(),
+                "Bucketed".to_string(),
+                GovernanceLayer::L2Operational,
+                bucket,
+            )
+            .unwrap()
+        };
+        let ast = r#"{"action": "transfer", "amount": 100}"#;
+        let exact = verify(ast, None);
+        let bucketed = verify(ast, Some(100));
+        assert_eq!(bucketed.pass, exact.pass);
+        assert_eq!(bucketed.complexity_score % 100, 0);
+        assert!((bucketed.complexity_score - exact.complexity_score).abs() <= 50);
+
+        // Bucketing never turns a failure into a pass
+        let cyclic = verify(r#"{"a": {"value": "$ref:a"}}"#, Some(1_000));
+        assert!(!cyclic.pass);
+    }
+
     #[test]
     #[cfg(feature = "napi")]
     fn test_paradox_detection() {
@@ -296,6 +1285,61 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_failure_complexity_saturates() {
+        use super::*;
+
+        let verdict: ChannelAVerdict =
+            RustChannelAVerdict::fail(COMPLEXITY_FAILURE, false, false).into();
+        assert_eq!(verdict.complexity_score, i64::MAX);
+        assert!(verdict.complexity_score >= 0);
+        assert_eq!(saturating_i64(42), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_partial_complexity() {
+        use super::*;
+
+        assert!(compute_text_complexity("Transfer 100 tokens".to_string()).unwrap() > 0);
+        assert!(compute_ast_complexity(r#"{"action": "test"}"#.to_string()).unwrap() > 0);
+        assert!(compute_ast_complexity("{not json".to_string()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_complexity_headroom() {
+        use super::*;
+
+        let max = get_max_complexity();
+        let headroom = get_complexity_headroom(max * 95 / 100, None);
+        assert_eq!(headroom.headroom, max / 20);
+        assert!(headroom.near_limit);
+        assert!(!get_complexity_headroom(max * 95 / 100, Some(99.0)).near_limit);
+        assert_eq!(get_complexity_headroom(-5, None).headroom, max);
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_warm_up() {
+        use super::*;
+
+        warm_up();
+        warm_up();
+        assert!(detect_paradox_in_text(
+            "This statement is false".to_string()
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_self_test() {
+        use super::*;
+
+        assert!(self_test().is_ok());
+    }
+
     #[test]
     #[cfg(feature = "napi")]
     fn test_friction_calculation() {
@@ -309,4 +1353,131 @@ mod tests {
         assert_eq!(params.quorum_multiplier, 1.5);
         assert_eq!(params.timelock_multiplier, 3.0);
     }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_friction_non_finite_input() {
+        use super::*;
+
+        for score in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let params = calculate_friction(score);
+            assert_eq!(params.alignment_score, 0.0);
+            assert_eq!(params.quorum_multiplier, 1.5);
+            assert_eq!(params.timelock_multiplier, 3.0);
+            assert!(params.required_quorum.is_finite());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_verify_proposal_timed() {
+        use super::*;
+
+        let ast = r#"{"action": "transfer", "amount": 100}"#;
+        let timed = verify_proposal_timed(
+            "rAddr".to_string(),
+            ast.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L2Operational,
+        );
+        let verdict = verify_proposal(
+            "rAddr".to_string(),
+            ast.to_string(),
+            "Transfer 100 tokens".to_string(),
+            GovernanceLayer::L2Operational,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(timed.verdict.pass, verdict.pass);
+        assert_eq!(timed.verdict.complexity_score, verdict.complexity_score);
+        let t = &timed.timings;
+        for ms in [
+            t.canonicalization_ms,
+            t.complexity_ms,
+            t.paradox_ms,
+            t.cycles_ms,
+        ] {
+            assert!(ms >= 0.0 && ms <= t.total_ms);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_explain_pass() {
+        use super::*;
+
+        let report = explain_pass(
+            "rAddr".to_string(),
+            r#"{"fee": {"value": 5}, "total": {"value": "$ref:fee"}}"#.to_string(),
+            "Set the fee to 5".to_string(),
+            GovernanceLayer::L2Operational,
+        )
+        .unwrap();
+        assert!(report.paradox_matches.is_empty());
+        assert_eq!((report.graph_nodes, report.graph_edges), (2, 1));
+        assert_eq!(
+            report.headroom.headroom,
+            report.max_complexity - report.complexity_score
+        );
+
+        assert!(explain_pass(
+            "rAddr".to_string(),
+            "{}".to_string(),
+            "This statement is false".to_string(),
+            GovernanceLayer::L2Operational,
+        )
+        .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_friction_delta() {
+        use super::*;
+
+        let delta = friction_delta_js(1.0, 0.0);
+        assert_eq!(delta.quorum_delta_bps, 500);
+        assert_eq!(delta.timelock_delta_secs, 172_800);
+
+        let back = friction_delta_js(0.0, 1.0);
+        assert_eq!(back.quorum_delta_bps, -500);
+        assert_eq!(back.timelock_delta_secs, -172_800);
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_text_containment() {
+        use super::*;
+
+        let result = text_containment(
+            "Transfer 100 tokens".to_string(),
+            "Transfer 100 tokens today".to_string(),
+        );
+        assert!(matches!(result.containment, Containment::Contiguous));
+        assert!(result.flagged);
+        assert_eq!(result.strength, 0.75);
+
+        let result = text_containment(
+            "Transfer 100 tokens".to_string(),
+            "Raise the bond".to_string(),
+        );
+        assert!(matches!(result.containment, Containment::Unrelated));
+        assert!(!result.flagged);
+    }
+
+    #[test]
+    #[cfg(feature = "napi")]
+    fn test_review_effort() {
+        use super::*;
+
+        let effort = estimate_review_effort(
+            "rTestAddress".to_string(),
+            r#"{"action": "amend"}"#.to_string(),
+            "Amend the jury rules".to_string(),
+            GovernanceLayer::L1Constitutional,
+        );
+        assert_eq!(effort.text_tokens, 4);
+        assert!(effort.suggested_duration_secs > 0);
+        assert!(effort.suggested_duration_secs <= get_jury_voting_period());
+    }
 }
@@ -0,0 +1,165 @@
+//! Jury Review Effort
+//!
+//! Advisory scheduling metadata for Class III proposals routed to the
+//! Constitutional Jury. The effort score combines three size measures:
+//!
+//! ```text
+//! effort = text_tokens + 2 * ast_nodes + complexity_score / 10
+//! ```
+//!
+//! Text is read, AST nodes must each be checked against the text, and the
+//! complexity score captures how much non-repetitive content there is in
+//! total. The suggested review duration is a fixed base plus a per-point
+//! allowance, capped at `JURY_VOTING_PERIOD`:
+//!
+//! ```text
+//! duration = min(BASE_REVIEW_SECONDS + effort * SECONDS_PER_EFFORT_POINT, JURY_VOTING_PERIOD)
+//! ```
+//!
+//! A proposal whose AST cannot be parsed counts zero AST nodes and zero
+//! complexity. None of this affects a verdict.
+
+use serde::{Deserialize, Serialize};
+
+use crate::channel_a::{ast_metrics, canonicalize, compute_complexity, normalize_text};
+use crate::types::{config, Proposal};
+
+/// Minimum review time for any proposal (1 hour)
+pub const BASE_REVIEW_SECONDS: u64 = 60 * 60;
+
+/// Review time allotted per effort point (1 minute)
+pub const SECONDS_PER_EFFORT_POINT: u64 = 60;
+
+/// Estimated jury review effort for a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewEffort {
+    /// Words in the normalized text
+    pub text_tokens: u64,
+    /// Values in the logic AST (see `AstMetrics::node_count`)
+    pub ast_nodes: u64,
+    /// Channel A complexity score of the canonical payload
+    pub complexity_score: u64,
+    /// Combined effort score
+    pub effort_score: u64,
+    /// Suggested review duration in seconds, at most `JURY_VOTING_PERIOD`
+    pub suggested_duration_secs: u64,
+}
+
+/// Estimate how much jury time a proposal needs
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::review::estimate_review_effort;
+/// use constitution_dao_core::{config, Proposal, GovernanceLayer};
+///
+/// let proposal = Proposal::new(
+///     "rAddr".to_string(),
+///     r#"{"action": "amend", "article": 4}"#.to_string(),
+///     "Amend article 4 to extend the jury voting period".to_string(),
+///     GovernanceLayer::L1Constitutional,
+/// );
+///
+/// let effort = estimate_review_effort(&proposal);
+/// assert_eq!(effort.text_tokens, 9);
+/// assert!(effort.suggested_duration_secs <= config::JURY_VOTING_PERIOD);
+/// ```
+pub fn estimate_review_effort(proposal: &Proposal) -> ReviewEffort {
+    let text_tokens = normalize_text(&proposal.text).split_whitespace().count() as u64;
+    let ast_nodes = ast_metrics(&proposal.logic_ast).map_or(0, |m| m.node_count);
+    let complexity_score = canonicalize(proposal).map_or(0, |c| compute_complexity(&c.bytes));
+
+    let effort_score = text_tokens
+        .saturating_add(ast_nodes.saturating_mul(2))
+        .saturating_add(complexity_score / 10);
+    let suggested_duration_secs = effort_score
+        .saturating_mul(SECONDS_PER_EFFORT_POINT)
+        .saturating_add(BASE_REVIEW_SECONDS)
+        .min(config::JURY_VOTING_PERIOD);
+
+    ReviewEffort {
+        text_tokens,
+        ast_nodes,
+        complexity_score,
+        effort_score,
+        suggested_duration_secs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GovernanceLayer;
+
+    fn proposal(ast: String, text: String) -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            ast,
+            text,
+            GovernanceLayer::L1Constitutional,
+        )
+    }
+
+    #[test]
+    fn test_large_proposal_needs_more_effort() {
+        let trivial = estimate_review_effort(&proposal(
+            r#"{"action": "noop"}"#.to_string(),
+            "Do nothing".to_string(),
+        ));
+
+        let clauses: Vec<String> = (0..40)
+            .map(|i| {
+                format!(
+                    r#""rule_{}": {{"threshold": {}, "depends_on": ["rule_{}"]}}"#,
+                    i,
+                    i * 7,
+                    i + 1
+                )
+            })
+            .collect();
+        let text: Vec<String> = (0..40)
+            .map(|i| {
+                format!(
+                    "Rule {} sets threshold {} and defers to rule {}.",
+                    i,
+                    i * 7,
+                    i + 1
+                )
+            })
+            .collect();
+        let large = estimate_review_effort(&proposal(
+            format!("{{{}}}", clauses.join(",")),
+            text.join(" "),
+        ));
+
+        assert!(large.text_tokens > trivial.text_tokens);
+        assert!(large.ast_nodes > trivial.ast_nodes);
+        assert!(large.complexity_score > trivial.complexity_score);
+        assert!(large.effort_score > trivial.effort_score);
+        assert!(large.suggested_duration_secs > trivial.suggested_duration_secs);
+        assert_eq!(
+            trivial.suggested_duration_secs,
+            BASE_REVIEW_SECONDS + trivial.effort_score * SECONDS_PER_EFFORT_POINT
+        );
+    }
+
+    #[test]
+    fn test_duration_capped_at_voting_period() {
+        let words: Vec<String> = (0..20_000).map(|i| format!("w{}", i)).collect();
+        let effort = estimate_review_effort(&proposal("{}".to_string(), words.join(" ")));
+
+        assert!(effort.effort_score * SECONDS_PER_EFFORT_POINT > config::JURY_VOTING_PERIOD);
+        assert_eq!(effort.suggested_duration_secs, config::JURY_VOTING_PERIOD);
+    }
+
+    #[test]
+    fn test_unparseable_ast_counts_text_only() {
+        let effort = estimate_review_effort(&proposal(
+            "{oops".to_string(),
+            "Three word text".to_string(),
+        ));
+        assert_eq!(effort.ast_nodes, 0);
+        assert_eq!(effort.complexity_score, 0);
+        assert_eq!(effort.effort_score, 3);
+    }
+}
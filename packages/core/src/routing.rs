@@ -0,0 +1,434 @@
+//! Combined Channel A / Channel B Routing
+//!
+//! Implements the decidability routing of spec v5.0 §3.3 on top of the two
+//! channel verdicts:
+//!
+//! - Channel A FAIL short-circuits to `Rejected`
+//! - Logic that would disable verification once executed (see
+//!   `detects_verification_disabling`) always goes to the jury
+//! - Alignment below `DecidabilityPolicy::auto_escalate_alignment` escalates
+//!   to the Constitutional Jury regardless of class
+//! - Class I is routed to the PoUW Marketplace
+//! - Class II proceeds to voting with alignment-based friction
+//! - Class III is escalated to `RequiresHumanReview`
+//!
+//! `route_proposal` drives the lifecycle status from whatever verdicts are
+//! available so far and is the single place lifecycle transitions are decided.
+
+use serde::{Deserialize, Serialize};
+
+use crate::channel_a::{detects_verification_disabling, COMPLEXITY_FAILURE};
+use crate::types::{
+    config, ChannelAVerdict, ChannelBVerdict, DecidabilityClass, FrictionParams, Proposal,
+    ProposalStatus,
+};
+
+/// Where a proposal goes next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Route {
+    /// PoUW Marketplace for formal verification (Class I)
+    PoUW,
+    /// Standard token-weighted voting (Class II)
+    StandardVoting,
+    /// Constitutional Jury (Class III, or auto-escalated)
+    ConstitutionalJury,
+    /// Immediate rejection (Channel A failure)
+    Rejected,
+}
+
+/// Outcome of combining both channel verdicts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingDecision {
+    /// Where the proposal goes next
+    pub route: Route,
+    /// The resulting lifecycle status
+    pub status: ProposalStatus,
+    /// Friction to apply when the proposal proceeds to voting
+    pub friction: Option<FrictionParams>,
+}
+
+/// Tunable thresholds for decidability routing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecidabilityPolicy {
+    /// Alignment scores strictly below this escalate to the jury
+    pub auto_escalate_alignment: f64,
+}
+
+impl Default for DecidabilityPolicy {
+    fn default() -> Self {
+        Self {
+            auto_escalate_alignment: config::AUTO_ESCALATE_ALIGNMENT,
+        }
+    }
+}
+
+impl DecidabilityPolicy {
+    /// Whether an alignment score is low enough to bypass friction and go to the jury
+    ///
+    /// Non-finite scores (NaN) always escalate.
+    pub fn should_auto_escalate(&self, alignment_score: f64) -> bool {
+        alignment_score.is_nan() || alignment_score < self.auto_escalate_alignment
+    }
+}
+
+/// Whether an alignment score escalates under the default policy
+#[inline]
+pub fn should_auto_escalate(alignment_score: f64) -> bool {
+    DecidabilityPolicy::default().should_auto_escalate(alignment_score)
+}
+
+/// Combine Channel A and Channel B verdicts into a routing decision
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::routing::{decide_routing, DecidabilityPolicy, Route};
+/// use constitution_dao_core::{ChannelAVerdict, ChannelBVerdict, DecidabilityClass};
+///
+/// let a = ChannelAVerdict::pass(120);
+/// let b = ChannelBVerdict::new(0.9, DecidabilityClass::II);
+///
+/// let decision = decide_routing(&a, &b, &DecidabilityPolicy::default());
+/// assert_eq!(decision.route, Route::StandardVoting);
+/// assert!(decision.friction.is_some());
+/// ```
+pub fn decide_routing(
+    channel_a: &ChannelAVerdict,
+    channel_b: &ChannelBVerdict,
+    policy: &DecidabilityPolicy,
+) -> RoutingDecision {
+    if !channel_a.pass {
+        return RoutingDecision {
+            route: Route::Rejected,
+            status: ProposalStatus::Rejected,
+            friction: None,
+        };
+    }
+
+    let escalate = RoutingDecision {
+        route: Route::ConstitutionalJury,
+        status: ProposalStatus::RequiresHumanReview,
+        friction: None,
+    };
+
+    if policy.should_auto_escalate(channel_b.semantic_alignment_score) {
+        return escalate;
+    }
+
+    let friction = Some(FrictionParams::from_alignment_score(
+        channel_b.semantic_alignment_score,
+    ));
+    match channel_b.decidability_class {
+        // Class I votes once PoUW miners have verified it; the route tells
+        // the caller to dispatch to the marketplace first
+        DecidabilityClass::I => RoutingDecision {
+            route: Route::PoUW,
+            status: ProposalStatus::Voting,
+            friction,
+        },
+        DecidabilityClass::II => RoutingDecision {
+            route: Route::StandardVoting,
+            status: ProposalStatus::Voting,
+            friction,
+        },
+        DecidabilityClass::III => escalate,
+    }
+}
+
+/// Advance a proposal's lifecycle status given the verdicts so far
+///
+/// | Current status / verdicts             | Next status                          |
+/// |---------------------------------------|--------------------------------------|
+/// | `Passed`, `Rejected`, `Executed`, `RequiresHumanReview` | unchanged (settled elsewhere) |
+/// | A FAIL, pipeline could not score it   | `RequiresHumanReview`                |
+/// | A FAIL                                | `Rejected`                           |
+/// | A PASS, logic disables verification   | `RequiresHumanReview`                |
+/// | A PASS, no B yet                      | `ChannelBReview`                     |
+/// | A PASS, B present                     | per `decide_routing` (default policy) |
+///
+/// A Channel A failure is structurally undecidable, rather than a clear
+/// rejection, when the complexity score is `COMPLEXITY_FAILURE`: the
+/// deterministic pipeline itself could not evaluate the payload, so a human
+/// has to.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::routing::route_proposal;
+/// use constitution_dao_core::{ChannelAVerdict, ChannelBVerdict, DecidabilityClass, GovernanceLayer, Proposal, ProposalStatus};
+///
+/// let proposal = Proposal::new("rAddr".into(), "{}".into(), "Fund it".into(), GovernanceLayer::L2Operational);
+/// let a = ChannelAVerdict::pass(80);
+///
+/// assert_eq!(route_proposal(&proposal, &a, None), (ProposalStatus::ChannelBReview, None));
+///
+/// let b = ChannelBVerdict::new(0.9, DecidabilityClass::II);
+/// let (status, friction) = route_proposal(&proposal, &a, Some(&b));
+/// assert_eq!(status, ProposalStatus::Voting);
+/// assert!(friction.is_some());
+/// ```
+pub fn route_proposal(
+    proposal: &Proposal,
+    channel_a: &ChannelAVerdict,
+    channel_b: Option<&ChannelBVerdict>,
+) -> (ProposalStatus, Option<FrictionParams>) {
+    match proposal.status {
+        ProposalStatus::Passed
+        | ProposalStatus::Rejected
+        | ProposalStatus::Executed
+        | ProposalStatus::RequiresHumanReview => return (proposal.status, None),
+        _ => {}
+    }
+
+    if !channel_a.pass {
+        if channel_a.complexity_score == COMPLEXITY_FAILURE {
+            return (ProposalStatus::RequiresHumanReview, None);
+        }
+        return (ProposalStatus::Rejected, None);
+    }
+
+    // Mandatory jury review, whatever Channel B says
+    if !detects_verification_disabling(&proposal.logic_ast).is_empty() {
+        return (ProposalStatus::RequiresHumanReview, None);
+    }
+
+    match channel_b {
+        None => (ProposalStatus::ChannelBReview, None),
+        Some(b) => {
+            let decision = decide_routing(channel_a, b, &DecidabilityPolicy::default());
+            (decision.status, decision.friction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: f64 = config::AUTO_ESCALATE_ALIGNMENT;
+
+    #[test]
+    fn test_should_auto_escalate_boundary() {
+        assert!(!should_auto_escalate(THRESHOLD));
+        assert!(!should_auto_escalate(THRESHOLD + 1e-9));
+        assert!(should_auto_escalate(THRESHOLD - 1e-9));
+        assert!(should_auto_escalate(0.0));
+        assert!(should_auto_escalate(f64::NAN));
+    }
+
+    #[test]
+    fn test_just_above_threshold_gets_friction() {
+        let a = ChannelAVerdict::pass(100);
+        let b = ChannelBVerdict::new(THRESHOLD + 0.01, DecidabilityClass::II);
+
+        let decision = decide_routing(&a, &b, &DecidabilityPolicy::default());
+        assert_eq!(decision.route, Route::StandardVoting);
+        assert_eq!(decision.status, ProposalStatus::Voting);
+        assert_eq!(
+            decision.friction,
+            Some(FrictionParams::from_alignment_score(THRESHOLD + 0.01))
+        );
+    }
+
+    #[test]
+    fn test_just_below_threshold_escalates() {
+        let a = ChannelAVerdict::pass(100);
+        let b = ChannelBVerdict::new(THRESHOLD - 0.01, DecidabilityClass::II);
+
+        let decision = decide_routing(&a, &b, &DecidabilityPolicy::default());
+        assert_eq!(decision.route, Route::ConstitutionalJury);
+        assert_eq!(decision.status, ProposalStatus::RequiresHumanReview);
+        assert!(decision.friction.is_none());
+    }
+
+    #[test]
+    fn test_custom_policy_threshold() {
+        let policy = DecidabilityPolicy {
+            auto_escalate_alignment: 0.5,
+        };
+        let a = ChannelAVerdict::pass(100);
+        let b = ChannelBVerdict::new(0.4, DecidabilityClass::II);
+
+        assert_eq!(
+            decide_routing(&a, &b, &policy).route,
+            Route::ConstitutionalJury
+        );
+        assert_eq!(
+            decide_routing(&a, &b, &DecidabilityPolicy::default()).route,
+            Route::StandardVoting
+        );
+    }
+
+    #[test]
+    fn test_each_decidability_class() {
+        let a = ChannelAVerdict::pass(100);
+        let policy = DecidabilityPolicy::default();
+        let friction = Some(FrictionParams::from_alignment_score(0.8));
+
+        let cases = [
+            (
+                DecidabilityClass::I,
+                Route::PoUW,
+                ProposalStatus::Voting,
+                friction.clone(),
+            ),
+            (
+                DecidabilityClass::II,
+                Route::StandardVoting,
+                ProposalStatus::Voting,
+                friction,
+            ),
+            (
+                DecidabilityClass::III,
+                Route::ConstitutionalJury,
+                ProposalStatus::RequiresHumanReview,
+                None,
+            ),
+        ];
+        for (class, route, status, friction) in cases {
+            let decision = decide_routing(&a, &ChannelBVerdict::new(0.8, class), &policy);
+            assert_eq!(
+                decision,
+                RoutingDecision {
+                    route,
+                    status,
+                    friction
+                },
+                "{:?}",
+                class
+            );
+        }
+
+        // A Channel A failure wins over every class
+        let failed = ChannelAVerdict::fail(100, false, true);
+        for class in [
+            DecidabilityClass::I,
+            DecidabilityClass::II,
+            DecidabilityClass::III,
+        ] {
+            let decision = decide_routing(&failed, &ChannelBVerdict::new(1.0, class), &policy);
+            assert_eq!(
+                (decision.route, decision.friction),
+                (Route::Rejected, None),
+                "{:?}",
+                class
+            );
+        }
+    }
+
+    #[test]
+    fn test_channel_a_fail_rejects() {
+        let a = ChannelAVerdict::fail(100, true, false);
+        let b = ChannelBVerdict::new(0.05, DecidabilityClass::III);
+
+        let decision = decide_routing(&a, &b, &DecidabilityPolicy::default());
+        assert_eq!(decision.route, Route::Rejected);
+        assert_eq!(decision.status, ProposalStatus::Rejected);
+    }
+
+    fn pending_proposal() -> Proposal {
+        Proposal::new(
+            "rTestAddress123".to_string(),
+            r#"{"action": "transfer"}"#.to_string(),
+            "Transfer 100 tokens".to_string(),
+            crate::types::GovernanceLayer::L2Operational,
+        )
+    }
+
+    #[test]
+    fn test_route_proposal_lifecycle_class_ii() {
+        let mut proposal = pending_proposal();
+        let a = ChannelAVerdict::pass(100);
+
+        // Pending -> ChannelBReview once Channel A passes
+        let (status, friction) = route_proposal(&proposal, &a, None);
+        assert_eq!(status, ProposalStatus::ChannelBReview);
+        assert!(friction.is_none());
+        proposal.status = status;
+
+        // ChannelBReview -> Voting with friction once Channel B reports
+        let b = ChannelBVerdict::new(0.6, DecidabilityClass::II);
+        let (status, friction) = route_proposal(&proposal, &a, Some(&b));
+        assert_eq!(status, ProposalStatus::Voting);
+        assert_eq!(friction, Some(FrictionParams::from_alignment_score(0.6)));
+        proposal.status = status;
+
+        // Settled states are not re-routed
+        proposal.status = ProposalStatus::Passed;
+        assert_eq!(
+            route_proposal(&proposal, &a, Some(&b)),
+            (ProposalStatus::Passed, None)
+        );
+        proposal.status = ProposalStatus::Executed;
+        assert_eq!(
+            route_proposal(&proposal, &a, Some(&b)),
+            (ProposalStatus::Executed, None)
+        );
+    }
+
+    #[test]
+    fn test_route_proposal_class_iii_escalates() {
+        let mut proposal = pending_proposal();
+        proposal.status = ProposalStatus::ChannelBReview;
+        let a = ChannelAVerdict::pass(100);
+        let b = ChannelBVerdict::new(0.9, DecidabilityClass::III);
+
+        let (status, friction) = route_proposal(&proposal, &a, Some(&b));
+        assert_eq!(status, ProposalStatus::RequiresHumanReview);
+        assert!(friction.is_none());
+
+        proposal.status = status;
+        let (status, _) = route_proposal(
+            &proposal,
+            &a,
+            Some(&ChannelBVerdict::new(0.9, DecidabilityClass::II)),
+        );
+        assert_eq!(status, ProposalStatus::RequiresHumanReview);
+    }
+
+    #[test]
+    fn test_route_proposal_verification_disabling_goes_to_jury() {
+        let mut proposal = pending_proposal();
+        proposal.logic_ast = r#"{"parameter": "active_oracle_set_size", "value": 0}"#.to_string();
+        let a = ChannelAVerdict::pass(100);
+        let b = ChannelBVerdict::new(0.95, DecidabilityClass::II);
+
+        assert_eq!(
+            route_proposal(&proposal, &a, None),
+            (ProposalStatus::RequiresHumanReview, None)
+        );
+        assert_eq!(
+            route_proposal(&proposal, &a, Some(&b)),
+            (ProposalStatus::RequiresHumanReview, None)
+        );
+
+        proposal.logic_ast = r#"{"parameter": "active_oracle_set_size", "value": 121}"#.to_string();
+        assert_eq!(
+            route_proposal(&proposal, &a, Some(&b)).0,
+            ProposalStatus::Voting
+        );
+    }
+
+    #[test]
+    fn test_route_proposal_channel_a_failures() {
+        let mut proposal = pending_proposal();
+        proposal.status = ProposalStatus::ChannelAReview;
+
+        let b = ChannelBVerdict::new(0.9, DecidabilityClass::II);
+        let paradox = ChannelAVerdict::fail(100, true, false);
+        assert_eq!(
+            route_proposal(&proposal, &paradox, None),
+            (ProposalStatus::Rejected, None)
+        );
+        assert_eq!(
+            route_proposal(&proposal, &paradox, Some(&b)),
+            (ProposalStatus::Rejected, None)
+        );
+
+        let unscorable = ChannelAVerdict::fail(COMPLEXITY_FAILURE, false, false);
+        assert_eq!(
+            route_proposal(&proposal, &unscorable, None),
+            (ProposalStatus::RequiresHumanReview, None)
+        );
+    }
+}
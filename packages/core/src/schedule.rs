@@ -0,0 +1,75 @@
+//! Oracle Scheduling
+//!
+//! Block arithmetic for oracle epochs and report windows, so oracles and
+//! the chain agree on boundaries:
+//!
+//! - Epoch `e` spans blocks `[e * ORACLE_EPOCH, (e + 1) * ORACLE_EPOCH)`;
+//!   block 0 is the first block of epoch 0
+//! - The report window is the first `ORACLE_WINDOW` blocks of each epoch
+
+use crate::types::config;
+
+/// Epoch a block belongs to
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::schedule::{epoch_for_block, epoch_start_block};
+/// use constitution_dao_core::config::ORACLE_EPOCH;
+///
+/// assert_eq!(epoch_for_block(0), 0);
+/// assert_eq!(epoch_for_block(ORACLE_EPOCH), 1);
+/// assert_eq!(epoch_start_block(epoch_for_block(ORACLE_EPOCH + 5)), ORACLE_EPOCH);
+/// ```
+pub fn epoch_for_block(block: u64) -> u64 {
+    block / config::ORACLE_EPOCH
+}
+
+/// First block of an epoch
+///
+/// Saturates at `u64::MAX` for epochs that start beyond the block range.
+pub fn epoch_start_block(epoch: u64) -> u64 {
+    epoch.saturating_mul(config::ORACLE_EPOCH)
+}
+
+/// Whether a block falls within its epoch's report window
+pub fn in_report_window(block: u64) -> bool {
+    block % config::ORACLE_EPOCH < config::ORACLE_WINDOW
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{ORACLE_EPOCH, ORACLE_WINDOW};
+
+    #[test]
+    fn test_epoch_boundaries() {
+        assert_eq!(epoch_for_block(0), 0);
+        assert_eq!(epoch_for_block(ORACLE_EPOCH - 1), 0);
+        assert_eq!(epoch_for_block(ORACLE_EPOCH), 1);
+        assert_eq!(epoch_for_block(3 * ORACLE_EPOCH - 1), 2);
+        assert_eq!(epoch_for_block(u64::MAX), u64::MAX / ORACLE_EPOCH);
+
+        assert_eq!(epoch_start_block(0), 0);
+        assert_eq!(epoch_start_block(2), 2 * ORACLE_EPOCH);
+        assert_eq!(epoch_start_block(u64::MAX), u64::MAX);
+
+        for block in [0, 1, ORACLE_EPOCH - 1, ORACLE_EPOCH, 5 * ORACLE_EPOCH + 17] {
+            let start = epoch_start_block(epoch_for_block(block));
+            assert!(start <= block && block - start < ORACLE_EPOCH);
+        }
+    }
+
+    #[test]
+    fn test_report_window_edges() {
+        assert!(in_report_window(0));
+        assert!(in_report_window(ORACLE_WINDOW - 1));
+        assert!(!in_report_window(ORACLE_WINDOW));
+        assert!(!in_report_window(ORACLE_EPOCH - 1));
+
+        // The window reopens at each epoch start
+        assert!(in_report_window(ORACLE_EPOCH));
+        assert!(in_report_window(ORACLE_EPOCH + ORACLE_WINDOW - 1));
+        assert!(!in_report_window(ORACLE_EPOCH + ORACLE_WINDOW));
+    }
+}
@@ -0,0 +1,52 @@
+//! Serde representation of 32-byte IDs as hex strings
+//!
+//! Used with `#[serde(with = "hex_array")]`. A `[u8; 32]` serializes as a
+//! 64-character lowercase hex string, the form of the on-chain proposal ID,
+//! instead of a 32-element array. Deserialization requires exactly 32
+//! bytes of hex (either case) and still accepts the array form, so JSON
+//! written before the change keeps loading. Both forms need a
+//! self-describing format such as JSON.
+
+use serde::de::{self, IgnoredAny, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+use std::fmt;
+
+pub(crate) fn serialize<S: Serializer>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(bytes))
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<[u8; 32], D::Error> {
+    deserializer.deserialize_any(HexArrayVisitor)
+}
+
+struct HexArrayVisitor;
+
+impl<'de> Visitor<'de> for HexArrayVisitor {
+    type Value = [u8; 32];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a 32-byte ID as a hex string or an array of 32 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let bytes =
+            hex::decode(value).map_err(|e| E::custom(format_args!("invalid hex ID: {}", e)))?;
+        let len = bytes.len();
+        bytes.try_into().map_err(|_| E::invalid_length(len, &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut id = [0u8; 32];
+        for (i, byte) in id.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(33, &self));
+        }
+        Ok(id)
+    }
+}
@@ -3,6 +3,9 @@
 //! These types implement the specification from v5.0 of the COINjecture AI Constitution DAO.
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+mod hex_array;
 
 /// Decidability classification for proposals
 ///
@@ -10,22 +13,17 @@ use serde::{Deserialize, Serialize};
 /// - Class I: Routed to PoUW Marketplace for formal verification
 /// - Class II: Requires Channel A PASS verdict to proceed
 /// - Class III: Automatically escalated to Constitutional Jury
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DecidabilityClass {
     /// Formally verifiable - route to PoUW miners
     I,
     /// Requires deterministic Channel A check
+    #[default]
     II,
     /// Requires human judgment - escalate to jury
     III,
 }
 
-impl Default for DecidabilityClass {
-    fn default() -> Self {
-        DecidabilityClass::II
-    }
-}
-
 /// Channel A verification verdict (deterministic)
 ///
 /// Binary PASS/FAIL result that acts as a hard gate on proposals.
@@ -62,6 +60,18 @@ impl ChannelAVerdict {
             cycle_found,
         }
     }
+
+    /// Fixed 11-byte encoding used for commitments
+    ///
+    /// Layout: `pass (1) || complexity_score (8, big-endian) || paradox_found (1) || cycle_found (1)`
+    pub fn to_canonical_bytes(&self) -> [u8; 11] {
+        let mut bytes = [0u8; 11];
+        bytes[0] = self.pass as u8;
+        bytes[1..9].copy_from_slice(&self.complexity_score.to_be_bytes());
+        bytes[9] = self.paradox_found as u8;
+        bytes[10] = self.cycle_found as u8;
+        bytes
+    }
 }
 
 /// Channel B verification verdict (heuristic)
@@ -100,7 +110,7 @@ impl ChannelBVerdict {
 /// Governance layer in the four-layer constitutional model
 ///
 /// Lower layers cannot modify higher layers (immutability gradient).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GovernanceLayer {
     /// L0: Immutable Core - Foundational axioms (off-chain verification)
     /// Note: L0 is not directly targetable by proposals
@@ -108,21 +118,17 @@ pub enum GovernanceLayer {
     /// L1: Constitutional Layer - High-level governance rules
     L1Constitutional,
     /// L2: Operational Layer - Day-to-day DAO parameters
+    #[default]
     L2Operational,
     /// L3: Execution Layer - Smart contract implementations
     L3Execution,
 }
 
-impl Default for GovernanceLayer {
-    fn default() -> Self {
-        GovernanceLayer::L2Operational
-    }
-}
-
 /// Status of a proposal in its lifecycle
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProposalStatus {
     /// Initial state after submission
+    #[default]
     Pending,
     /// Awaiting Channel A deterministic verification
     ChannelAReview,
@@ -140,16 +146,11 @@ pub enum ProposalStatus {
     Executed,
 }
 
-impl Default for ProposalStatus {
-    fn default() -> Self {
-        ProposalStatus::Pending
-    }
-}
-
 /// A governance proposal
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Proposal {
-    /// Unique identifier (sha256 of canonical payload)
+    /// Unique identifier (sha256 of canonical payload), hex in serialized form
+    #[serde(with = "hex_array")]
     pub id: [u8; 32],
     /// XRPL address of the proposer
     pub proposer: String,
@@ -167,14 +168,9 @@ pub struct Proposal {
 
 impl Proposal {
     /// Create a new proposal (ID will be computed from canonical payload)
-    pub fn new(
-        proposer: String,
-        logic_ast: String,
-        text: String,
-        layer: GovernanceLayer,
-    ) -> Self {
+    pub fn new(proposer: String, logic_ast: String, text: String, layer: GovernanceLayer) -> Self {
         Self {
-            id: [0u8; 32], // Will be set by canonicalization
+            id: [0u8; 32], // Set from the canonical hash by `canonicalize_and_id`
             proposer,
             logic_ast,
             text,
@@ -192,6 +188,62 @@ impl Proposal {
         self.id = id;
         self
     }
+
+    /// Check the proposal's fields before submitting it
+    ///
+    /// Catches proposals that would verify fine but fail on-chain, such as
+    /// a malformed proposer address. Not part of Channel A:
+    /// `verify_proposal` does not call it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::{GovernanceLayer, Proposal, ProposalError};
+    ///
+    /// let proposal = Proposal::new(
+    ///     "rNotAnAddress".to_string(),
+    ///     "{}".to_string(),
+    ///     "Transfer tokens".to_string(),
+    ///     GovernanceLayer::L2Operational,
+    /// );
+    /// assert!(matches!(proposal.validate(), Err(ProposalError::InvalidProposer(_))));
+    /// ```
+    pub fn validate(&self) -> Result<(), ProposalError> {
+        if !is_valid_xrpl_address(&self.proposer) {
+            return Err(ProposalError::InvalidProposer(self.proposer.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Why a proposal failed `Proposal::validate`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ProposalError {
+    #[error("Proposer {0:?} is not a valid classic XRPL address")]
+    InvalidProposer(String),
+}
+
+/// Whether `address` is a classic XRPL account address
+///
+/// Base58Check in the XRPL alphabet over version byte 0x00 and a 20-byte
+/// account ID. Version 0x00 is what gives classic addresses their leading
+/// `r`, and the 25 decoded bytes fix the length at 25 to 35 characters.
+/// X-addresses and other encodings are rejected.
+///
+/// # Example
+///
+/// ```
+/// use constitution_dao_core::is_valid_xrpl_address;
+///
+/// assert!(is_valid_xrpl_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+/// assert!(!is_valid_xrpl_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj"));
+/// ```
+pub fn is_valid_xrpl_address(address: &str) -> bool {
+    bs58::decode(address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check(Some(0x00))
+        .into_vec()
+        .is_ok_and(|decoded| decoded.len() == 21)
 }
 
 /// Friction parameters calculated from Channel B alignment score
@@ -214,16 +266,27 @@ impl FrictionParams {
     pub const BASE_QUORUM: f64 = 0.1;
     /// Base timelock (24 hours in seconds)
     pub const BASE_TIMELOCK: u64 = 86400;
+    /// Quorum multiplier at alignment score 0.0
+    pub const MAX_QUORUM_MULTIPLIER: f64 = 1.5;
+    /// Timelock multiplier at alignment score 0.0
+    pub const MAX_TIMELOCK_MULTIPLIER: f64 = 3.0;
 
     /// Calculate friction parameters from alignment score
     ///
     /// From spec v5.0:
     /// - Quorum Multiplier: 1.0 + (1.0 - alignment_score) * 0.5
     /// - Timelock Multiplier: 1.0 + (1.0 - alignment_score) * 2.0
+    ///
+    /// Non-finite scores (`NaN`, `±Infinity`) are treated as 0.0, the most
+    /// conservative value, so they yield maximum friction rather than NaN.
     pub fn from_alignment_score(alignment_score: f64) -> Self {
-        let score = alignment_score.clamp(0.0, 1.0);
-        let quorum_multiplier = 1.0 + (1.0 - score) * 0.5;
-        let timelock_multiplier = 1.0 + (1.0 - score) * 2.0;
+        let score = if alignment_score.is_finite() {
+            alignment_score.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let quorum_multiplier = 1.0 + (1.0 - score) * (Self::MAX_QUORUM_MULTIPLIER - 1.0);
+        let timelock_multiplier = 1.0 + (1.0 - score) * (Self::MAX_TIMELOCK_MULTIPLIER - 1.0);
 
         Self {
             required_quorum: Self::BASE_QUORUM * quorum_multiplier,
@@ -233,6 +296,97 @@ impl FrictionParams {
             timelock_multiplier,
         }
     }
+
+    /// Alignment score that yields a timelock of `seconds`
+    ///
+    /// Inverts the timelock multiplier formula. `None` if `seconds` is below
+    /// `BASE_TIMELOCK` or above `BASE_TIMELOCK * MAX_TIMELOCK_MULTIPLIER`; the
+    /// bounds themselves map to 1.0 and 0.0. The score is clamped to
+    /// `[0.0, 1.0]` so rounding never takes it out of range.
+    ///
+    /// `from_alignment_score` truncates the timelock to whole seconds, so the
+    /// score is nudged down (never up) until it yields exactly `seconds`. A
+    /// round trip through it returns the same duration, not the same score.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::FrictionParams;
+    ///
+    /// // A 48-hour timelock
+    /// let score = FrictionParams::alignment_for_timelock(172_800).unwrap();
+    /// assert_eq!(score, 0.5);
+    /// assert_eq!(FrictionParams::from_alignment_score(score).timelock_duration, 172_800);
+    ///
+    /// assert_eq!(FrictionParams::alignment_for_timelock(3_600), None);
+    /// ```
+    pub fn alignment_for_timelock(seconds: u64) -> Option<f64> {
+        let max = (Self::BASE_TIMELOCK as f64 * Self::MAX_TIMELOCK_MULTIPLIER) as u64;
+        if !(Self::BASE_TIMELOCK..=max).contains(&seconds) {
+            return None;
+        }
+        let multiplier = seconds as f64 / Self::BASE_TIMELOCK as f64;
+        let mut score = Self::invert_multiplier(multiplier, Self::MAX_TIMELOCK_MULTIPLIER);
+        // Off by at most a few ulps; a lower score means a longer timelock
+        while Self::from_alignment_score(score).timelock_duration < seconds {
+            score = score.next_down();
+        }
+        Some(score)
+    }
+
+    /// Alignment score that yields a required quorum of `quorum`
+    ///
+    /// Inverts the quorum multiplier formula. `None` if `quorum` is not
+    /// finite, below `BASE_QUORUM` or above the quorum at score 0.0
+    /// (`BASE_QUORUM * MAX_QUORUM_MULTIPLIER`); the bounds themselves map to
+    /// 1.0 and 0.0. The score is clamped to `[0.0, 1.0]`.
+    pub fn alignment_for_quorum(quorum: f64) -> Option<f64> {
+        let max = Self::BASE_QUORUM * Self::MAX_QUORUM_MULTIPLIER;
+        if !(Self::BASE_QUORUM..=max).contains(&quorum) {
+            return None;
+        }
+        let multiplier = quorum / Self::BASE_QUORUM;
+        Some(Self::invert_multiplier(
+            multiplier,
+            Self::MAX_QUORUM_MULTIPLIER,
+        ))
+    }
+
+    /// Solve `multiplier = 1.0 + (1.0 - score) * (max - 1.0)` for `score`
+    fn invert_multiplier(multiplier: f64, max: f64) -> f64 {
+        (1.0 - (multiplier - 1.0) / (max - 1.0)).clamp(0.0, 1.0)
+    }
+}
+
+/// Change in friction between two alignment scores
+///
+/// Each field is `to - from`, so a positive value means more friction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrictionDelta {
+    /// Change in required quorum (fraction of voting power)
+    pub quorum_delta: f64,
+    /// Change in required quorum in basis points (rounded)
+    pub quorum_delta_bps: i64,
+    /// Change in timelock duration in seconds
+    pub timelock_delta_secs: i64,
+}
+
+/// Calculate the change in friction when the alignment score moves from
+/// `from` to `to`
+///
+/// Both scores go through [`FrictionParams::from_alignment_score`], so
+/// clamping and non-finite handling match the friction actually applied.
+/// Swapping the arguments negates every field.
+pub fn friction_delta(from: f64, to: f64) -> FrictionDelta {
+    let from = FrictionParams::from_alignment_score(from);
+    let to = FrictionParams::from_alignment_score(to);
+    let quorum_delta = to.required_quorum - from.required_quorum;
+
+    FrictionDelta {
+        quorum_delta,
+        quorum_delta_bps: (quorum_delta * 10_000.0).round() as i64,
+        timelock_delta_secs: to.timelock_duration as i64 - from.timelock_duration as i64,
+    }
 }
 
 /// Vote options for proposals
@@ -263,12 +417,19 @@ pub struct OracleOperator {
 /// Fraud proof for Channel A misbehavior
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FraudProof {
-    /// The proposal being challenged
+    /// The proposal being challenged, hex in serialized form
+    #[serde(with = "hex_array")]
     pub proposal_id: [u8; 32],
     /// The verdict that was submitted by the oracle
     pub claimed_verdict: ChannelAVerdict,
     /// The correct verdict (recomputed)
     pub actual_verdict: ChannelAVerdict,
+    /// Layer the proposal was submitted under (not part of the payload)
+    pub layer: GovernanceLayer,
+    /// `VerificationConfig::rules_hash` of the rules the oracle verified
+    /// under, hex in serialized form
+    #[serde(with = "hex_array")]
+    pub rules_hash: [u8; 32],
     /// Witness data for verification
     pub witness: FraudProofWitness,
 }
@@ -278,7 +439,9 @@ pub struct FraudProof {
 pub struct FraudProofWitness {
     /// The canonical payload bytes (hex encoded)
     pub canonical_payload: String,
-    /// Computation trace for debugging
+    /// Ordered, human-readable trace of each pipeline step
+    ///
+    /// See `channel_a::verify_proposal_traced`.
     pub computation_trace: Vec<String>,
 }
 
@@ -287,6 +450,41 @@ pub mod config {
     /// Maximum allowed complexity score (from spec)
     pub const MAX_COMPLEXITY: u64 = 10_000;
 
+    /// Version of the canonical payload form
+    ///
+    /// Bumped whenever a change alters canonical bytes (and so proposal IDs);
+    /// anything keyed by canonical hash must also be keyed by this version.
+    ///
+    /// - 1: original v5.1 form
+    /// - 2: text is NFKC-normalized before lowercasing
+    /// - 3: integral floats in the AST are printed as integers
+    /// - 4: the payload is prefixed with this version as a single byte
+    pub const CANONICAL_FORM_VERSION: u8 = 4;
+
+    /// Maximum number of edges in a proposal's dependency graph
+    ///
+    /// Consensus-relevant: a graph exceeding this fails cycle detection.
+    pub const MAX_GRAPH_EDGES: usize = 100_000;
+
+    /// Maximum number of nodes in a proposal's dependency graph
+    ///
+    /// Consensus-relevant: a graph exceeding this fails cycle detection.
+    pub const MAX_GRAPH_NODES: usize = 50_000;
+
+    /// Maximum nesting depth of a logic AST analyzed for cycles
+    ///
+    /// The root is depth 0. Matches serde_json's parser recursion limit,
+    /// so only ASTs built directly as values can exceed it.
+    /// Consensus-relevant: a deeper AST fails cycle detection.
+    pub const MAX_AST_DEPTH: usize = 128;
+
+    /// Maximum raw proposal size in bytes (logic AST plus text) accepted for ingest
+    ///
+    /// Also the longest canonical payload compressed for a complexity
+    /// score; longer payloads score `COMPLEXITY_FAILURE` without being
+    /// compressed. Consensus-relevant through that second use.
+    pub const MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
+
     /// Oracle bond amount (100,000 XRP equivalent)
     pub const ORACLE_BOND: &str = "100000000000"; // 100,000 XRP in drops
 
@@ -313,12 +511,135 @@ pub mod config {
 
     /// Required supermajority for jury (2/3)
     pub const JURY_SUPERMAJORITY: f64 = 2.0 / 3.0;
+
+    /// Channel B alignment below which proposals skip friction and go to the jury
+    pub const AUTO_ESCALATE_ALIGNMENT: f64 = 0.2;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_xrpl_address_validation() {
+        // Genesis account and the special addresses for account IDs 0, 1 and the name reservation
+        for good in [
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "rrrrrrrrrrrrrrrrrrrrBZbvji",
+            "rrrrrrrrrrrrrrrrrNAMEtxvNvQ",
+        ] {
+            assert!(is_valid_xrpl_address(good), "{}", good);
+        }
+
+        for bad in [
+            // Bad checksums
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj",
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyth",
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTq",
+            // Wrong prefix: an X-address, a Bitcoin address, a classic address with its `r` dropped
+            "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ",
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            "Hb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            // Characters outside the XRPL alphabet
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyT0",
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTl",
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdty h",
+            "",
+        ] {
+            assert!(!is_valid_xrpl_address(bad), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_proposal_validate() {
+        let proposal = |proposer: &str| {
+            Proposal::new(
+                proposer.to_string(),
+                "{}".to_string(),
+                "Transfer".to_string(),
+                GovernanceLayer::L2Operational,
+            )
+        };
+        assert_eq!(
+            proposal("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").validate(),
+            Ok(())
+        );
+        assert_eq!(
+            proposal("rTestAddress123").validate(),
+            Err(ProposalError::InvalidProposer(
+                "rTestAddress123".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ids_serialize_as_hex() {
+        let id = [0xAB; 32];
+        let proposal = Proposal::new(
+            "rAddr".to_string(),
+            "{}".to_string(),
+            "Fund it".to_string(),
+            GovernanceLayer::L2Operational,
+        )
+        .with_id(id);
+        let json = serde_json::to_value(&proposal).unwrap();
+        assert_eq!(json["id"], "ab".repeat(32));
+        assert_eq!(serde_json::from_value::<Proposal>(json).unwrap(), proposal);
+
+        let proof = FraudProof {
+            proposal_id: id,
+            claimed_verdict: ChannelAVerdict::pass(10),
+            actual_verdict: ChannelAVerdict::fail(10, true, false),
+            layer: GovernanceLayer::L2Operational,
+            rules_hash: [0xCD; 32],
+            witness: FraudProofWitness {
+                canonical_payload: "00".to_string(),
+                computation_trace: Vec::new(),
+            },
+        };
+        let json = serde_json::to_string(&proof).unwrap();
+        assert!(json.contains(&format!(r#""proposal_id":"{}""#, "ab".repeat(32))));
+        assert!(json.contains(&format!(r#""rules_hash":"{}""#, "cd".repeat(32))));
+        assert_eq!(serde_json::from_str::<FraudProof>(&json).unwrap(), proof);
+    }
+
+    #[test]
+    fn test_hex_id_deserialization() {
+        let with_id = |id: serde_json::Value| {
+            let mut json = serde_json::to_value(Proposal::new(
+                "rAddr".to_string(),
+                "{}".to_string(),
+                "Fund it".to_string(),
+                GovernanceLayer::L2Operational,
+            ))
+            .unwrap();
+            json["id"] = id;
+            serde_json::from_value::<Proposal>(json).map(|p| p.id)
+        };
+
+        // Uppercase hex and the legacy array form are accepted
+        assert_eq!(
+            with_id(serde_json::json!("AB".repeat(32))).unwrap(),
+            [0xAB; 32]
+        );
+        assert_eq!(with_id(serde_json::json!(vec![7u8; 32])).unwrap(), [7; 32]);
+
+        // 31 and 33 bytes, odd-length and non-hex strings are rejected
+        for bad in [
+            serde_json::json!("ab".repeat(31)),
+            serde_json::json!("ab".repeat(33)),
+            serde_json::json!("a".repeat(63)),
+            serde_json::json!("zz".repeat(32)),
+            serde_json::json!(vec![7u8; 31]),
+            serde_json::json!(vec![7u8; 33]),
+        ] {
+            assert!(with_id(bad.clone()).is_err(), "{}", bad);
+        }
+        let err = with_id(serde_json::json!("ab".repeat(31))).unwrap_err();
+        assert!(err.to_string().contains("invalid length 31"), "{}", err);
+    }
+
     #[test]
     fn test_friction_params_perfect_alignment() {
         let params = FrictionParams::from_alignment_score(1.0);
@@ -333,7 +654,7 @@ mod tests {
         let params = FrictionParams::from_alignment_score(0.0);
         assert_eq!(params.quorum_multiplier, 1.5);
         assert_eq!(params.timelock_multiplier, 3.0);
-        assert_eq!(params.required_quorum, 0.15);
+        assert!((params.required_quorum - 0.15).abs() < 1e-12);
         assert_eq!(params.timelock_duration, 259200);
     }
 
@@ -344,6 +665,113 @@ mod tests {
         assert_eq!(params.timelock_multiplier, 2.0);
     }
 
+    #[test]
+    fn test_friction_params_non_finite_is_conservative() {
+        let max = FrictionParams::from_alignment_score(0.0);
+        for score in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let params = FrictionParams::from_alignment_score(score);
+            assert!(params.required_quorum.is_finite());
+            assert!(params.quorum_multiplier.is_finite());
+            assert!(params.timelock_multiplier.is_finite());
+            assert_eq!(params, max, "score {}", score);
+        }
+    }
+
+    #[test]
+    fn test_alignment_for_timelock_round_trips() {
+        for score in [0.0, 0.1, 0.25, 1.0 / 3.0, 0.5, 0.75, 0.9, 0.999, 1.0] {
+            let params = FrictionParams::from_alignment_score(score);
+            let inverse = FrictionParams::alignment_for_timelock(params.timelock_duration).unwrap();
+            assert!(
+                (inverse - score).abs() < 1e-5,
+                "score {} -> {}",
+                score,
+                inverse
+            );
+            assert_eq!(
+                FrictionParams::from_alignment_score(inverse).timelock_duration,
+                params.timelock_duration,
+                "score {}",
+                score
+            );
+        }
+        for seconds in 86_400..=259_200 {
+            let score = FrictionParams::alignment_for_timelock(seconds).unwrap();
+            assert_eq!(
+                FrictionParams::from_alignment_score(score).timelock_duration,
+                seconds
+            );
+        }
+    }
+
+    #[test]
+    fn test_alignment_for_timelock_bounds() {
+        assert_eq!(FrictionParams::alignment_for_timelock(86_400), Some(1.0));
+        assert_eq!(FrictionParams::alignment_for_timelock(259_200), Some(0.0));
+        assert_eq!(FrictionParams::alignment_for_timelock(86_399), None);
+        assert_eq!(FrictionParams::alignment_for_timelock(259_201), None);
+        assert_eq!(FrictionParams::alignment_for_timelock(0), None);
+    }
+
+    #[test]
+    fn test_alignment_for_quorum_round_trips() {
+        for score in [0.0, 0.1, 0.25, 1.0 / 3.0, 0.5, 0.75, 0.9, 0.999, 1.0] {
+            let params = FrictionParams::from_alignment_score(score);
+            let inverse = FrictionParams::alignment_for_quorum(params.required_quorum).unwrap();
+            assert!(
+                (inverse - score).abs() < 1e-9,
+                "score {} -> {}",
+                score,
+                inverse
+            );
+            let round_trip = FrictionParams::from_alignment_score(inverse).required_quorum;
+            assert!(
+                (round_trip - params.required_quorum).abs() < 1e-12,
+                "score {}",
+                score
+            );
+        }
+    }
+
+    #[test]
+    fn test_alignment_for_quorum_bounds() {
+        let max = FrictionParams::from_alignment_score(0.0).required_quorum;
+        assert_eq!(FrictionParams::alignment_for_quorum(0.1), Some(1.0));
+        assert_eq!(FrictionParams::alignment_for_quorum(max), Some(0.0));
+        assert!(FrictionParams::alignment_for_quorum(0.15).unwrap() < 1e-12);
+        assert_eq!(FrictionParams::alignment_for_quorum(0.099), None);
+        assert_eq!(FrictionParams::alignment_for_quorum(0.151), None);
+        for quorum in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(FrictionParams::alignment_for_quorum(quorum), None);
+        }
+    }
+
+    #[test]
+    fn test_friction_delta_spec_vectors() {
+        let delta = friction_delta(1.0, 0.0);
+        assert!((delta.quorum_delta - 0.05).abs() < 1e-9);
+        assert_eq!(delta.quorum_delta_bps, 500);
+        assert_eq!(delta.timelock_delta_secs, 172_800);
+
+        let delta = friction_delta(1.0, 0.5);
+        assert_eq!(delta.quorum_delta_bps, 250);
+        assert_eq!(delta.timelock_delta_secs, 86_400);
+
+        assert_eq!(friction_delta(0.7, 0.7).timelock_delta_secs, 0);
+        assert_eq!(friction_delta(f64::NAN, 0.0).quorum_delta_bps, 0);
+    }
+
+    #[test]
+    fn test_friction_delta_is_antisymmetric() {
+        for (a, b) in [(1.0, 0.0), (0.6, 0.8), (0.25, 0.9), (0.0, 0.33)] {
+            let forward = friction_delta(a, b);
+            let back = friction_delta(b, a);
+            assert_eq!(forward.quorum_delta, -back.quorum_delta);
+            assert_eq!(forward.quorum_delta_bps, -back.quorum_delta_bps);
+            assert_eq!(forward.timelock_delta_secs, -back.timelock_delta_secs);
+        }
+    }
+
     #[test]
     fn test_channel_a_verdict() {
         let pass = ChannelAVerdict::pass(100);
@@ -356,6 +784,16 @@ mod tests {
         assert!(fail.paradox_found);
     }
 
+    #[test]
+    fn test_verdict_canonical_bytes() {
+        let verdict = ChannelAVerdict::fail(0x0102, true, false);
+        assert_eq!(
+            verdict.to_canonical_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 0]
+        );
+        assert_eq!(ChannelAVerdict::pass(1).to_canonical_bytes()[0], 1);
+    }
+
     #[test]
     fn test_channel_b_verdict_clamps() {
         let verdict = ChannelBVerdict::new(1.5, DecidabilityClass::II);
@@ -0,0 +1,306 @@
+//! Compact Wire Encoding
+//!
+//! Nodes gossip proposals peer-to-peer, where JSON is bulky. The compact
+//! encoding carries a proposal with a fixed binary layout, so the same
+//! proposal always encodes to the same bytes:
+//!
+//! ```text
+//! version (1) || layer (1) || status (1) || created_at (8, big-endian)
+//! || len (4, big-endian) || proposer
+//! || len (4, big-endian) || logic AST
+//! || len (4, big-endian) || text
+//! ```
+//!
+//! The AST and text are sent as submitted, so a proposal round-trips to
+//! an identical `Proposal`. The ID is not sent; it is recomputed from the
+//! decoded contents.
+
+use thiserror::Error;
+
+use crate::channel_a::{canonicalize, CanonicalizeError};
+use crate::types::{GovernanceLayer, Proposal, ProposalStatus};
+
+/// Current compact encoding version
+pub const COMPACT_VERSION: u8 = 1;
+
+/// Size of the fixed header (version, layer, status, created_at)
+const HEADER_LEN: usize = 11;
+
+/// Errors from decoding a compact proposal
+#[derive(Debug, Error)]
+pub enum CompactError {
+    #[error("Unsupported compact encoding version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Encoding ends before the {0} field")]
+    Truncated(&'static str),
+    #[error("Invalid governance layer byte {0}")]
+    InvalidLayer(u8),
+    #[error("Invalid proposal status byte {0}")]
+    InvalidStatus(u8),
+    #[error("The {0} field is not valid UTF-8")]
+    InvalidUtf8(&'static str),
+    #[error("{0} trailing bytes after the last field")]
+    TrailingBytes(usize),
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
+
+impl Proposal {
+    /// Encode the proposal in the compact wire format
+    ///
+    /// Fails only if the logic AST cannot be canonicalized, as the decoder
+    /// could not then recompute the ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constitution_dao_core::{GovernanceLayer, Proposal};
+    ///
+    /// let proposal = Proposal::new(
+    ///     "rAddr".to_string(),
+    ///     r#"{"action": "transfer", "amount": 100}"#.to_string(),
+    ///     "Transfer 100 tokens".to_string(),
+    ///     GovernanceLayer::L2Operational,
+    /// );
+    ///
+    /// let bytes = proposal.to_compact_bytes().unwrap();
+    /// let decoded = Proposal::from_compact_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded.logic_ast, proposal.logic_ast);
+    /// assert_eq!(decoded.text, proposal.text);
+    /// ```
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>, CanonicalizeError> {
+        canonicalize(self)?;
+        let fields = [
+            self.proposer.as_bytes(),
+            self.logic_ast.as_bytes(),
+            self.text.as_bytes(),
+        ];
+
+        let mut bytes =
+            Vec::with_capacity(HEADER_LEN + fields.iter().map(|f| 4 + f.len()).sum::<usize>());
+        bytes.push(COMPACT_VERSION);
+        bytes.push(layer_byte(self.layer));
+        bytes.push(status_byte(self.status));
+        bytes.extend_from_slice(&self.created_at.to_be_bytes());
+        for field in fields {
+            bytes.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(field);
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a proposal from the compact wire format
+    ///
+    /// The returned proposal's `id` is its canonical hash.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, CompactError> {
+        let header = bytes
+            .get(..HEADER_LEN)
+            .ok_or(CompactError::Truncated("header"))?;
+        if header[0] != COMPACT_VERSION {
+            return Err(CompactError::UnsupportedVersion(header[0]));
+        }
+        let layer = layer_from_byte(header[1]).ok_or(CompactError::InvalidLayer(header[1]))?;
+        let status = status_from_byte(header[2]).ok_or(CompactError::InvalidStatus(header[2]))?;
+        let created_at =
+            u64::from_be_bytes(header[3..HEADER_LEN].try_into().expect("8-byte slice"));
+
+        let mut rest = &bytes[HEADER_LEN..];
+        let proposer = read_field(&mut rest, "proposer")?;
+        let logic_ast = read_field(&mut rest, "logic_ast")?;
+        let text = read_field(&mut rest, "text")?;
+        if !rest.is_empty() {
+            return Err(CompactError::TrailingBytes(rest.len()));
+        }
+
+        let mut proposal = Proposal {
+            id: [0u8; 32],
+            proposer,
+            logic_ast,
+            text,
+            layer,
+            created_at,
+            status,
+        };
+        proposal.id = canonicalize(&proposal)?.hash;
+        Ok(proposal)
+    }
+}
+
+/// Read one length-prefixed UTF-8 field, advancing `rest` past it
+fn read_field(rest: &mut &[u8], name: &'static str) -> Result<String, CompactError> {
+    let len_bytes = rest.get(..4).ok_or(CompactError::Truncated(name))?;
+    let len = u32::from_be_bytes(len_bytes.try_into().expect("4-byte slice")) as usize;
+    let field = rest
+        .get(4..)
+        .and_then(|r| r.get(..len))
+        .ok_or(CompactError::Truncated(name))?;
+    let value = std::str::from_utf8(field)
+        .map_err(|_| CompactError::InvalidUtf8(name))?
+        .to_string();
+    *rest = &rest[4 + len..];
+    Ok(value)
+}
+
+fn layer_byte(layer: GovernanceLayer) -> u8 {
+    match layer {
+        GovernanceLayer::L0Immutable => 0,
+        GovernanceLayer::L1Constitutional => 1,
+        GovernanceLayer::L2Operational => 2,
+        GovernanceLayer::L3Execution => 3,
+    }
+}
+
+fn layer_from_byte(byte: u8) -> Option<GovernanceLayer> {
+    Some(match byte {
+        0 => GovernanceLayer::L0Immutable,
+        1 => GovernanceLayer::L1Constitutional,
+        2 => GovernanceLayer::L2Operational,
+        3 => GovernanceLayer::L3Execution,
+        _ => return None,
+    })
+}
+
+fn status_byte(status: ProposalStatus) -> u8 {
+    match status {
+        ProposalStatus::Pending => 0,
+        ProposalStatus::ChannelAReview => 1,
+        ProposalStatus::ChannelBReview => 2,
+        ProposalStatus::Voting => 3,
+        ProposalStatus::RequiresHumanReview => 4,
+        ProposalStatus::Passed => 5,
+        ProposalStatus::Rejected => 6,
+        ProposalStatus::Executed => 7,
+    }
+}
+
+fn status_from_byte(byte: u8) -> Option<ProposalStatus> {
+    Some(match byte {
+        0 => ProposalStatus::Pending,
+        1 => ProposalStatus::ChannelAReview,
+        2 => ProposalStatus::ChannelBReview,
+        3 => ProposalStatus::Voting,
+        4 => ProposalStatus::RequiresHumanReview,
+        5 => ProposalStatus::Passed,
+        6 => ProposalStatus::Rejected,
+        7 => ProposalStatus::Executed,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proposal(ast: &str, text: &str) -> Proposal {
+        let mut proposal = Proposal::new(
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".to_string(),
+            ast.to_string(),
+            text.to_string(),
+            GovernanceLayer::L1Constitutional,
+        );
+        proposal.created_at = 1_700_000_000;
+        proposal.status = ProposalStatus::Voting;
+        proposal
+    }
+
+    #[test]
+    fn test_proposal_roundtrips_exactly() {
+        let cases = [
+            (
+                r#"{"action":"transfer","amount":100}"#,
+                "transfer 100 tokens",
+            ),
+            (r#"{"to": "alice",  "amount": 1.0}"#, "Pay Alice 1.0 XRP!"),
+            (
+                r#"{"name":"Zoë 😀","plan":{"steps":[1,[2,null]]}}"#,
+                "Ünïcödé  TËXT 日本語",
+            ),
+            (r#"{}"#, ""),
+        ];
+
+        for (ast, text) in cases {
+            let original = proposal(ast, text);
+            let original = original
+                .clone()
+                .with_id(canonicalize(&original).unwrap().hash);
+
+            let decoded =
+                Proposal::from_compact_bytes(&original.to_compact_bytes().unwrap()).unwrap();
+            assert_eq!(decoded, original, "{}", ast);
+        }
+    }
+
+    #[test]
+    fn test_encoding_is_deterministic() {
+        let a = proposal(r#"{"b": 2,  "a": [1, 2]}"#, "Hello,   WORLD!");
+        let b = proposal(r#"{"a":[1,2],"b":2}"#, "hello world");
+
+        let bytes = a.to_compact_bytes().unwrap();
+        assert_eq!(bytes, a.to_compact_bytes().unwrap());
+        assert!(bytes.len() < serde_json::to_vec(&a).unwrap().len());
+
+        // Canonically equal proposals keep their own fields but share an ID
+        let b_bytes = b.to_compact_bytes().unwrap();
+        assert_ne!(bytes, b_bytes);
+        let decoded = Proposal::from_compact_bytes(&bytes).unwrap();
+        assert_eq!(decoded.id, canonicalize(&a).unwrap().hash);
+        assert_eq!(
+            decoded.id,
+            Proposal::from_compact_bytes(&b_bytes).unwrap().id
+        );
+        assert_eq!(decoded.to_compact_bytes().unwrap(), bytes);
+
+        // The AST must canonicalize for the decoder to recompute the ID
+        assert!(proposal("{not json", "text").to_compact_bytes().is_err());
+    }
+
+    #[test]
+    fn test_malformed_encodings() {
+        let bytes = proposal(r#"{"a":1}"#, "text").to_compact_bytes().unwrap();
+
+        let mut bad = bytes.clone();
+        bad[0] = 9;
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bad),
+            Err(CompactError::UnsupportedVersion(9))
+        ));
+
+        let mut bad = bytes.clone();
+        bad[1] = 4;
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bad),
+            Err(CompactError::InvalidLayer(4))
+        ));
+
+        let mut bad = bytes.clone();
+        bad[2] = 8;
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bad),
+            Err(CompactError::InvalidStatus(8))
+        ));
+
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bytes[..bytes.len() - 1]),
+            Err(CompactError::Truncated("text"))
+        ));
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bytes[..5]),
+            Err(CompactError::Truncated("header"))
+        ));
+
+        let mut bad = bytes.clone();
+        bad.push(0);
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bad),
+            Err(CompactError::TrailingBytes(1))
+        ));
+
+        let mut bad = bytes.clone();
+        let last = bad.len() - 1;
+        bad[last] = 0xFF;
+        assert!(matches!(
+            Proposal::from_compact_bytes(&bad),
+            Err(CompactError::InvalidUtf8("text"))
+        ));
+    }
+}
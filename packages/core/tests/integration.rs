@@ -0,0 +1,232 @@
+//! End-to-end proposal lifecycle
+//!
+//! Drives representative proposals from construction through
+//! canonicalization, Channel A verification, routing with a mock Channel B
+//! verdict, friction, and the resulting status transition.
+
+use constitution_dao_core::channel_a::{
+    canonicalize, verify_proposal_with, FailureReason, VerificationConfig,
+};
+use constitution_dao_core::routing::{decide_routing, route_proposal, DecidabilityPolicy, Route};
+use constitution_dao_core::{
+    config, verify_proposal, ChannelAVerdict, ChannelBVerdict, DecidabilityClass, FrictionParams,
+    GovernanceLayer, Proposal, ProposalStatus,
+};
+
+/// Outcome of one run through the lifecycle
+struct Outcome {
+    proposal: Proposal,
+    verdict: ChannelAVerdict,
+    status: ProposalStatus,
+    friction: Option<FrictionParams>,
+}
+
+/// Build a proposal with its canonical ID, as the submission path does
+fn submit(ast: &str, text: &str, layer: GovernanceLayer) -> Proposal {
+    let proposal = Proposal::new(
+        "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".to_string(),
+        ast.to_string(),
+        text.to_string(),
+        layer,
+    );
+    match canonicalize(&proposal) {
+        Ok(payload) => proposal.with_id(payload.hash),
+        Err(_) => proposal,
+    }
+}
+
+/// Run Channel A, then Channel B if Channel A routes there, applying each transition
+fn run(mut proposal: Proposal, channel_b: ChannelBVerdict) -> Outcome {
+    proposal.status = ProposalStatus::ChannelAReview;
+    let verdict = verify_proposal(&proposal);
+
+    let (mut status, mut friction) = route_proposal(&proposal, &verdict, None);
+    proposal.status = status;
+    if status == ProposalStatus::ChannelBReview {
+        (status, friction) = route_proposal(&proposal, &verdict, Some(&channel_b));
+        proposal.status = status;
+    }
+
+    Outcome {
+        proposal,
+        verdict,
+        status,
+        friction,
+    }
+}
+
+/// Text whose compressed size is far above `MAX_COMPLEXITY`
+fn incompressible_text() -> String {
+    let mut state: u32 = 0x2545_F491;
+    let mut words = Vec::new();
+    for _ in 0..6_000 {
+        let word: String = (0..6)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                char::from(b'a' + (state % 26) as u8)
+            })
+            .collect();
+        words.push(word);
+    }
+    words.join(" ")
+}
+
+const TRANSFER_AST: &str = r#"{"action": "transfer", "amount": 100, "to": "community_fund"}"#;
+
+#[test]
+fn test_passing_proposal_proceeds_to_voting_with_friction() {
+    let proposal = submit(
+        TRANSFER_AST,
+        "Transfer 100 tokens to the community fund",
+        GovernanceLayer::L2Operational,
+    );
+    assert_eq!(proposal.id, canonicalize(&proposal).unwrap().hash);
+
+    let outcome = run(proposal, ChannelBVerdict::new(0.8, DecidabilityClass::II));
+    assert!(outcome.verdict.pass);
+    assert!(outcome.verdict.complexity_score <= config::MAX_COMPLEXITY);
+    assert_eq!(outcome.status, ProposalStatus::Voting);
+    assert_eq!(outcome.proposal.status, ProposalStatus::Voting);
+
+    let friction = outcome.friction.unwrap();
+    assert_eq!(friction, FrictionParams::from_alignment_score(0.8));
+    assert!((friction.quorum_multiplier - 1.1).abs() < 1e-9);
+    assert!(friction.timelock_duration > FrictionParams::BASE_TIMELOCK);
+}
+
+#[test]
+fn test_class_routes() {
+    let proposal = submit(
+        TRANSFER_AST,
+        "Transfer 100 tokens to the community fund",
+        GovernanceLayer::L2Operational,
+    );
+
+    // Class I votes after PoUW verification
+    let b = ChannelBVerdict::new(0.95, DecidabilityClass::I);
+    let outcome = run(proposal.clone(), b.clone());
+    assert_eq!(outcome.status, ProposalStatus::Voting);
+    assert_eq!(
+        decide_routing(&outcome.verdict, &b, &DecidabilityPolicy::default()).route,
+        Route::PoUW
+    );
+
+    // Class III and poorly aligned proposals go to the jury without friction
+    for b in [
+        ChannelBVerdict::new(0.95, DecidabilityClass::III),
+        ChannelBVerdict::new(config::AUTO_ESCALATE_ALIGNMENT / 2.0, DecidabilityClass::II),
+    ] {
+        let outcome = run(proposal.clone(), b.clone());
+        assert!(outcome.verdict.pass);
+        assert_eq!(outcome.status, ProposalStatus::RequiresHumanReview);
+        assert!(outcome.friction.is_none());
+        assert_eq!(
+            decide_routing(&outcome.verdict, &b, &DecidabilityPolicy::default()).route,
+            Route::ConstitutionalJury
+        );
+    }
+}
+
+#[test]
+fn test_channel_a_failures_are_rejected_before_channel_b() {
+    let aligned = ChannelBVerdict::new(1.0, DecidabilityClass::II);
+
+    let paradox = run(
+        submit(
+            r#"{"action": "conditional"}"#,
+            "This proposal passes iff it fails",
+            GovernanceLayer::L2Operational,
+        ),
+        aligned.clone(),
+    );
+    assert!(!paradox.verdict.pass && paradox.verdict.paradox_found && !paradox.verdict.cycle_found);
+
+    let cyclic = run(
+        submit(
+            r#"{"a": {"value": "$ref:b"}, "b": {"value": "$ref:a"}}"#,
+            "Circular budget",
+            GovernanceLayer::L3Execution,
+        ),
+        aligned.clone(),
+    );
+    assert!(!cyclic.verdict.pass && cyclic.verdict.cycle_found && !cyclic.verdict.paradox_found);
+
+    let complex = run(
+        submit(
+            TRANSFER_AST,
+            &incompressible_text(),
+            GovernanceLayer::L2Operational,
+        ),
+        aligned.clone(),
+    );
+    assert!(!complex.verdict.pass);
+    assert!(complex.verdict.complexity_score > config::MAX_COMPLEXITY);
+    assert!(!complex.verdict.paradox_found && !complex.verdict.cycle_found);
+
+    for outcome in [paradox, cyclic, complex] {
+        assert_eq!(outcome.status, ProposalStatus::Rejected);
+        assert_eq!(outcome.proposal.status, ProposalStatus::Rejected);
+        assert!(outcome.friction.is_none());
+    }
+}
+
+#[test]
+fn test_l0_targeting_proposal_rejected() {
+    let proposal = submit(
+        r#"{"action": "amend_axiom", "axiom": 1}"#,
+        "Amend the first axiom",
+        GovernanceLayer::L0Immutable,
+    );
+
+    let detailed = verify_proposal_with(&proposal, &VerificationConfig::default());
+    assert_eq!(
+        detailed.failure_reason,
+        Some(FailureReason::L0NotTargetable)
+    );
+
+    let outcome = run(proposal, ChannelBVerdict::new(1.0, DecidabilityClass::I));
+    assert_eq!(outcome.verdict, detailed.verdict);
+    assert!(!outcome.verdict.pass);
+    assert_eq!(outcome.status, ProposalStatus::Rejected);
+    assert!(outcome.friction.is_none());
+}
+
+#[test]
+fn test_verification_disabling_proposal_goes_to_jury() {
+    let proposal = submit(
+        r#"{"action": "set_parameter", "parameter": "active_oracle_set_size", "value": 1}"#,
+        "Shrink the oracle set to save fees",
+        GovernanceLayer::L2Operational,
+    );
+
+    // Channel A passes it, but Channel B is never consulted
+    let outcome = run(proposal, ChannelBVerdict::new(1.0, DecidabilityClass::II));
+    assert!(outcome.verdict.pass);
+    assert_eq!(outcome.status, ProposalStatus::RequiresHumanReview);
+    assert!(outcome.friction.is_none());
+}
+
+#[test]
+fn test_settled_proposals_do_not_move() {
+    let mut proposal = submit(
+        TRANSFER_AST,
+        "Transfer 100 tokens to the community fund",
+        GovernanceLayer::L2Operational,
+    );
+    let verdict = verify_proposal(&proposal);
+    let b = ChannelBVerdict::new(0.9, DecidabilityClass::II);
+
+    for settled in [
+        ProposalStatus::Passed,
+        ProposalStatus::Rejected,
+        ProposalStatus::Executed,
+    ] {
+        proposal.status = settled;
+        assert_eq!(
+            route_proposal(&proposal, &verdict, Some(&b)),
+            (settled, None)
+        );
+    }
+}
@@ -14,6 +14,12 @@ import * as crypto from 'crypto';
 import * as zlib from 'zlib';
 import { ChannelAVerdict, GovernanceLayer, Proposal, CONFIG } from '../types';
 
+/**
+ * Canonical form version, prefixed to every payload (must match the core's
+ * `config::CANONICAL_FORM_VERSION`)
+ */
+const CANONICAL_FORM_VERSION = 4;
+
 // Try to load native bindings
 let nativeCore: NativeCore | null = null;
 
@@ -80,8 +86,8 @@ export interface CanonicalPayload {
  *
  * Process:
  * 1. Parse and sort AST JSON alphabetically
- * 2. Normalize text: lowercase, remove punctuation, single spaces
- * 3. Combine: ast_json + "." + normalized_text
+ * 2. Normalize text: NFKC, lowercase, remove punctuation, single spaces
+ * 3. Combine: version_byte + ast_json + "." + normalized_text
  * 4. Compute SHA-256 hash
  */
 export function canonicalize(proposal: Proposal): CanonicalPayload {
@@ -106,6 +112,7 @@ export function canonicalize(proposal: Proposal): CanonicalPayload {
 
   // Step 2: Normalize text
   const normalizedText = proposal.text
+    .normalize('NFKC')
     .toLowerCase()
     .replace(/[^\w\s]/g, '')
     .replace(/\s+/g, ' ')
@@ -113,7 +120,7 @@ export function canonicalize(proposal: Proposal): CanonicalPayload {
 
   // Step 3: Combine
   const payload = astJson + '.' + normalizedText;
-  const bytes = Buffer.from(payload, 'utf8');
+  const bytes = Buffer.concat([Buffer.from([CANONICAL_FORM_VERSION]), Buffer.from(payload, 'utf8')]);
 
   // Step 4: Hash
   const hash = crypto.createHash('sha256').update(bytes).digest('hex');
@@ -244,8 +251,10 @@ export function verifyProposal(proposal: Proposal): ChannelAVerdict {
     // Step 2: Compute complexity
     const complexity_score = computeComplexity(canonical.bytes);
 
-    // Step 3: Detect paradoxes
-    const paradox_found = detectParadox(proposal.text);
+    // Step 3: Detect paradoxes on the normalized text the hash commits to
+    // (normalized text never contains '.', so it follows the last one)
+    const normalizedText = canonical.bytes.subarray(canonical.bytes.lastIndexOf(0x2e) + 1).toString('utf8');
+    const paradox_found = detectParadox(normalizedText);
 
     // Step 4: Detect cycles
     const cycle_found = detectCycles(proposal.logic_ast);